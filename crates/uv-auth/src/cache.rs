@@ -1,6 +1,7 @@
 use std::hash::BuildHasherDefault;
 use std::sync::Arc;
 use std::sync::RwLock;
+use std::time::SystemTime;
 
 use rustc_hash::{FxHashMap, FxHasher};
 use tracing::trace;
@@ -20,6 +21,8 @@ pub struct CredentialsCache {
     pub(crate) fetches: FxOnceMap<(Realm, Username), Option<Arc<Credentials>>>,
     /// A cache per URL, uses a trie for efficient prefix queries.
     urls: RwLock<UrlTrie>,
+    /// A cache of credentials returned by credential helpers per realm, with their expiry.
+    helpers: RwLock<FxHashMap<Realm, (Arc<Credentials>, Option<SystemTime>)>>,
 }
 
 impl Default for CredentialsCache {
@@ -35,6 +38,7 @@ impl CredentialsCache {
             fetches: FxOnceMap::default(),
             realms: RwLock::new(FxHashMap::default()),
             urls: RwLock::new(UrlTrie::new()),
+            helpers: RwLock::new(FxHashMap::default()),
         }
     }
 
@@ -87,6 +91,45 @@ impl CredentialsCache {
         None
     }
 
+    /// Return the unexpired credentials returned by a credential helper for a realm, if any.
+    pub(crate) fn get_helper(&self, realm: &Realm) -> Option<Arc<Credentials>> {
+        let helpers = self.helpers.read().unwrap();
+        let (credentials, expires_at) = helpers.get(realm)?;
+        if expires_at.is_some_and(|expires_at| expires_at <= SystemTime::now()) {
+            trace!("Cached credentials from credential helper for realm {realm} have expired");
+            return None;
+        }
+        trace!("Found cached credentials from credential helper for realm {realm}");
+        Some(credentials.clone())
+    }
+
+    /// Update the cache with credentials returned by a credential helper for a realm.
+    pub(crate) fn insert_helper(
+        &self,
+        realm: Realm,
+        credentials: Arc<Credentials>,
+        expires_at: Option<SystemTime>,
+    ) {
+        let mut helpers = self.helpers.write().unwrap();
+        helpers.insert(realm, (credentials, expires_at));
+    }
+
+    /// Remove the credentials returned by a credential helper for a realm, if they're the given
+    /// credentials.
+    ///
+    /// Credentials that were replaced in the meantime (e.g., by a concurrent request) are
+    /// retained.
+    pub(crate) fn invalidate_helper(&self, realm: &Realm, credentials: &Arc<Credentials>) {
+        let mut helpers = self.helpers.write().unwrap();
+        if helpers
+            .get(realm)
+            .is_some_and(|(cached, _)| Arc::ptr_eq(cached, credentials))
+        {
+            trace!("Invalidating cached credentials from credential helper for realm {realm}");
+            helpers.remove(realm);
+        }
+    }
+
     /// Update the cache with the given credentials.
     pub(crate) fn insert(&self, url: &Url, credentials: Arc<Credentials>) {
         // Do not cache empty credentials
@@ -0,0 +1,233 @@
+use std::path::{Path, PathBuf};
+
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+use url::Url;
+
+use uv_fs::LockedFile;
+
+use crate::credentials::Credentials;
+use crate::native::write_private;
+use crate::realm::Realm;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CredentialsFileError {
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+    #[error("Failed to parse `{}`", _0.display())]
+    Parse(PathBuf, #[source] toml::de::Error),
+    #[error(transparent)]
+    Serialize(#[from] toml::ser::Error),
+    #[error("Invalid realm in `{}`: `{1}`", _0.display())]
+    InvalidRealm(PathBuf, String, #[source] url::ParseError),
+}
+
+/// The on-disk representation of a [`CredentialsFile`].
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CredentialsFileWire {
+    #[serde(default, rename = "credential")]
+    credentials: Vec<CredentialWire>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct CredentialWire {
+    realm: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+    password: String,
+}
+
+/// A plaintext file of credentials managed by uv, keyed by [`Realm`].
+///
+/// The file is stored in the user configuration directory, and is only readable by the current
+/// user.
+#[derive(Debug, Clone)]
+pub struct CredentialsFile {
+    path: PathBuf,
+    credentials: FxHashMap<Realm, Credentials>,
+}
+
+impl CredentialsFile {
+    /// The default location of the credentials file, i.e., `$XDG_CONFIG_HOME/uv/credentials.toml`.
+    pub fn default_path() -> Option<PathBuf> {
+        uv_dirs::user_config_dir().map(|dir| dir.join("credentials.toml"))
+    }
+
+    /// Read the credentials file at the default location.
+    ///
+    /// Returns `None` if the location could not be determined.
+    pub fn from_default_location() -> Result<Option<Self>, CredentialsFileError> {
+        Self::default_path().map(Self::read).transpose()
+    }
+
+    /// Read the credentials file at the given path.
+    ///
+    /// If the file does not exist, an empty credentials file is returned.
+    pub fn read(path: impl Into<PathBuf>) -> Result<Self, CredentialsFileError> {
+        let path = path.into();
+        let contents = match fs_err::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+                debug!("No credentials file found at: `{}`", path.display());
+                return Ok(Self {
+                    path,
+                    credentials: FxHashMap::default(),
+                });
+            }
+            Err(err) => return Err(err.into()),
+        };
+        let wire: CredentialsFileWire = toml::from_str(&contents)
+            .map_err(|err| CredentialsFileError::Parse(path.clone(), err))?;
+
+        let mut credentials = FxHashMap::default();
+        for entry in wire.credentials {
+            let url = Url::parse(&entry.realm).map_err(|err| {
+                CredentialsFileError::InvalidRealm(path.clone(), entry.realm.clone(), err)
+            })?;
+            credentials.insert(
+                Realm::from(&url),
+                Credentials::new(entry.username, Some(entry.password)),
+            );
+        }
+        Ok(Self { path, credentials })
+    }
+
+    /// The path to the credentials file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Return the credentials for the realm of the given [`Url`], if any.
+    ///
+    /// If a username is provided, it must match the stored username or [`None`] is returned.
+    pub fn get(&self, url: &Url, username: Option<&str>) -> Option<&Credentials> {
+        let credentials = self.credentials.get(&Realm::from(url))?;
+        if username.is_some_and(|username| credentials.username() != Some(username)) {
+            return None;
+        }
+        Some(credentials)
+    }
+
+    /// Store the credentials for a realm, replacing any existing credentials.
+    pub fn insert(&mut self, realm: Realm, username: Option<String>, password: String) {
+        self.credentials
+            .insert(realm, Credentials::new(username, Some(password)));
+    }
+
+    /// Remove the credentials for a realm, returning them if they existed.
+    pub fn remove(&mut self, realm: &Realm) -> Option<Credentials> {
+        self.credentials.remove(realm)
+    }
+
+    /// Iterate over the stored credentials, ordered by realm.
+    pub fn iter(&self) -> impl Iterator<Item = (&Realm, &Credentials)> {
+        let mut credentials = self.credentials.iter().collect::<Vec<_>>();
+        credentials.sort_by_cached_key(|(realm, _)| realm.to_string());
+        credentials.into_iter()
+    }
+
+    /// Apply a modification to the credentials file, and write it to disk.
+    ///
+    /// The file is locked and re-read before the modification is applied, such that concurrent
+    /// modifications (e.g., from another `uv auth login`) aren't lost.
+    pub fn update<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> Result<T, CredentialsFileError> {
+        if let Some(parent) = self.path.parent() {
+            fs_err::create_dir_all(parent)?;
+        }
+        let _lock =
+            LockedFile::acquire_blocking(self.path.with_extension("lock"), "credentials file")?;
+        *self = Self::read(self.path.clone())?;
+        let result = f(self);
+        self.write()?;
+        Ok(result)
+    }
+
+    /// Write the credentials file to disk, with permissions restricted to the current user.
+    ///
+    /// Callers are expected to hold the lock acquired in [`CredentialsFile::update`].
+    fn write(&self) -> Result<(), CredentialsFileError> {
+        let wire = CredentialsFileWire {
+            credentials: self
+                .iter()
+                .map(|(realm, credentials)| CredentialWire {
+                    realm: realm.to_string(),
+                    username: credentials.username().map(ToString::to_string),
+                    password: credentials.password().unwrap_or_default().to_string(),
+                })
+                .collect(),
+        };
+        let contents = toml::to_string(&wire)?;
+        write_private(&self.path, contents.as_bytes())?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("credentials.toml");
+
+        let mut file = CredentialsFile::read(&path).unwrap();
+        let url = Url::parse("https://example.com:8080/simple/").unwrap();
+        file.update(|file| {
+            file.insert(
+                Realm::from(&url),
+                Some("user".to_string()),
+                "secret".to_string(),
+            );
+        })
+        .unwrap();
+
+        let contents = fs_err::read_to_string(&path).unwrap();
+        assert_eq!(
+            contents,
+            "[[credential]]\nrealm = \"https://example.com:8080\"\nusername = \"user\"\npassword = \"secret\"\n"
+        );
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs_err::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let file = CredentialsFile::read(&path).unwrap();
+        let other = Url::parse("https://example.com:8080/other").unwrap();
+        let credentials = file.get(&other, None).unwrap();
+        assert_eq!(credentials.password(), Some("secret"));
+        assert!(file.get(&other, Some("other")).is_none());
+
+        let other = Url::parse("https://example.com/simple").unwrap();
+        assert!(file.get(&other, None).is_none());
+    }
+
+    #[test]
+    fn concurrent_update() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("credentials.toml");
+
+        // Both copies are read before either is updated.
+        let mut first = CredentialsFile::read(&path).unwrap();
+        let mut second = CredentialsFile::read(&path).unwrap();
+
+        let url = Url::parse("https://example.com/simple/").unwrap();
+        first
+            .update(|file| file.insert(Realm::from(&url), None, "first".to_string()))
+            .unwrap();
+        let url = Url::parse("https://example.org/simple/").unwrap();
+        second
+            .update(|file| file.insert(Realm::from(&url), None, "second".to_string()))
+            .unwrap();
+
+        // Neither update is lost.
+        let file = CredentialsFile::read(&path).unwrap();
+        assert_eq!(file.iter().count(), 2);
+    }
+}
@@ -0,0 +1,186 @@
+use std::process::Stdio;
+use std::time::SystemTime;
+
+use serde::{Deserialize, Serialize};
+use tokio::io::AsyncWriteExt;
+use tokio::process::Command;
+use tracing::{debug, instrument};
+use url::Url;
+
+use crate::credentials::Credentials;
+
+#[derive(Debug, thiserror::Error)]
+pub enum CredentialHelperError {
+    #[error("Failed to run credential helper `{0}`")]
+    Io(String, #[source] std::io::Error),
+    #[error("Credential helper `{0}` failed with {1}")]
+    Status(String, std::process::ExitStatus),
+    #[error("Failed to parse the response from credential helper `{0}`")]
+    Json(String, #[source] serde_json::Error),
+    #[error("Credential helper `{0}` did not return a `password` or `token`")]
+    MissingPassword(String),
+}
+
+/// An external command that provides credentials for an index, akin to a Git credential helper.
+///
+/// The command is run in the system shell. It receives a JSON request on stdin, e.g.,
+/// `{"url": "https://example.com/simple/anyio/"}`, and must write a JSON response to stdout, e.g.,
+/// `{"username": "user", "password": "secret", "expires_at": "2025-01-01T00:00:00Z"}`.
+///
+/// A `token` may be returned in place of a `password`, in which case the username defaults to
+/// `__token__`. If `expires_at` is omitted, the credentials are reused for the remainder of the
+/// invocation.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CredentialHelper {
+    command: String,
+}
+
+/// The request sent to a [`CredentialHelper`] on stdin.
+#[derive(Debug, Serialize)]
+struct HelperRequest<'a> {
+    url: &'a str,
+}
+
+/// The response read from a [`CredentialHelper`] on stdout.
+#[derive(Debug, Deserialize)]
+struct HelperResponse {
+    username: Option<String>,
+    password: Option<String>,
+    token: Option<String>,
+    expires_at: Option<jiff::Timestamp>,
+}
+
+/// Credentials returned by a [`CredentialHelper`], along with their expiry.
+#[derive(Debug)]
+pub(crate) struct HelperCredentials {
+    pub(crate) credentials: Credentials,
+    pub(crate) expires_at: Option<SystemTime>,
+}
+
+impl CredentialHelper {
+    /// Create a [`CredentialHelper`] that runs the given shell command.
+    pub fn new(command: impl Into<String>) -> Self {
+        Self {
+            command: command.into(),
+        }
+    }
+
+    /// The shell command to run.
+    pub fn command(&self) -> &str {
+        &self.command
+    }
+
+    /// Run the helper to fetch credentials for the given [`Url`].
+    #[instrument(skip_all, fields(url = % url.to_string()))]
+    pub(crate) async fn fetch(
+        &self,
+        url: &Url,
+    ) -> Result<HelperCredentials, CredentialHelperError> {
+        debug!("Running credential helper `{}` for {url}", self.command);
+
+        let mut child = self
+            .shell()
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()
+            .map_err(|err| CredentialHelperError::Io(self.command.clone(), err))?;
+
+        // Write the request, then close stdin to signal the end of the input.
+        let request = serde_json::to_vec(&HelperRequest { url: url.as_str() })
+            .expect("the request is always serializable");
+        if let Some(mut stdin) = child.stdin.take() {
+            match stdin.write_all(&request).await {
+                Ok(()) => {}
+                // The helper may exit without reading the request.
+                Err(err) if err.kind() == std::io::ErrorKind::BrokenPipe => {}
+                Err(err) => return Err(CredentialHelperError::Io(self.command.clone(), err)),
+            }
+        }
+
+        let output = child
+            .wait_with_output()
+            .await
+            .map_err(|err| CredentialHelperError::Io(self.command.clone(), err))?;
+        if !output.status.success() {
+            return Err(CredentialHelperError::Status(
+                self.command.clone(),
+                output.status,
+            ));
+        }
+
+        let response: HelperResponse = serde_json::from_slice(&output.stdout)
+            .map_err(|err| CredentialHelperError::Json(self.command.clone(), err))?;
+
+        let (username, password) = match (response.username, response.password, response.token) {
+            (username, _, Some(token)) => (
+                Some(username.unwrap_or_else(|| "__token__".to_string())),
+                token,
+            ),
+            (username, Some(password), None) => (username, password),
+            (_, None, None) => {
+                return Err(CredentialHelperError::MissingPassword(self.command.clone()))
+            }
+        };
+
+        Ok(HelperCredentials {
+            credentials: Credentials::new(username, Some(password)),
+            expires_at: response.expires_at.map(SystemTime::from),
+        })
+    }
+
+    /// Create a [`Command`] that runs the helper in the system shell.
+    fn shell(&self) -> Command {
+        if cfg!(windows) {
+            let mut command = Command::new("cmd");
+            command.arg("/C").arg(&self.command);
+            command
+        } else {
+            let mut command = Command::new("sh");
+            command.arg("-c").arg(&self.command);
+            command
+        }
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn fetch_token() {
+        let helper = CredentialHelper::new(
+            r#"cat > /dev/null; echo '{"token": "secret", "expires_at": "2030-01-01T00:00:00Z"}'"#,
+        );
+        let url = Url::parse("https://example.com/simple/").unwrap();
+        let HelperCredentials {
+            credentials,
+            expires_at,
+        } = helper.fetch(&url).await.unwrap();
+        assert_eq!(credentials.username(), Some("__token__"));
+        assert_eq!(credentials.password(), Some("secret"));
+        assert!(expires_at.is_some());
+    }
+
+    #[tokio::test]
+    async fn fetch_receives_url() {
+        // Echo the URL back as the password.
+        let helper = CredentialHelper::new(
+            r#"url=$(sed 's/.*"url":"\([^"]*\)".*/\1/'); echo "{\"username\": \"user\", \"password\": \"$url\"}""#,
+        );
+        let url = Url::parse("https://example.com/simple/").unwrap();
+        let HelperCredentials { credentials, .. } = helper.fetch(&url).await.unwrap();
+        assert_eq!(credentials.username(), Some("user"));
+        assert_eq!(credentials.password(), Some("https://example.com/simple/"));
+    }
+
+    #[tokio::test]
+    async fn fetch_failure() {
+        let helper = CredentialHelper::new("exit 1");
+        let url = Url::parse("https://example.com/simple/").unwrap();
+        assert!(matches!(
+            helper.fetch(&url).await,
+            Err(CredentialHelperError::Status(..))
+        ));
+    }
+}
@@ -4,6 +4,7 @@ use tracing::{instrument, trace, warn};
 use url::Url;
 
 use crate::credentials::Credentials;
+use crate::native::NativeKeyring;
 
 /// A backend for retrieving credentials from a keyring.
 ///
@@ -18,6 +19,8 @@ pub struct KeyringProvider {
 pub(crate) enum KeyringProviderBackend {
     /// Use the `keyring` command to fetch credentials.
     Subprocess,
+    /// Use uv's built-in credential store to fetch credentials.
+    Native(NativeKeyring),
     #[cfg(test)]
     Dummy(std::collections::HashMap<(String, &'static str), &'static str>),
 }
@@ -30,6 +33,30 @@ impl KeyringProvider {
         }
     }
 
+    /// Create a new [`KeyringProvider::Native`] backed by the given store.
+    pub fn native(store: NativeKeyring) -> Self {
+        Self {
+            backend: KeyringProviderBackend::Native(store),
+        }
+    }
+
+    /// Returns `true` if the provider can look up credentials without a username.
+    pub fn supports_lookup_without_username(&self) -> bool {
+        matches!(self.backend, KeyringProviderBackend::Native(_))
+    }
+
+    /// Fetch credentials for the given [`Url`] from the keyring, without a known username.
+    ///
+    /// Only supported by the native backend; returns [`None`] for other backends.
+    #[instrument(skip_all, fields(url = % url.to_string()))]
+    pub async fn fetch_without_username(&self, url: &Url) -> Option<Credentials> {
+        if let KeyringProviderBackend::Native(ref store) = self.backend {
+            Self::fetch_native(store, url, None).await
+        } else {
+            None
+        }
+    }
+
     /// Fetch credentials for the given [`Url`] from the keyring.
     ///
     /// Returns [`None`] if no password was found for the username or if any errors
@@ -50,6 +77,11 @@ impl KeyringProvider {
             "Should only use keyring with a username"
         );
 
+        // The native store performs its own matching on the URL and host.
+        if let KeyringProviderBackend::Native(ref store) = self.backend {
+            return Self::fetch_native(store, url, Some(username)).await;
+        }
+
         // Check the full URL first
         // <https://github.com/pypa/pip/blob/ae5fff36b0aad6e5e0037884927eaa29163c0611/src/pip/_internal/network/auth.py#L376C1-L379C14>
         trace!("Checking keyring for URL {url}");
@@ -57,6 +89,7 @@ impl KeyringProvider {
             KeyringProviderBackend::Subprocess => {
                 self.fetch_subprocess(url.as_str(), username).await
             }
+            KeyringProviderBackend::Native(_) => None,
             #[cfg(test)]
             KeyringProviderBackend::Dummy(ref store) => {
                 Self::fetch_dummy(store, url.as_str(), username)
@@ -72,6 +105,7 @@ impl KeyringProvider {
             trace!("Checking keyring for host {host}");
             password = match self.backend {
                 KeyringProviderBackend::Subprocess => self.fetch_subprocess(&host, username).await,
+                KeyringProviderBackend::Native(_) => None,
                 #[cfg(test)]
                 KeyringProviderBackend::Dummy(ref store) => {
                     Self::fetch_dummy(store, &host, username)
@@ -114,6 +148,29 @@ impl KeyringProvider {
         }
     }
 
+    /// Fetch credentials from the native store, off of the async runtime, since reading the store
+    /// involves blocking I/O and key derivation.
+    async fn fetch_native(
+        store: &NativeKeyring,
+        url: &Url,
+        username: Option<&str>,
+    ) -> Option<Credentials> {
+        let store = store.clone();
+        let url = url.clone();
+        let username = username.map(ToString::to_string);
+        match tokio::task::spawn_blocking(move || store.fetch(&url, username.as_deref())).await {
+            Ok(Ok(credentials)) => credentials,
+            Ok(Err(err)) => {
+                warn!("Failed to read the native credential store: {err}");
+                None
+            }
+            Err(err) => {
+                warn!("Failed to read the native credential store: {err}");
+                None
+            }
+        }
+    }
+
     #[cfg(test)]
     fn fetch_dummy(
         store: &std::collections::HashMap<(String, &'static str), &'static str>,
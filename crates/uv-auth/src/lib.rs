@@ -1,18 +1,24 @@
 use std::sync::{Arc, LazyLock};
 
-use tracing::trace;
+use tracing::{trace, warn};
 use url::Url;
 
 use cache::CredentialsCache;
 pub use credentials::Credentials;
+pub use credentials_file::{CredentialsFile, CredentialsFileError};
+pub use helper::{CredentialHelper, CredentialHelperError};
 pub use keyring::KeyringProvider;
 pub use middleware::AuthMiddleware;
-use realm::Realm;
+pub use native::{KeyringEntry, NativeKeyring, NativeKeyringError};
+pub use realm::Realm;
 
 mod cache;
 mod credentials;
+mod credentials_file;
+mod helper;
 mod keyring;
 mod middleware;
+mod native;
 mod realm;
 
 // TODO(zanieb): Consider passing a cache explicitly throughout
@@ -43,3 +49,29 @@ pub fn store_credentials(url: &Url, credentials: Credentials) {
     trace!("Caching credentials for {url}");
     CREDENTIALS_CACHE.insert(url, Arc::new(credentials));
 }
+
+/// Return the credentials for a proxy from the uv credentials file or the netrc file, if any.
+///
+/// If the proxy URL includes a username, only credentials for that username are returned. Unlike
+/// for indexes, the keyring is not consulted, since proxies are configured before any requests
+/// are sent.
+pub fn proxy_credentials(url: &Url) -> Option<Credentials> {
+    let credentials = Credentials::from_url(url);
+    let username = credentials.as_ref().and_then(Credentials::username);
+
+    match CredentialsFile::from_default_location() {
+        Ok(Some(file)) => {
+            if let Some(credentials) = file.get(url, username) {
+                trace!("Using credentials from the credentials file for proxy: {url}");
+                return Some(credentials.clone());
+            }
+        }
+        Ok(None) => {}
+        Err(err) => warn!("Error reading credentials file: {err}"),
+    }
+
+    let netrc = netrc::Netrc::new().ok()?;
+    let credentials = Credentials::from_netrc(&netrc, url, username)?;
+    trace!("Using credentials from the netrc file for proxy: {url}");
+    Some(credentials)
+}
@@ -6,7 +6,7 @@ use url::Url;
 use crate::{
     credentials::{Credentials, Username},
     realm::Realm,
-    CredentialsCache, KeyringProvider, CREDENTIALS_CACHE,
+    CredentialHelper, CredentialsCache, CredentialsFile, KeyringProvider, CREDENTIALS_CACHE,
 };
 use anyhow::{anyhow, format_err};
 use netrc::Netrc;
@@ -48,13 +48,58 @@ impl NetrcMode {
     }
 }
 
+/// Strategy for loading the uv-managed credentials file.
+enum CredentialsFileMode {
+    Automatic(LazyLock<Option<CredentialsFile>>),
+    Enabled(CredentialsFile),
+    Disabled,
+}
+
+impl Default for CredentialsFileMode {
+    fn default() -> Self {
+        CredentialsFileMode::Automatic(LazyLock::new(
+            || match CredentialsFile::from_default_location() {
+                Ok(file) => file,
+                Err(err) => {
+                    warn!("Error reading credentials file: {err}");
+                    None
+                }
+            },
+        ))
+    }
+}
+
+impl CredentialsFileMode {
+    /// Get the parsed credentials file if enabled.
+    fn get(&self) -> Option<&CredentialsFile> {
+        match self {
+            CredentialsFileMode::Automatic(lock) => lock.as_ref(),
+            CredentialsFileMode::Enabled(file) => Some(file),
+            CredentialsFileMode::Disabled => None,
+        }
+    }
+}
+
+/// A [`CredentialHelper`] configured for a realm.
+struct HelperEntry {
+    helper: CredentialHelper,
+    /// Ensures that the helper is only run once at a time, so that concurrent requests share
+    /// its credentials.
+    lock: tokio::sync::Mutex<()>,
+}
+
 /// A middleware that adds basic authentication to requests.
 ///
 /// Uses a cache to propagate credentials from previously seen requests and
-/// fetches credentials from a netrc file and the keyring.
+/// fetches credentials from a netrc file, the uv credentials file, and the keyring.
+///
+/// Requests to realms with a configured [`CredentialHelper`] are always authenticated with the
+/// credentials returned by the helper instead.
 pub struct AuthMiddleware {
     netrc: NetrcMode,
+    credentials_file: CredentialsFileMode,
     keyring: Option<KeyringProvider>,
+    credential_helpers: Vec<(Realm, HelperEntry)>,
     cache: Option<CredentialsCache>,
     /// We know that the endpoint needs authentication, so we don't try to send an unauthenticated
     /// request, avoiding cloning an uncloneable request.
@@ -65,7 +110,9 @@ impl AuthMiddleware {
     pub fn new() -> Self {
         Self {
             netrc: NetrcMode::default(),
+            credentials_file: CredentialsFileMode::default(),
             keyring: None,
+            credential_helpers: Vec::new(),
             cache: None,
             only_authenticated: false,
         }
@@ -84,6 +131,19 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the [`CredentialsFile`] to use.
+    ///
+    /// `None` disables authentication via the credentials file.
+    #[must_use]
+    pub fn with_credentials_file(mut self, credentials_file: Option<CredentialsFile>) -> Self {
+        self.credentials_file = if let Some(credentials_file) = credentials_file {
+            CredentialsFileMode::Enabled(credentials_file)
+        } else {
+            CredentialsFileMode::Disabled
+        };
+        self
+    }
+
     /// Configure the [`KeyringProvider`] to use.
     #[must_use]
     pub fn with_keyring(mut self, keyring: Option<KeyringProvider>) -> Self {
@@ -91,6 +151,29 @@ impl AuthMiddleware {
         self
     }
 
+    /// Configure the [`CredentialHelper`]s to use, along with the URLs they apply to.
+    ///
+    /// Each helper applies to all requests in the realm of its URL.
+    #[must_use]
+    pub fn with_credential_helpers(
+        mut self,
+        credential_helpers: impl IntoIterator<Item = (Url, CredentialHelper)>,
+    ) -> Self {
+        self.credential_helpers = credential_helpers
+            .into_iter()
+            .map(|(url, helper)| {
+                (
+                    Realm::from(&url),
+                    HelperEntry {
+                        helper,
+                        lock: tokio::sync::Mutex::new(()),
+                    },
+                )
+            })
+            .collect();
+        self
+    }
+
     /// Configure the [`CredentialsCache`] to use.
     #[must_use]
     pub fn with_cache(mut self, cache: CredentialsCache) -> Self {
@@ -139,6 +222,7 @@ impl Middleware for AuthMiddleware {
     ///
     /// - Check the cache (realm key) for a password
     /// - Check the netrc for a password
+    /// - Check the credentials file for a password
     /// - Check the keyring for a password
     /// - Perform the request
     /// - Add the username and password to the cache if successful
@@ -156,8 +240,18 @@ impl Middleware for AuthMiddleware {
     /// - On 401, 403, or 404 check for authentication if there was a cache miss
     ///     - Check the cache (realm key) for the username and password
     ///     - Check the netrc for a username and password
+    ///     - Check the credentials file for a username and password
     ///     - Perform the request again if found
     ///     - Add the username and password to the cache if successful
+    ///
+    /// ## If the realm has a credential helper
+    ///
+    /// Unless the request already has a password, the credential helper takes precedence over
+    /// all other sources.
+    ///
+    /// - Check the cache (helper key) for unexpired credentials, otherwise run the helper
+    /// - Perform the request
+    /// - On 401, run the helper again and retry the request once
     async fn handle(
         &self,
         mut request: Request,
@@ -172,6 +266,17 @@ impl Middleware for AuthMiddleware {
         let url = tracing_url(&request, credentials.as_ref());
         trace!("Handling request for {url}");
 
+        if !credentials
+            .as_ref()
+            .is_some_and(|credentials| credentials.password().is_some())
+        {
+            if let Some(entry) = self.credential_helper(request.url()) {
+                return self
+                    .handle_with_helper(entry, request, extensions, next)
+                    .await;
+            }
+        }
+
         if let Some(credentials) = credentials {
             let credentials = Arc::new(credentials);
 
@@ -333,6 +438,93 @@ impl Middleware for AuthMiddleware {
 }
 
 impl AuthMiddleware {
+    /// Return the [`CredentialHelper`] configured for the realm of a URL, if any.
+    fn credential_helper(&self, url: &Url) -> Option<&HelperEntry> {
+        let realm = Realm::from(url);
+        self.credential_helpers
+            .iter()
+            .find(|(helper_realm, _)| *helper_realm == realm)
+            .map(|(_, entry)| entry)
+    }
+
+    /// Authenticate a request with credentials from a [`CredentialHelper`].
+    ///
+    /// If the server rejects the credentials, e.g., because a token expired early, the helper is
+    /// run again and the request is retried once.
+    async fn handle_with_helper(
+        &self,
+        entry: &HelperEntry,
+        request: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let request_url = request.url().clone();
+        let realm = Realm::from(&request_url);
+        let url = tracing_url(&request, None);
+
+        let retry_request = request.try_clone().ok_or_else(|| {
+            Error::Middleware(anyhow!(
+                "Request object is not cloneable. Are you passing a streaming body?".to_string()
+            ))
+        })?;
+
+        let credentials = self
+            .fetch_helper_credentials(entry, &realm, &request_url, None)
+            .await?;
+        trace!("Attempting request for {url} with credentials from credential helper");
+        let response = next
+            .clone()
+            .run(credentials.authenticate(request), extensions)
+            .await?;
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Ok(response);
+        }
+
+        trace!("Request for {url} failed with 401, running credential helper again");
+        let credentials = self
+            .fetch_helper_credentials(entry, &realm, &request_url, Some(&credentials))
+            .await?;
+        next.run(credentials.authenticate(retry_request), extensions)
+            .await
+    }
+
+    /// Fetch credentials for a realm from a [`CredentialHelper`], reusing cached credentials
+    /// until they expire.
+    ///
+    /// The helper is passed the URL of the request that triggered the fetch. If `rejected`
+    /// credentials are provided, they're evicted from the cache first.
+    async fn fetch_helper_credentials(
+        &self,
+        entry: &HelperEntry,
+        realm: &Realm,
+        url: &Url,
+        rejected: Option<&Arc<Credentials>>,
+    ) -> reqwest_middleware::Result<Arc<Credentials>> {
+        let _guard = entry.lock.lock().await;
+
+        if let Some(rejected) = rejected {
+            self.cache().invalidate_helper(realm, rejected);
+        }
+        if let Some(credentials) = self.cache().get_helper(realm) {
+            return Ok(credentials);
+        }
+
+        let helper_credentials = entry
+            .helper
+            .fetch(url)
+            .await
+            .map_err(|err| Error::Middleware(err.into()))?;
+        debug!("Found credentials from credential helper for {realm}");
+
+        let credentials = Arc::new(helper_credentials.credentials);
+        self.cache().insert_helper(
+            realm.clone(),
+            credentials.clone(),
+            helper_credentials.expires_at,
+        );
+        Ok(credentials)
+    }
+
     /// Run a request to completion.
     ///
     /// If credentials are present, insert them into the cache on success.
@@ -365,7 +557,7 @@ impl AuthMiddleware {
 
     /// Fetch credentials for a URL.
     ///
-    /// Supports netrc file and keyring lookups.
+    /// Supports netrc file, credentials file, and keyring lookups.
     async fn fetch_credentials(
         &self,
         credentials: Option<&Credentials>,
@@ -411,6 +603,18 @@ impl AuthMiddleware {
         }) {
             debug!("Found credentials in netrc file for {url}");
             Some(credentials)
+        } else if let Some(credentials) = self.credentials_file.get().and_then(|file| {
+            debug!("Checking credentials file for credentials for {url}");
+            file.get(
+                url,
+                credentials
+                    .as_ref()
+                    .and_then(|credentials| credentials.username()),
+            )
+            .cloned()
+        }) {
+            debug!("Found credentials in credentials file for {url}");
+            Some(credentials)
         // N.B. The keyring provider performs lookups for the exact URL then
         //      falls back to the host, but we cache the result per realm so if a keyring
         //      implementation returns different credentials for different URLs in the
@@ -420,6 +624,9 @@ impl AuthMiddleware {
                 if let Some(username) = credentials.and_then(|credentials| credentials.username()) {
                     debug!("Checking keyring for credentials for {username}@{url}");
                     keyring.fetch(url, username).await
+                } else if keyring.supports_lookup_without_username() {
+                    debug!("Checking keyring for credentials for {url}");
+                    keyring.fetch_without_username(url).await
                 } else {
                     debug!("Skipping keyring lookup for {url} with no username");
                     None
@@ -779,6 +986,103 @@ mod tests {
         Ok(())
     }
 
+    #[test(tokio::test)]
+    async fn test_credentials_file() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+
+        let dir = tempfile::tempdir()?;
+        let mut credentials_file = CredentialsFile::read(dir.path().join("credentials.toml"))?;
+        credentials_file.insert(
+            Realm::from(&base_url),
+            Some(username.to_string()),
+            password.to_string(),
+        );
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_netrc(None)
+                    .with_credentials_file(Some(credentials_file)),
+            )
+            .build();
+
+        assert_eq!(
+            client.get(server.uri()).send().await?.status(),
+            200,
+            "Credentials should be pulled from the credentials file"
+        );
+
+        let mut url = base_url.clone();
+        url.set_username("other_user").unwrap();
+        assert_eq!(
+            client.get(url).send().await?.status(),
+            401,
+            "Credentials are not pulled from the credentials file when given another username"
+        );
+
+        Ok(())
+    }
+
+    /// Credentials from a credential helper are reused and refreshed when rejected.
+    #[cfg(unix)]
+    #[test(tokio::test)]
+    async fn test_credential_helper() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+
+        // The helper returns stale credentials on the first invocation only.
+        let dir = tempfile::tempdir()?;
+        let count = dir.path().join("count");
+        let script = dir.path().join("helper.sh");
+        fs_err::write(
+            &script,
+            format!(
+                r#"cat > /dev/null
+n=$(($(cat {count} 2>/dev/null || echo 0) + 1))
+echo $n > {count}
+if [ $n = 1 ]; then p=stale; else p={password}; fi
+echo "{{\"username\": \"{username}\", \"password\": \"$p\"}}"
+"#,
+                count = count.display(),
+            ),
+        )?;
+        let helper = CredentialHelper::new(format!("sh {}", script.display()));
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_credential_helpers([(base_url.clone(), helper)]),
+            )
+            .build();
+
+        assert_eq!(
+            client.get(server.uri()).send().await?.status(),
+            200,
+            "Rejected credentials should be refreshed from the credential helper"
+        );
+        assert_eq!(fs_err::read_to_string(&count)?.trim(), "2");
+
+        assert_eq!(
+            client
+                .get(format!("{}/foo", server.uri()))
+                .send()
+                .await?
+                .status(),
+            200,
+            "Credentials from the credential helper should be reused"
+        );
+        assert_eq!(fs_err::read_to_string(&count)?.trim(), "2");
+
+        Ok(())
+    }
+
     #[test(tokio::test)]
     async fn test_netrc_file_mismatched_host() -> Result<(), Error> {
         let username = "user";
@@ -929,6 +1233,43 @@ mod tests {
         Ok(())
     }
 
+    /// The native keyring can provide credentials without a username.
+    #[test(tokio::test)]
+    async fn test_native_keyring() -> Result<(), Error> {
+        let username = "user";
+        let password = "password";
+        let server = start_test_server(username, password).await;
+        let base_url = Url::parse(&server.uri())?;
+
+        let dir = tempfile::tempdir()?;
+        let store = crate::NativeKeyring::new(dir.path());
+        store.insert(base_url.as_str(), username, password)?;
+
+        let client = test_client_builder()
+            .with(
+                AuthMiddleware::new()
+                    .with_cache(CredentialsCache::new())
+                    .with_keyring(Some(KeyringProvider::native(store))),
+            )
+            .build();
+
+        assert_eq!(
+            client.get(server.uri()).send().await?.status(),
+            200,
+            "Credentials are pulled from the native keyring without a username"
+        );
+
+        let mut url = base_url.clone();
+        url.set_username("other_user").unwrap();
+        assert_eq!(
+            client.get(url).send().await?.status(),
+            401,
+            "Credentials are not pulled from the native keyring when given another username"
+        );
+
+        Ok(())
+    }
+
     /// We include ports in keyring requests, e.g., `localhost:8000` should be distinct from `localhost`,
     /// unless the server is running on a default port, e.g., `localhost:80` is equivalent to `localhost`.
     /// We don't unit test the latter case because it's possible to collide with a server a developer is
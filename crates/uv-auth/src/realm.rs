@@ -22,7 +22,7 @@ use url::Url;
 // However, `url` (and therefore `reqwest`) sets the `port` to `None` if it matches the default port
 // so we do not need any special handling here.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub(crate) struct Realm {
+pub struct Realm {
     scheme: String,
     host: Option<String>,
     port: Option<u16>,
@@ -0,0 +1,170 @@
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+use tracing::debug;
+
+use uv_fs::directories;
+
+use crate::{rm_rf, Cache, CacheBucket, CacheEntry, Removal};
+
+/// The file extension used for the marker that records the last use of a cached environment.
+const LAST_USED_EXTENSION: &str = "last-used";
+
+/// The period after its last use during which a cached environment is assumed to be in use by
+/// another process, and so is never evicted.
+const IN_USE_PERIOD: Duration = Duration::from_secs(60 * 60);
+
+/// The retention policy for reusable environments in [`CacheBucket::Environments`].
+///
+/// Cached environments are content-addressed by the interpreter and the resolved set of
+/// requirements, so an environment is reused whenever the same requirements are requested again
+/// with the same interpreter. To bound the size of the bucket, environments that haven't been
+/// used within `max_age` are considered expired, and only the `max_entries` most recently used
+/// environments are retained.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct EnvironmentRetention {
+    /// The maximum number of cached environments to retain.
+    pub max_entries: usize,
+    /// The maximum amount of time since a cached environment was last used.
+    pub max_age: Duration,
+}
+
+impl Default for EnvironmentRetention {
+    fn default() -> Self {
+        Self {
+            max_entries: 64,
+            max_age: Duration::from_secs(30 * 24 * 60 * 60),
+        }
+    }
+}
+
+impl EnvironmentRetention {
+    /// Returns `true` if an environment last used at the given time has expired.
+    pub fn is_expired(&self, last_used: SystemTime) -> bool {
+        SystemTime::now()
+            .duration_since(last_used)
+            .is_ok_and(|age| age >= self.max_age)
+    }
+}
+
+/// A reusable environment in [`CacheBucket::Environments`].
+#[derive(Debug)]
+struct EnvironmentEntry {
+    /// The path to the symlink that points to the environment's archive.
+    path: PathBuf,
+    /// The time at which the environment was last used.
+    last_used: SystemTime,
+}
+
+impl Cache {
+    /// Record a use of the cached environment at the given [`CacheEntry`].
+    pub fn touch_environment(&self, entry: &CacheEntry) -> io::Result<()> {
+        fs_err::write(last_used_marker(entry.path()), [])
+    }
+
+    /// Return the time at which the cached environment at the given [`CacheEntry`] was last used.
+    ///
+    /// Returns `None` if the environment doesn't exist.
+    pub fn environment_last_used(&self, entry: &CacheEntry) -> Option<SystemTime> {
+        last_used(entry.path())
+    }
+
+    /// Remove any cached environments that fall outside the cache's [`EnvironmentRetention`].
+    ///
+    /// Expired environments are removed first; the least recently used environments are then
+    /// removed until at most [`EnvironmentRetention::max_entries`] remain. Environments whose
+    /// archive no longer exists are always removed.
+    ///
+    /// Environments used within the last hour are retained regardless of the retention policy, as
+    /// they may still be in use by another process.
+    pub fn evict_environments(&self) -> io::Result<Removal> {
+        let retention = self.environment_retention();
+        let mut summary = Removal::default();
+
+        let mut entries = Vec::new();
+        for shard in directories(self.bucket(CacheBucket::Environments)) {
+            for entry in fs_err::read_dir(&shard)? {
+                let path = entry?.path();
+
+                // Skip the marker files; they're removed alongside their environments.
+                if path
+                    .extension()
+                    .is_some_and(|extension| extension == LAST_USED_EXTENSION)
+                {
+                    continue;
+                }
+
+                // Remove any environments whose archive is missing.
+                if !path.exists() {
+                    debug!("Removing dangling cache environment: {}", path.display());
+                    summary += remove_environment(&path)?;
+                    continue;
+                }
+
+                let Some(last_used) = last_used(&path) else {
+                    continue;
+                };
+
+                // Retain any environments that may still be in use.
+                let age = SystemTime::now()
+                    .duration_since(last_used)
+                    .unwrap_or_default();
+                if age < IN_USE_PERIOD {
+                    debug!(
+                        "Retaining recently used cache environment: {}",
+                        path.display()
+                    );
+                    continue;
+                }
+
+                if retention.is_expired(last_used) {
+                    debug!("Removing expired cache environment: {}", path.display());
+                    summary += remove_environment(&path)?;
+                    continue;
+                }
+
+                entries.push(EnvironmentEntry { path, last_used });
+            }
+        }
+
+        // Retain the most recently used environments.
+        entries.sort_unstable_by(|a, b| b.last_used.cmp(&a.last_used));
+        for entry in entries.into_iter().skip(retention.max_entries) {
+            debug!(
+                "Removing least recently used cache environment: {}",
+                entry.path.display()
+            );
+            summary += remove_environment(&entry.path)?;
+        }
+
+        Ok(summary)
+    }
+}
+
+/// Return the path to the marker that records the last use of the environment at `path`.
+fn last_used_marker(path: &Path) -> PathBuf {
+    path.with_extension(LAST_USED_EXTENSION)
+}
+
+/// Return the time at which the environment at `path` was last used.
+///
+/// Environments created before the marker was introduced fall back to the modification time of
+/// the link itself.
+fn last_used(path: &Path) -> Option<SystemTime> {
+    fs_err::metadata(last_used_marker(path))
+        .or_else(|_| fs_err::symlink_metadata(path))
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Remove the environment at `path`, along with its archive and marker.
+fn remove_environment(path: &Path) -> io::Result<Removal> {
+    let mut summary = Removal::default();
+    if let Ok(target) = fs_err::canonicalize(path) {
+        summary += rm_rf(target)?;
+    }
+    summary += rm_rf(path)?;
+    summary += rm_rf(last_used_marker(path))?;
+    Ok(summary)
+}
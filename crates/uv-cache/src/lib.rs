@@ -19,6 +19,7 @@ use uv_pypi_types::ResolutionMetadata;
 pub use crate::by_timestamp::CachedByTimestamp;
 #[cfg(feature = "clap")]
 pub use crate::cli::CacheArgs;
+pub use crate::environments::EnvironmentRetention;
 use crate::removal::Remover;
 pub use crate::removal::{rm_rf, Removal};
 pub use crate::wheel::WheelCache;
@@ -28,6 +29,7 @@ mod archive;
 mod by_timestamp;
 #[cfg(feature = "clap")]
 mod cli;
+mod environments;
 mod removal;
 mod wheel;
 
@@ -125,6 +127,8 @@ pub struct Cache {
     /// Included to ensure that the temporary directory exists for the length of the operation, but
     /// is dropped at the end as appropriate.
     temp_dir: Option<Arc<tempfile::TempDir>>,
+    /// The retention policy for reusable environments.
+    environment_retention: EnvironmentRetention,
 }
 
 impl Cache {
@@ -134,6 +138,7 @@ impl Cache {
             root: root.into(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: None,
+            environment_retention: EnvironmentRetention::default(),
         }
     }
 
@@ -144,6 +149,7 @@ impl Cache {
             root: temp_dir.path().to_path_buf(),
             refresh: Refresh::None(Timestamp::now()),
             temp_dir: Some(Arc::new(temp_dir)),
+            environment_retention: EnvironmentRetention::default(),
         })
     }
 
@@ -153,6 +159,15 @@ impl Cache {
         Self { refresh, ..self }
     }
 
+    /// Set the [`EnvironmentRetention`] policy for the cache.
+    #[must_use]
+    pub fn with_environment_retention(self, environment_retention: EnvironmentRetention) -> Self {
+        Self {
+            environment_retention,
+            ..self
+        }
+    }
+
     /// Return the root of the cache.
     pub fn root(&self) -> &Path {
         &self.root
@@ -163,6 +178,11 @@ impl Cache {
         &self.refresh
     }
 
+    /// Return the [`EnvironmentRetention`] policy for the cache.
+    pub fn environment_retention(&self) -> &EnvironmentRetention {
+        &self.environment_retention
+    }
+
     /// The folder for a specific cache bucket
     pub fn bucket(&self, cache_bucket: CacheBucket) -> PathBuf {
         self.root.join(cache_bucket.to_str())
@@ -426,20 +446,8 @@ impl Cache {
             }
         }
 
-        // Second, remove any cached environments. These are never referenced by symlinks, so we can
-        // remove them directly.
-        match fs_err::read_dir(self.bucket(CacheBucket::Environments)) {
-            Ok(entries) => {
-                for entry in entries {
-                    let entry = entry?;
-                    let path = fs_err::canonicalize(entry.path())?;
-                    debug!("Removing dangling cache environment: {}", path.display());
-                    summary += rm_rf(path)?;
-                }
-            }
-            Err(err) if err.kind() == io::ErrorKind::NotFound => (),
-            Err(err) => return Err(err),
-        }
+        // Second, remove any cached environments that fall outside the retention policy.
+        summary += self.evict_environments()?;
 
         // Third, if enabled, remove all unzipped wheels, leaving only the wheel archives.
         if ci {
@@ -0,0 +1,174 @@
+//! Dynamic shell completions for values that depend on the user's environment, e.g., the names
+//! of installed tools.
+//!
+//! Completers are invoked while the user is typing, so any errors are ignored and an empty set
+//! of candidates is returned instead.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use clap::builder::StyledStr;
+use clap_complete::engine::CompletionCandidate;
+
+use uv_normalize::PackageName;
+use uv_static::EnvVars;
+use uv_workspace::{DiscoveryOptions, VirtualProject};
+
+/// Complete the names of the installed tools, e.g., for `uv tool uninstall <TAB>`.
+pub(crate) fn installed_tools(current: &OsStr) -> Vec<CompletionCandidate> {
+    let Some(current) = current.to_str() else {
+        return Vec::new();
+    };
+
+    installed_tool_names()
+        .into_iter()
+        .filter(|name| name.starts_with(current))
+        .map(CompletionCandidate::new)
+        .collect()
+}
+
+/// Complete the commands provided by the installed tools, e.g., for `uvx <TAB>`.
+///
+/// The candidates are filtered by the completion engine.
+pub(crate) fn tool_commands() -> Vec<CompletionCandidate> {
+    installed_tool_names()
+        .into_iter()
+        .map(CompletionCandidate::new)
+        .collect()
+}
+
+/// Return the names of the installed tools, i.e., the directories in the tool directory that
+/// contain a tool receipt.
+fn installed_tool_names() -> Vec<String> {
+    let Some(tool_dir) = tool_dir() else {
+        return Vec::new();
+    };
+    let Ok(entries) = fs_err::read_dir(tool_dir) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.join("uv-receipt.toml").is_file())
+        .filter_map(|path| {
+            let name = path.file_name()?.to_str()?;
+            PackageName::from_str(name).ok()?;
+            Some(name.to_string())
+        })
+        .collect()
+}
+
+/// Return the directory in which tools are installed.
+///
+/// Mirrors the tool directory resolution of `uv tool`, without creating the directory.
+fn tool_dir() -> Option<PathBuf> {
+    if let Some(tool_dir) = std::env::var_os(EnvVars::UV_TOOL_DIR) {
+        return Some(PathBuf::from(tool_dir));
+    }
+    let state_dir = uv_dirs::legacy_user_state_dir()
+        .filter(|dir| dir.exists())
+        .or_else(uv_dirs::user_state_dir)?;
+    Some(state_dir.join("tools"))
+}
+
+/// Complete the commands available to `uv run`.
+///
+/// Includes the `[tool.uv.scripts]` of the current project and of its workspace root, along with
+/// the executables in the project environment. The candidates are filtered by the completion
+/// engine.
+pub(crate) fn run_commands() -> Vec<CompletionCandidate> {
+    let Ok(cwd) = std::env::current_dir() else {
+        return Vec::new();
+    };
+    let Ok(runtime) = tokio::runtime::Builder::new_current_thread().build() else {
+        return Vec::new();
+    };
+    let Ok(project) =
+        runtime.block_on(VirtualProject::discover(&cwd, &DiscoveryOptions::default()))
+    else {
+        return Vec::new();
+    };
+
+    // Collect the tasks of the current project, falling back to those of the workspace root.
+    let mut tasks = BTreeMap::new();
+    for pyproject_toml in [
+        project.pyproject_toml(),
+        project.workspace().pyproject_toml(),
+    ] {
+        let Some(scripts) = pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.scripts.as_ref())
+        else {
+            continue;
+        };
+        for (name, script) in scripts {
+            tasks
+                .entry(name.clone())
+                .or_insert_with(|| script.help().map(ToString::to_string));
+        }
+    }
+
+    // Include the executables in the project environment, which includes the project's own
+    // entry points once it's installed.
+    let mut commands = executables(&project.workspace().venv(project.project_name()))
+        .into_iter()
+        .collect::<BTreeSet<_>>();
+
+    // Tasks take precedence over commands of the same name.
+    commands.retain(|command| !tasks.contains_key(command));
+
+    tasks
+        .into_iter()
+        .map(|(name, help)| CompletionCandidate::new(name).help(help.map(StyledStr::from)))
+        .chain(commands.into_iter().map(CompletionCandidate::new))
+        .collect()
+}
+
+/// Return the names of the executables in the scripts directory of the given virtual environment.
+fn executables(environment: &Path) -> Vec<String> {
+    let scripts = if cfg!(windows) {
+        environment.join("Scripts")
+    } else {
+        environment.join("bin")
+    };
+    let Ok(entries) = fs_err::read_dir(scripts) else {
+        return Vec::new();
+    };
+
+    entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| is_executable(path))
+        .filter_map(|path| {
+            let name = if cfg!(windows) {
+                path.file_stem()
+            } else {
+                path.file_name()
+            };
+            name.and_then(OsStr::to_str).map(ToString::to_string)
+        })
+        .collect()
+}
+
+/// Returns `true` if the file at the given path is executable.
+#[cfg(unix)]
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+
+    fs_err::metadata(path)
+        .is_ok_and(|metadata| metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+}
+
+/// Returns `true` if the file at the given path is executable.
+#[cfg(not(unix))]
+fn is_executable(path: &Path) -> bool {
+    path.is_file()
+        && path
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("exe"))
+}
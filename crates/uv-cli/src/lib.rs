@@ -1,4 +1,6 @@
 use std::ffi::OsString;
+use std::net::SocketAddr;
+use std::num::NonZeroUsize;
 use std::ops::{Deref, DerefMut};
 use std::path::PathBuf;
 use std::str::FromStr;
@@ -6,13 +8,15 @@ use std::str::FromStr;
 use anyhow::{anyhow, Result};
 use clap::builder::styling::{AnsiColor, Effects, Style};
 use clap::builder::Styles;
-use clap::{Args, Parser, Subcommand};
+use clap::{Args, CommandFactory, Parser, Subcommand};
+use clap_complete::engine::{ArgValueCompleter, SubcommandCandidates};
 
 use url::Url;
 use uv_cache::CacheArgs;
 use uv_configuration::{
-    ConfigSettingEntry, ExportFormat, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
-    ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing, VersionControlSystem,
+    AddBoundsKind, ConfigSettingEntry, ExportFormat, IndexStrategy, KeyringProviderType,
+    PackageNameSpecifier, ProjectBuildBackend, TargetTriple, TrustedHost, TrustedPublishing,
+    VersionControlSystem,
 };
 use uv_distribution_types::{Index, IndexUrl, Origin, PipExtraIndex, PipFindLinks, PipIndex};
 use uv_normalize::{ExtraName, GroupName, PackageName};
@@ -24,6 +28,7 @@ use uv_static::EnvVars;
 
 pub mod comma;
 pub mod compat;
+mod complete;
 pub mod options;
 pub mod version;
 
@@ -35,6 +40,35 @@ pub enum VersionFormat {
     Json,
 }
 
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, clap::ValueEnum)]
+pub enum VersionBump {
+    /// Increase the major version (e.g., `1.2.3` => `2.0.0`).
+    Major,
+    /// Increase the minor version (e.g., `1.2.3` => `1.3.0`).
+    Minor,
+    /// Increase the patch version (e.g., `1.2.3` => `1.2.4`).
+    Patch,
+    /// Remove any pre-release, post-release, or development segments (e.g., `1.2.3b1` =>
+    /// `1.2.3`).
+    Stable,
+    /// Increase the alpha version, starting a new alpha release if necessary (e.g., `1.2.3a1` =>
+    /// `1.2.3a2`).
+    Alpha,
+    /// Increase the beta version, starting a new beta release if necessary (e.g., `1.2.3a4` =>
+    /// `1.2.3b1`).
+    Beta,
+    /// Increase the release candidate version, starting a new release candidate if necessary
+    /// (e.g., `1.2.3b4` => `1.2.3rc1`).
+    Rc,
+    /// Increase the number of the current pre-release (e.g., `1.2.3b1` => `1.2.3b2`).
+    Pre,
+    /// Increase the post-release version (e.g., `1.2.3` => `1.2.3.post1`).
+    Post,
+    /// Increase the development version, starting the development of the next version if
+    /// necessary (e.g., `1.2.3.dev1` => `1.2.3.dev2`, or `1.2.3` => `1.2.4.dev1`).
+    Dev,
+}
+
 #[derive(Debug, Default, Clone, clap::ValueEnum)]
 pub enum ListFormat {
     /// Display the list of packages in a human-readable table.
@@ -47,6 +81,15 @@ pub enum ListFormat {
     Json,
 }
 
+#[derive(Debug, Default, Clone, Copy, clap::ValueEnum)]
+pub enum WorkspaceListFormat {
+    /// Display the names of the workspace members, one per line.
+    #[default]
+    Text,
+    /// Display the workspace members in a machine-readable JSON format.
+    Json,
+}
+
 fn extra_name_with_clap_error(arg: &str) -> Result<ExtraName> {
     ExtraName::from_str(arg).map_err(|_err| {
         anyhow!(
@@ -84,6 +127,33 @@ pub struct Cli {
     pub top_level: TopLevelArgs,
 }
 
+impl Cli {
+    /// Return the [`clap::Command`] for `uvx`, i.e., `uv tool uvx` combined with the top-level
+    /// arguments.
+    pub fn uvx_command() -> clap::Command {
+        let mut uvx = Self::command()
+            .find_subcommand("tool")
+            .unwrap()
+            .find_subcommand("uvx")
+            .unwrap()
+            .clone()
+            // Avoid duplicating the `--help` and `--version` flags from the top-level arguments.
+            .disable_help_flag(true)
+            .disable_version_flag(true)
+            .version(env!("CARGO_PKG_VERSION"));
+
+        // Copy the top-level arguments into the `uvx` command. (Like `Args::augment_args`, but
+        // expanded to skip collisions.)
+        for arg in TopLevelArgs::command().get_arguments() {
+            if arg.get_id() != "isolated" {
+                uvx = uvx.arg(arg);
+            }
+        }
+
+        uvx
+    }
+}
+
 #[derive(Parser)]
 #[command(disable_help_flag = true, disable_version_flag = true)]
 pub struct TopLevelArgs {
@@ -256,6 +326,12 @@ pub struct GlobalArgs {
     #[arg(global = true, long, env = EnvVars::UV_NO_PROGRESS, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_progress: bool,
 
+    /// Print a JSON summary of the HTTP cache statistics after the command completes.
+    ///
+    /// The summary is written to stdout, and includes counters for each index.
+    #[arg(global = true, long)]
+    pub report_cache_stats: bool,
+
     /// Skip writing `uv` installer metadata files (e.g., `INSTALLER`, `REQUESTED`, and `direct_url.json`) to site-packages `.dist-info` directories.
     #[arg(global = true, long, hide = true, env = EnvVars::UV_NO_INSTALLER_METADATA, value_parser = clap::builder::BoolishValueParser::new())]
     pub no_installer_metadata: bool,
@@ -434,6 +510,12 @@ pub enum Commands {
     Build(BuildArgs),
     /// Upload distributions to an index.
     Publish(PublishArgs),
+    /// Manage credentials for package indexes.
+    #[command(
+        after_help = "Use `uv help auth` for more details.",
+        after_long_help = ""
+    )]
+    Auth(AuthNamespace),
     /// The implementation of the build backend.
     ///
     /// These commands are not directly exposed to the user, instead users invoke their build
@@ -455,11 +537,20 @@ pub enum Commands {
     /// Clear the cache, removing all entries or those linked to specific packages.
     #[command(hide = true)]
     Clean(CleanArgs),
-    /// Display uv's version
-    Version {
-        #[arg(long, value_enum, default_value = "text")]
-        output_format: VersionFormat,
-    },
+    /// Read or update the project's version.
+    ///
+    /// The version is read from the `project.version` field of the project's `pyproject.toml`. A
+    /// new version can be provided explicitly, or computed from the current version with `--bump`.
+    ///
+    /// When the version is updated, the lockfile is updated to reflect the new version, if it
+    /// exists. To skip updating the lockfile, use `--frozen`.
+    ///
+    /// When used outside a project, uv's own version is displayed.
+    #[command(
+        after_help = "Use `uv help version` for more details.",
+        after_long_help = ""
+    )]
+    Version(VersionArgs),
     /// Generate shell completion
     #[command(alias = "--generate-shell-completion", hide = true)]
     GenerateShellCompletion(GenerateShellCompletionArgs),
@@ -541,6 +632,20 @@ pub enum CacheCommand {
     /// Note that it is important for performance for the cache directory to be located on the same
     /// file system as the Python environment uv is operating on.
     Dir,
+    /// Serve the wheels in the cache as a read-only package index.
+    ///
+    /// Starts an HTTP server that exposes every wheel in the cache that was downloaded from, or
+    /// built from a source distribution fetched from, a package index. The wheels are served as a
+    /// simple repository at `/simple/`, with both HTML (PEP 503) and JSON (PEP 691) pages, such
+    /// that other machines can use it as an index, e.g., with `--index-url
+    /// http://127.0.0.1:8000/simple/`.
+    ///
+    /// The cache stores wheels in unzipped form, so each wheel is re-packed when the server starts.
+    /// As such, the hash of a served wheel will differ from that of the wheel on the original
+    /// index.
+    ///
+    /// The server reflects the contents of the cache at startup.
+    Serve(ServeArgs),
 }
 
 #[derive(Args, Debug)]
@@ -570,6 +675,14 @@ pub struct PruneArgs {
     pub ci: bool,
 }
 
+#[derive(Args, Debug)]
+pub struct ServeArgs {
+    /// The address on which to listen, e.g., `0.0.0.0:8000` to accept connections from other
+    /// machines.
+    #[arg(long, default_value = "127.0.0.1:8000")]
+    pub bind: SocketAddr,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct PipNamespace {
@@ -652,6 +765,10 @@ pub enum ProjectCommand {
     /// When used in a project, the project environment will be created and
     /// updated before invoking the command.
     ///
+    /// When used in a project, if the command is the name of a task declared
+    /// in `tool.uv.scripts`, the task will be run instead, after any tasks it
+    /// depends on.
+    ///
     /// When used outside a project, if a virtual environment can be found in
     /// the current directory or a parent directory, the command will be run in
     /// that environment. Otherwise, the command will be run in the environment
@@ -663,7 +780,8 @@ pub enum ProjectCommand {
     /// from uv options for clarity, e.g., `uv run --python 3.12 -- python`.
     #[command(
         after_help = "Use `uv help run` for more details.",
-        after_long_help = ""
+        after_long_help = "",
+        add = SubcommandCandidates::new(complete::run_commands)
     )]
     Run(RunArgs),
     /// Create a new project.
@@ -732,6 +850,28 @@ pub enum ProjectCommand {
         after_long_help = ""
     )]
     Remove(RemoveArgs),
+    /// Upgrade the project's dependencies to the latest versions.
+    ///
+    /// Unlike `uv lock --upgrade`, which only upgrades the locked versions within the project's
+    /// existing version specifiers, `uv upgrade` updates the version specifiers in the project's
+    /// `pyproject.toml` to include the latest versions available on the package index, then
+    /// updates the lockfile and project environment.
+    ///
+    /// By default, the style of each version specifier is retained: `>=2.28` is upgraded to a
+    /// lower bound on the latest version, while `>=1.2, <2` is upgraded to the latest version
+    /// along with an upper bound on the next major version. Dependencies without a version
+    /// specifier, or with a URL or non-registry source, are left unchanged.
+    ///
+    /// To preview the changes without modifying the project, use `--dry-run`. To skip updating
+    /// the lockfile, use `--frozen`. To skip updating the environment, use `--no-sync`.
+    ///
+    /// uv will search for a project in the current directory or any parent
+    /// directory. If a project cannot be found, uv will exit with an error.
+    #[command(
+        after_help = "Use `uv help upgrade` for more details.",
+        after_long_help = ""
+    )]
+    Upgrade(UpgradeArgs),
     /// Update the project's environment.
     ///
     /// Syncing ensures that all project dependencies are installed and up-to-date with the
@@ -788,8 +928,70 @@ pub enum ProjectCommand {
         after_long_help = ""
     )]
     Export(ExportArgs),
+    /// Materialize a local package index from the project's lockfile.
+    ///
+    /// Downloads every source distribution and wheel referenced by `uv.lock`, for all platforms,
+    /// and verifies each against the hash recorded in the lockfile. The distributions are written
+    /// to the given directory alongside a static simple index, with both HTML (PEP 503) and JSON
+    /// (PEP 691) pages, e.g., for use with `--index-url file:///path/to/mirror` in an air-gapped
+    /// environment.
+    ///
+    /// Only distributions from package indexes are included; Git, path, and direct URL
+    /// dependencies are skipped. Distributions that already exist in the directory with a
+    /// matching hash are not downloaded again.
+    ///
+    /// Requires a lockfile (`uv.lock`). uv will search for a project in the current directory or
+    /// any parent directory. If a project cannot be found, uv will exit with an error.
+    #[command(
+        after_help = "Use `uv help mirror` for more details.",
+        after_long_help = ""
+    )]
+    Mirror(MirrorArgs),
     /// Display the project's dependency tree.
     Tree(TreeArgs),
+    /// Manage the project's workspace.
+    #[command(
+        after_help = "Use `uv help workspace` for more details.",
+        after_long_help = ""
+    )]
+    Workspace(WorkspaceNamespace),
+}
+
+#[derive(Args)]
+pub struct WorkspaceNamespace {
+    #[command(subcommand)]
+    pub command: WorkspaceCommand,
+}
+
+#[derive(Subcommand)]
+pub enum WorkspaceCommand {
+    /// List the members of the workspace.
+    ///
+    /// uv will search for a workspace in the current directory or any parent directory. If a
+    /// workspace cannot be found, uv will exit with an error.
+    ///
+    /// With `--affected-by`, only the members affected by changes to the given paths are listed,
+    /// e.g., to determine which members need to be tested in continuous integration.
+    List(WorkspaceListArgs),
+}
+
+#[derive(Args, Debug)]
+pub struct WorkspaceListArgs {
+    /// Only list the members affected by changes to the given paths.
+    ///
+    /// Each path is attributed to the workspace member that contains it. Those members are listed,
+    /// along with any members that depend on them, directly or transitively, as recorded in the
+    /// lockfile. Paths outside of all workspace members are ignored. Relative paths are resolved
+    /// against the project directory.
+    ///
+    /// Requires a lockfile (`uv.lock`). If the lockfile is outdated, a warning is shown, as the
+    /// dependents recorded in it may be incomplete.
+    #[arg(long, num_args = 1.., value_name = "PATHS")]
+    pub affected_by: Option<Vec<PathBuf>>,
+
+    /// The format in which to display the workspace members.
+    #[arg(long, value_enum, default_value_t = WorkspaceListFormat::default())]
+    pub format: WorkspaceListFormat,
 }
 
 /// A re-implementation of `Option`, used to avoid Clap's automatic `Option` flattening in
@@ -1810,8 +2012,9 @@ pub struct PipUninstallArgs {
 
     /// Attempt to use `keyring` for authentication for remote requirements files.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to use uv's built-in credential store, as
+    /// managed by `uv auth`.
     ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
@@ -2425,8 +2628,9 @@ pub struct VenvArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to use uv's built-in credential store, as
+    /// managed by `uv auth`.
     ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
@@ -2588,6 +2792,28 @@ pub struct InitArgs {
     #[arg(long, value_enum, conflicts_with_all=["script", "no_package"])]
     pub build_backend: Option<ProjectBuildBackend>,
 
+    /// Initialize the project from a template.
+    ///
+    /// The template may be a path to a local directory or the URL of a Git repository (e.g.,
+    /// `https://github.com/org/template` or `git+https://github.com/org/template@v1.0.0`). Use a
+    /// `#subdirectory=` fragment to select a template within a Git repository.
+    ///
+    /// The files in the template are copied into the project directory, excluding the `.git`
+    /// directory. Existing files are not overwritten. In both file contents and paths, the
+    /// placeholders `{{ name }}`, `{{ module_name }}`, and `{{ python_version }}` are replaced
+    /// with the project name, the importable module name, and the minimum supported Python
+    /// version, respectively.
+    ///
+    /// The `name` (and `description`, if provided) of the template's `pyproject.toml` are set
+    /// for the new project, and a `requires-python` is added if the template does not define one.
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "script", "app", "lib", "package", "no_package", "build_backend", "author_from"
+        ]
+    )]
+    pub template: Option<String>,
+
     /// Do not create a `README.md` file.
     #[arg(long)]
     pub no_readme: bool,
@@ -2838,16 +3064,31 @@ pub struct RunArgs {
     #[command(flatten)]
     pub refresh: RefreshArgs,
 
-    /// Run the command with all workspace members installed.
+    /// Run the command in every workspace member.
     ///
     /// The workspace's environment (`.venv`) is updated to include all workspace
-    /// members.
+    /// members. The command is then run once per member, with the member's directory
+    /// as the working directory.
     ///
     /// Any extras or groups specified via `--extra`, `--group`, or related options
     /// will be applied to all workspace members.
     #[arg(long, conflicts_with = "package")]
     pub all_packages: bool,
 
+    /// The number of workspace members to run the command in concurrently.
+    ///
+    /// Only applies when used with `--all-packages`. By default, the command is run in
+    /// one member at a time.
+    #[arg(long, requires = "all_packages", value_name = "JOBS")]
+    pub jobs: Option<NonZeroUsize>,
+
+    /// Continue running the command in the remaining workspace members after a failure.
+    ///
+    /// Only applies when used with `--all-packages`. By default, uv stops running the
+    /// command in further members as soon as it fails in one of them.
+    #[arg(long, requires = "all_packages")]
+    pub keep_going: bool,
+
     /// Run the command in a specific package in the workspace.
     ///
     /// If the workspace member does not exist, uv will exit with an error.
@@ -3032,6 +3273,16 @@ pub struct SyncArgs {
     #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "locked")]
     pub frozen: bool,
 
+    /// Check if the project environment is in sync with the lockfile, without modifying it.
+    ///
+    /// Reports the packages that would be installed, removed, or reinstalled by `uv sync`, and
+    /// exits with a non-zero status if the environment is out of sync. Neither the lockfile nor
+    /// the environment will be modified.
+    ///
+    /// Implies `--locked`, unless `--frozen` is provided.
+    #[arg(long)]
+    pub check: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -3137,6 +3388,68 @@ pub struct LockArgs {
     pub python: Option<Maybe<String>>,
 }
 
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct VersionArgs {
+    /// Set the project version to this value.
+    ///
+    /// To compute the new version from the current version instead, use `--bump`.
+    pub value: Option<String>,
+
+    /// Update the project version using the given semantics.
+    ///
+    /// May be provided multiple times to update several segments at once, e.g., `--bump minor
+    /// --bump beta` updates `1.2.3` to `1.3.0b1`. Release segments are updated first, followed by
+    /// the pre-release, post-release, and development segments.
+    ///
+    /// The new version must be greater than the current version.
+    #[arg(long, value_enum, conflicts_with = "value")]
+    pub bump: Vec<VersionBump>,
+
+    /// Display the new version without writing it to the `pyproject.toml`.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Only display the version, omitting the project name.
+    #[arg(long)]
+    pub short: bool,
+
+    /// The format of the output.
+    #[arg(long, value_enum, default_value = "text")]
+    pub output_format: VersionFormat,
+
+    /// Update the version without re-locking the project.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new())]
+    pub frozen: bool,
+
+    /// Read or update the version of a specific package in the workspace.
+    #[arg(long)]
+    pub package: Option<PackageName>,
+
+    #[command(flatten)]
+    pub resolver: ResolverArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// The Python interpreter to use when re-locking the project.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
 #[derive(Args)]
 #[command(group = clap::ArgGroup::new("sources").required(true).multiple(true))]
 #[allow(clippy::struct_excessive_bools)]
@@ -3191,6 +3504,18 @@ pub struct AddArgs {
     )]
     pub raw_sources: bool,
 
+    /// The kind of version specifier to use when adding dependencies.
+    ///
+    /// When adding a dependency to the project without a version specifier, uv will add bounds
+    /// based on the resolved version. By default, only a lower bound is added (e.g., `>=1.2.3`).
+    ///
+    /// When used with `--upgrade` or `--upgrade-package`, the bounds of existing dependencies that
+    /// are re-added without a version specifier are updated to reflect the upgraded version.
+    ///
+    /// This option is ignored when used with `--raw-sources` or `--frozen`.
+    #[arg(long, value_enum)]
+    pub bounds: Option<AddBoundsKind>,
+
     /// Commit to use when adding a dependency from Git.
     #[arg(long, group = "git-ref", action = clap::ArgAction::Set)]
     pub rev: Option<String>,
@@ -3270,7 +3595,7 @@ pub struct AddArgs {
 #[allow(clippy::struct_excessive_bools)]
 pub struct RemoveArgs {
     /// The names of the dependencies to remove (e.g., `ruff`).
-    #[arg(required = true)]
+    #[arg(required_unless_present = "unused")]
     pub packages: Vec<PackageName>,
 
     /// Remove the packages from the development dependency group.
@@ -3287,6 +3612,21 @@ pub struct RemoveArgs {
     #[arg(long, conflicts_with("dev"), conflicts_with("optional"))]
     pub group: Option<GroupName>,
 
+    /// Remove any dependencies that aren't imported by the project.
+    ///
+    /// Each dependency is mapped to the modules it provides, as recorded by its installed
+    /// distribution in the project environment. The project's Python sources are then scanned for
+    /// imports of those modules. Dependencies listed in `tool.uv.allow-unused` are retained.
+    ///
+    /// By default, the project's production dependencies are checked. Use `--dev`, `--optional`,
+    /// or `--group` to check another set of dependencies instead.
+    #[arg(long, conflicts_with = "packages", conflicts_with = "script")]
+    pub unused: bool,
+
+    /// Show the unused dependencies without removing them.
+    #[arg(long, requires = "unused")]
+    pub dry_run: bool,
+
     /// Avoid syncing the virtual environment after re-locking the project.
     #[arg(long, env = EnvVars::UV_NO_SYNC, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
     pub no_sync: bool,
@@ -3339,6 +3679,64 @@ pub struct RemoveArgs {
     pub python: Option<Maybe<String>>,
 }
 
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct UpgradeArgs {
+    /// The names of the dependencies to upgrade (e.g., `ruff`).
+    ///
+    /// If no names are provided, all dependencies with a version specifier are upgraded.
+    pub packages: Vec<PackageName>,
+
+    /// The kind of version specifier to use for the upgraded dependencies.
+    ///
+    /// By default, the style of each existing version specifier is retained (e.g., `>=1.2.3` or
+    /// `>=1.2.3, <2.0.0`). Dependencies with version specifiers that don't match any of the
+    /// supported styles (e.g., `>=1.2.3, !=1.3.0`) are skipped, unless `--bounds` is provided.
+    #[arg(long, value_enum)]
+    pub bounds: Option<AddBoundsKind>,
+
+    /// Show the proposed changes without updating the project.
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Avoid syncing the virtual environment after re-locking the project.
+    #[arg(long, env = EnvVars::UV_NO_SYNC, value_parser = clap::builder::BoolishValueParser::new(), conflicts_with = "frozen")]
+    pub no_sync: bool,
+
+    /// Upgrade the dependencies without re-locking the project.
+    ///
+    /// The project environment will not be synced.
+    #[arg(long, env = EnvVars::UV_FROZEN, value_parser = clap::builder::BoolishValueParser::new())]
+    pub frozen: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+
+    /// Upgrade the dependencies of a specific package in the workspace.
+    #[arg(long, conflicts_with = "isolated")]
+    pub package: Option<PackageName>,
+
+    /// The Python interpreter to use for resolving and syncing.
+    ///
+    /// See `uv help python` for details on Python discovery and supported
+    /// request formats.
+    #[arg(
+        long,
+        short,
+        env = EnvVars::UV_PYTHON,
+        verbatim_doc_comment,
+        help_heading = "Python options",
+        value_parser = parse_maybe_string,
+    )]
+    pub python: Option<Maybe<String>>,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct TreeArgs {
@@ -3650,6 +4048,25 @@ pub struct ExportArgs {
     pub python: Option<Maybe<String>>,
 }
 
+#[derive(Args)]
+pub struct MirrorArgs {
+    /// The directory in which to write the index.
+    ///
+    /// The directory is created if it doesn't exist.
+    #[arg(value_name = "DIR")]
+    pub out_dir: PathBuf,
+
+    /// Attempt to use `keyring` for authentication for index URLs.
+    ///
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to use uv's built-in credential store, as
+    /// managed by `uv auth`.
+    ///
+    /// Defaults to `disabled`.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct ToolNamespace {
@@ -3682,7 +4099,8 @@ pub enum ToolCommand {
     #[command(
         after_help = "Use `uvx` as a shortcut for `uv tool run`.\n\n\
         Use `uv help tool run` for more details.",
-        after_long_help = ""
+        after_long_help = "",
+        add = SubcommandCandidates::new(complete::tool_commands)
     )]
     Run(ToolRunArgs),
     /// Hidden alias for `uv tool run` for the `uvx` command
@@ -3691,7 +4109,8 @@ pub enum ToolCommand {
         override_usage = "uvx [OPTIONS] [COMMAND]",
         about = "Run a command provided by a Python package.",
         after_help = "Use `uv help tool run` for more details.",
-        after_long_help = ""
+        after_long_help = "",
+        add = SubcommandCandidates::new(complete::tool_commands)
     )]
     Uvx(ToolRunArgs),
     /// Install commands provided by a Python package.
@@ -3719,6 +4138,28 @@ pub enum ToolCommand {
     List(ToolListArgs),
     /// Uninstall a tool.
     Uninstall(ToolUninstallArgs),
+    /// Check installed tools for problems, and repair any broken tools.
+    ///
+    /// Each tool's environment is checked for a missing or incompatible Python
+    /// interpreter, installed files that are missing or modified with respect to
+    /// their `RECORD`, and missing dependencies. The tool's executables are
+    /// checked against its receipt.
+    ///
+    /// Broken tools are rebuilt from their receipts, respecting the
+    /// requirements, constraints, and settings that were provided at
+    /// installation time.
+    Doctor(ToolDoctorArgs),
+    /// Export the wheels required to reproduce an installed tool's environment.
+    ///
+    /// Every distribution in the tool's environment is written to the output
+    /// directory as a wheel, building any source distributions as needed. The
+    /// directory can then be used to install the tool on a machine without
+    /// network access, e.g., with `uv tool install --offline --no-index
+    /// --find-links <DIR> <NAME>`.
+    ///
+    /// The tool's requirements, constraints, and settings are read from its
+    /// receipt, and the exported versions match those currently installed.
+    Export(ToolExportArgs),
     /// Ensure that the tool executable directory is on the `PATH`.
     ///
     /// If the tool executable directory is not present on the `PATH`, uv will
@@ -3781,6 +4222,15 @@ pub struct ToolRunArgs {
     #[arg(long)]
     pub isolated: bool,
 
+    /// Run the version of the tool that's pinned in the project's lockfile.
+    ///
+    /// The package that provides the command (or the `--from` package) is looked up in the
+    /// `uv.lock` of the current project or workspace, and the locked version is run in an isolated
+    /// environment, without installing the project itself. This ensures that tools defined in
+    /// (e.g.) a dependency group run at the same version in CI and locally.
+    #[arg(long)]
+    pub from_project: bool,
+
     #[command(flatten)]
     pub installer: ResolverInstallerArgs,
 
@@ -3810,6 +4260,14 @@ pub struct ToolRunArgs {
     #[arg(long, env = EnvVars::UV_SHOW_RESOLUTION, value_parser = clap::builder::BoolishValueParser::new(), hide = true)]
     pub show_resolution: bool,
 
+    /// Show the environment used to run the tool.
+    ///
+    /// Displays the path to the environment, along with whether it's an installed tool
+    /// environment or a cached environment, and whether the cached environment was reused or
+    /// created (and why).
+    #[arg(long)]
+    pub show_env: bool,
+
     #[arg(long, hide = true)]
     pub generate_shell_completion: Option<clap_complete_command::Shell>,
 }
@@ -3878,6 +4336,15 @@ pub struct ToolInstallArgs {
     #[arg(long)]
     pub force: bool,
 
+    /// The kind of version specifier to record for the tool's requirements.
+    ///
+    /// By default, requirements are recorded as provided. When set, requirements without a
+    /// version specifier are recorded with bounds based on the installed version (e.g.,
+    /// `--bounds major` records `ruff>=0.6.0, <0.7.0`), such that `uv tool upgrade` respects
+    /// them.
+    #[arg(long, value_enum)]
+    pub bounds: Option<AddBoundsKind>,
+
     /// The Python interpreter to use to build the tool environment.
     ///
     /// See `uv help python` for details on Python discovery and supported
@@ -3935,7 +4402,7 @@ pub struct ToolDirArgs {
 #[allow(clippy::struct_excessive_bools)]
 pub struct ToolUninstallArgs {
     /// The name of the tool to uninstall.
-    #[arg(required = true)]
+    #[arg(required = true, add = ArgValueCompleter::new(complete::installed_tools))]
     pub name: Vec<PackageName>,
 
     /// Uninstall all tools.
@@ -3943,11 +4410,56 @@ pub struct ToolUninstallArgs {
     pub all: bool,
 }
 
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ToolDoctorArgs {
+    /// The name of the tool to check.
+    ///
+    /// If no names are provided, all installed tools are checked.
+    #[arg(add = ArgValueCompleter::new(complete::installed_tools))]
+    pub name: Vec<PackageName>,
+
+    /// Report any problems without repairing the affected tools.
+    ///
+    /// If any tools are broken, uv will exit with an error.
+    #[arg(long)]
+    pub check: bool,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+}
+
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ToolExportArgs {
+    /// The name of the tool to export.
+    #[arg(add = ArgValueCompleter::new(complete::installed_tools))]
+    pub name: PackageName,
+
+    /// The output directory to which the wheels should be written.
+    ///
+    /// The directory will be created if it doesn't exist.
+    #[arg(long, short, value_parser = parse_file_path)]
+    pub out_dir: PathBuf,
+
+    #[command(flatten)]
+    pub installer: ResolverInstallerArgs,
+
+    #[command(flatten)]
+    pub build: BuildOptionsArgs,
+
+    #[command(flatten)]
+    pub refresh: RefreshArgs,
+}
+
 #[derive(Args)]
 #[allow(clippy::struct_excessive_bools)]
 pub struct ToolUpgradeArgs {
     /// The name of the tool to upgrade, along with an optional version specifier.
-    #[arg(required = true)]
+    #[arg(required = true, add = ArgValueCompleter::new(complete::installed_tools))]
     pub name: Vec<String>,
 
     /// Upgrade all tools.
@@ -4023,8 +4535,9 @@ pub struct ToolUpgradeArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to use uv's built-in credential store, as
+    /// managed by `uv auth`.
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -4687,8 +5200,9 @@ pub struct InstallerArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to use uv's built-in credential store, as
+    /// managed by `uv auth`.
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -4829,8 +5343,9 @@ pub struct ResolverArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to use uv's built-in credential store, as
+    /// managed by `uv auth`.
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -5019,8 +5534,9 @@ pub struct ResolverInstallerArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to use uv's built-in credential store, as
+    /// managed by `uv auth`.
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -5190,8 +5706,9 @@ pub struct FetchArgs {
 
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to use uv's built-in credential store, as
+    /// managed by `uv auth`.
     ///
     /// Defaults to `disabled`.
     #[arg(
@@ -5309,8 +5826,9 @@ pub struct PublishArgs {
 
     /// Attempt to use `keyring` for authentication for remote requirements files.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to use uv's built-in credential store, as
+    /// managed by `uv auth`.
     ///
     /// Defaults to `disabled`.
     #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
@@ -5345,6 +5863,135 @@ pub struct PublishArgs {
     pub skip_existing: bool,
 }
 
+/// A store for the credentials managed by `uv auth`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum AuthStore {
+    /// uv's plaintext credentials file, in which credentials apply to all URLs with the same
+    /// scheme, host, and port.
+    File,
+    /// uv's encrypted credential store, in which a URL's credentials apply to any URL nested
+    /// under it.
+    Native,
+}
+
+#[derive(Args)]
+pub struct AuthNamespace {
+    #[command(subcommand)]
+    pub command: AuthCommand,
+}
+
+#[derive(Subcommand)]
+pub enum AuthCommand {
+    /// Store credentials for an index or URL.
+    Login(AuthLoginArgs),
+    /// Remove the stored credentials for an index or URL.
+    Logout(AuthLogoutArgs),
+    /// Show the stored token (i.e., password) for an index or URL.
+    Token(AuthTokenArgs),
+    /// List the stored credentials.
+    List(AuthListArgs),
+}
+
+#[derive(Args)]
+pub struct AuthLoginArgs {
+    /// The index or URL to store credentials for.
+    ///
+    /// Either the name of an index defined in the configuration (e.g., in `[[tool.uv.index]]`), a
+    /// URL (e.g., `https://example.com/simple`), or a host with an optional port (e.g.,
+    /// `example.com`).
+    ///
+    /// By default, credentials are stored in uv's credentials file, and apply to all URLs with
+    /// the same scheme, host, and port. With `--store native`, credentials are stored in uv's
+    /// encrypted credential store, and a URL's credentials apply to any URL nested under it.
+    pub service: String,
+
+    /// The username for the service.
+    #[arg(short, long, required_unless_present = "token")]
+    pub username: Option<String>,
+
+    /// The password for the service.
+    ///
+    /// If omitted, uv will prompt for the password.
+    #[arg(short, long)]
+    pub password: Option<String>,
+
+    /// The token for the service.
+    ///
+    /// Using a token is equivalent to passing `__token__` as `--username` and the token as
+    /// `--password`.
+    #[arg(short, long, conflicts_with = "username", conflicts_with = "password")]
+    pub token: Option<String>,
+
+    /// The credential store to save the credentials to.
+    ///
+    /// Defaults to uv's encrypted credential store (`native`) if the keyring provider is
+    /// `native`, and to uv's credentials file (`file`) otherwise.
+    #[arg(long, value_enum)]
+    pub store: Option<AuthStore>,
+
+    /// The keyring provider, used to select the credential store if `--store` is omitted.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+}
+
+#[derive(Args)]
+pub struct AuthLogoutArgs {
+    /// The index or URL to remove credentials for.
+    pub service: String,
+
+    /// The username to remove credentials for.
+    ///
+    /// If omitted, the credentials for all usernames are removed.
+    #[arg(short, long)]
+    pub username: Option<String>,
+
+    /// The credential store to remove the credentials from.
+    ///
+    /// Defaults to uv's encrypted credential store (`native`) if the keyring provider is
+    /// `native`, and to uv's credentials file (`file`) otherwise.
+    #[arg(long, value_enum)]
+    pub store: Option<AuthStore>,
+
+    /// The keyring provider, used to select the credential store if `--store` is omitted.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+}
+
+#[derive(Args)]
+pub struct AuthTokenArgs {
+    /// The index or URL to show the token for.
+    pub service: String,
+
+    /// The username to show the token for.
+    #[arg(short, long)]
+    pub username: Option<String>,
+
+    /// The credential store to read the token from.
+    ///
+    /// Defaults to uv's encrypted credential store (`native`) if the keyring provider is
+    /// `native`, and to uv's credentials file (`file`) otherwise.
+    #[arg(long, value_enum)]
+    pub store: Option<AuthStore>,
+
+    /// The keyring provider, used to select the credential store if `--store` is omitted.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+}
+
+#[derive(Args)]
+pub struct AuthListArgs {
+    /// The credential store to list the credentials from.
+    ///
+    /// Defaults to uv's encrypted credential store (`native`) if the keyring provider is
+    /// `native`, and to uv's credentials file (`file`) otherwise.
+    #[arg(long, value_enum)]
+    pub store: Option<AuthStore>,
+
+    /// The keyring provider, used to select the credential store if `--store` is omitted.
+    #[arg(long, value_enum, env = EnvVars::UV_KEYRING_PROVIDER)]
+    pub keyring_provider: Option<KeyringProviderType>,
+}
+
 /// See [PEP 517](https://peps.python.org/pep-0517/) and
 /// [PEP 660](https://peps.python.org/pep-0660/) for specifications of the parameters.
 #[derive(Subcommand)]
@@ -13,7 +13,7 @@ use std::time::Duration;
 use std::{env, iter};
 use tracing::{debug, trace};
 use url::Url;
-use uv_auth::AuthMiddleware;
+use uv_auth::{AuthMiddleware, CredentialHelper, Realm};
 use uv_configuration::{KeyringProviderType, TrustedHost};
 use uv_fs::Simplified;
 use uv_pep508::MarkerEnvironment;
@@ -22,9 +22,12 @@ use uv_static::EnvVars;
 use uv_version::version;
 use uv_warnings::warn_user_once;
 
+use crate::cache_stats::CacheStatsRecorder;
 use crate::linehaul::LineHaul;
 use crate::middleware::OfflineMiddleware;
-use crate::tls::read_identity;
+use crate::proxy::ProxySettings;
+use crate::rate_limit::{RateLimitMiddleware, RateLimits, RetryBackoff};
+use crate::tls::{read_certificates, read_identity, read_identity_with_key, TlsSettings};
 use crate::Connectivity;
 
 pub const DEFAULT_RETRIES: u32 = 3;
@@ -49,6 +52,8 @@ pub struct BaseClientBuilder<'a> {
     allow_insecure_host: Vec<TrustedHost>,
     native_tls: bool,
     retries: u32,
+    retry_backoff: RetryBackoff,
+    concurrency_per_host: Option<usize>,
     pub connectivity: Connectivity,
     client: Option<Client>,
     markers: Option<&'a MarkerEnvironment>,
@@ -56,6 +61,11 @@ pub struct BaseClientBuilder<'a> {
     auth_integration: AuthIntegration,
     default_timeout: Duration,
     extra_middleware: Option<ExtraMiddleware>,
+    index_tls: Vec<(Url, TlsSettings)>,
+    index_no_proxy: Vec<Url>,
+    proxies: Option<ProxySettings>,
+    credential_helpers: Vec<(Url, CredentialHelper)>,
+    cache_stats: CacheStatsRecorder,
 }
 
 /// A list of user-defined middlewares to be applied to the client.
@@ -84,12 +94,19 @@ impl BaseClientBuilder<'_> {
             native_tls: false,
             connectivity: Connectivity::Online,
             retries: DEFAULT_RETRIES,
+            retry_backoff: RetryBackoff::default(),
+            concurrency_per_host: None,
             client: None,
             markers: None,
             platform: None,
             auth_integration: AuthIntegration::default(),
             default_timeout: Duration::from_secs(30),
             extra_middleware: None,
+            index_tls: vec![],
+            index_no_proxy: vec![],
+            proxies: None,
+            credential_helpers: vec![],
+            cache_stats: CacheStatsRecorder::default(),
         }
     }
 }
@@ -119,6 +136,21 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
+    /// Set the backoff between retries of a failed request.
+    #[must_use]
+    pub fn retry_backoff(mut self, retry_backoff: RetryBackoff) -> Self {
+        self.retry_backoff = retry_backoff;
+        self
+    }
+
+    /// Limit the number of concurrent requests to a single host, until the response headers are
+    /// received.
+    #[must_use]
+    pub fn concurrency_per_host(mut self, concurrency_per_host: Option<usize>) -> Self {
+        self.concurrency_per_host = concurrency_per_host;
+        self
+    }
+
     #[must_use]
     pub fn native_tls(mut self, native_tls: bool) -> Self {
         self.native_tls = native_tls;
@@ -161,13 +193,47 @@ impl<'a> BaseClientBuilder<'a> {
         self
     }
 
-    pub fn is_offline(&self) -> bool {
-        matches!(self.connectivity, Connectivity::Offline)
+    /// Use dedicated TLS settings for requests to the realm of each of the given index URLs.
+    #[must_use]
+    pub fn index_tls(mut self, index_tls: Vec<(Url, TlsSettings)>) -> Self {
+        self.index_tls = index_tls;
+        self
+    }
+
+    /// Bypass any proxies for requests to the realm of each of the given index URLs.
+    #[must_use]
+    pub fn index_no_proxy(mut self, index_no_proxy: Vec<Url>) -> Self {
+        self.index_no_proxy = index_no_proxy;
+        self
+    }
+
+    /// Use the given proxies, rather than those that `reqwest` reads from the environment.
+    ///
+    /// The settings should be [resolved](ProxySettings::resolve) first, since they replace any
+    /// proxies in the environment.
+    #[must_use]
+    pub fn proxies(mut self, proxies: Option<ProxySettings>) -> Self {
+        self.proxies = proxies;
+        self
+    }
+
+    /// Use a dedicated [`CredentialHelper`] for requests to the realm of each of the given index
+    /// URLs.
+    #[must_use]
+    pub fn credential_helpers(mut self, credential_helpers: Vec<(Url, CredentialHelper)>) -> Self {
+        self.credential_helpers = credential_helpers;
+        self
+    }
+
+    /// Record the decisions made by the HTTP cache for requests through this client.
+    #[must_use]
+    pub fn cache_stats(mut self, cache_stats: CacheStatsRecorder) -> Self {
+        self.cache_stats = cache_stats;
+        self
     }
 
-    /// Create a [`RetryPolicy`] for the client.
-    fn retry_policy(&self) -> ExponentialBackoff {
-        ExponentialBackoff::builder().build_with_max_retries(self.retries)
+    pub fn is_offline(&self) -> bool {
+        matches!(self.connectivity, Connectivity::Offline)
     }
 
     pub fn build(&self) -> BaseClient {
@@ -217,6 +283,8 @@ impl<'a> BaseClientBuilder<'a> {
             timeout,
             ssl_cert_file_exists,
             Security::Secure,
+            None,
+            Proxies::Configured,
         );
 
         // Create an insecure client that accepts invalid certificates.
@@ -225,11 +293,73 @@ impl<'a> BaseClientBuilder<'a> {
             timeout,
             ssl_cert_file_exists,
             Security::Insecure,
+            None,
+            Proxies::Configured,
         );
 
         // Wrap in any relevant middleware and handle connectivity.
-        let client = self.apply_middleware(raw_client.clone());
-        let dangerous_client = self.apply_middleware(raw_dangerous_client.clone());
+        let rate_limits = Arc::new(RateLimits::new(
+            self.retry_backoff,
+            self.concurrency_per_host,
+        ));
+        let client = self.apply_middleware(raw_client.clone(), &rate_limits);
+        let dangerous_client = self.apply_middleware(raw_dangerous_client.clone(), &rate_limits);
+
+        // Create dedicated clients for any indexes with their own TLS or proxy settings.
+        let mut index_realms: Vec<(Realm, Option<&TlsSettings>, Proxies)> = Vec::new();
+        for (url, tls) in &self.index_tls {
+            let realm = Realm::from(url);
+            if index_realms.iter().any(|(existing, ..)| *existing == realm) {
+                warn_user_once!(
+                    "Ignoring TLS settings for `{url}`, since TLS settings were already provided for `{realm}`"
+                );
+                continue;
+            }
+            debug!("Using dedicated TLS settings for: {realm}");
+            index_realms.push((realm, Some(tls), Proxies::Configured));
+        }
+        for url in &self.index_no_proxy {
+            let realm = Realm::from(url);
+            debug!("Bypassing proxies for: {realm}");
+            if let Some((.., proxies)) = index_realms
+                .iter_mut()
+                .find(|(existing, ..)| *existing == realm)
+            {
+                *proxies = Proxies::Bypass;
+            } else {
+                index_realms.push((realm, None, Proxies::Bypass));
+            }
+        }
+
+        let index_clients = index_realms
+            .into_iter()
+            .map(|(realm, tls, proxies)| {
+                let raw_client = self.create_client(
+                    &user_agent_string,
+                    timeout,
+                    ssl_cert_file_exists,
+                    Security::Secure,
+                    tls,
+                    proxies,
+                );
+                let raw_dangerous_client = self.create_client(
+                    &user_agent_string,
+                    timeout,
+                    ssl_cert_file_exists,
+                    Security::Insecure,
+                    tls,
+                    proxies,
+                );
+                IndexClient {
+                    realm,
+                    client: self.apply_middleware(raw_client.clone(), &rate_limits),
+                    dangerous_client: self
+                        .apply_middleware(raw_dangerous_client.clone(), &rate_limits),
+                    raw_client,
+                    raw_dangerous_client,
+                }
+            })
+            .collect();
 
         BaseClient {
             connectivity: self.connectivity,
@@ -239,15 +369,34 @@ impl<'a> BaseClientBuilder<'a> {
             raw_client,
             dangerous_client,
             raw_dangerous_client,
+            index_clients,
             timeout,
+            rate_limits,
+            proxies: self.proxies.clone(),
+            cache_stats: self.cache_stats.clone(),
         }
     }
 
     /// Share the underlying client between two different middleware configurations.
     pub fn wrap_existing(&self, existing: &BaseClient) -> BaseClient {
-        // Wrap in any relevant middleware and handle connectivity.
-        let client = self.apply_middleware(existing.raw_client.clone());
-        let dangerous_client = self.apply_middleware(existing.raw_dangerous_client.clone());
+        // Wrap in any relevant middleware and handle connectivity, sharing the rate limits with the
+        // existing client.
+        let rate_limits = &existing.rate_limits;
+        let client = self.apply_middleware(existing.raw_client.clone(), rate_limits);
+        let dangerous_client =
+            self.apply_middleware(existing.raw_dangerous_client.clone(), rate_limits);
+        let index_clients = existing
+            .index_clients
+            .iter()
+            .map(|index| IndexClient {
+                realm: index.realm.clone(),
+                client: self.apply_middleware(index.raw_client.clone(), rate_limits),
+                dangerous_client: self
+                    .apply_middleware(index.raw_dangerous_client.clone(), rate_limits),
+                raw_client: index.raw_client.clone(),
+                raw_dangerous_client: index.raw_dangerous_client.clone(),
+            })
+            .collect();
 
         BaseClient {
             connectivity: self.connectivity,
@@ -257,7 +406,11 @@ impl<'a> BaseClientBuilder<'a> {
             dangerous_client,
             raw_client: existing.raw_client.clone(),
             raw_dangerous_client: existing.raw_dangerous_client.clone(),
+            index_clients,
             timeout: existing.timeout,
+            rate_limits: existing.rate_limits.clone(),
+            proxies: existing.proxies.clone(),
+            cache_stats: self.cache_stats.clone(),
         }
     }
 
@@ -267,6 +420,8 @@ impl<'a> BaseClientBuilder<'a> {
         timeout: Duration,
         ssl_cert_file_exists: bool,
         security: Security,
+        tls: Option<&TlsSettings>,
+        proxies: Proxies,
     ) -> Client {
         // Configure the builder.
         let client_builder = ClientBuilder::new()
@@ -288,12 +443,17 @@ impl<'a> BaseClientBuilder<'a> {
             client_builder.tls_built_in_webpki_certs(true)
         };
 
-        // Configure mTLS.
-        let client_builder = if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
-            match read_identity(&ssl_client_cert) {
-                Ok(identity) => client_builder.identity(identity),
+        // Trust any additional CA certificates.
+        let client_builder = if let Some(ca_cert) = tls.and_then(|tls| tls.ca_cert.as_deref()) {
+            match read_certificates(ca_cert) {
+                Ok(certificates) => certificates
+                    .into_iter()
+                    .fold(client_builder, ClientBuilder::add_root_certificate),
                 Err(err) => {
-                    warn_user_once!("Ignoring invalid `SSL_CLIENT_CERT`: {err}");
+                    warn_user_once!(
+                        "Ignoring invalid CA certificate `{}`: {err}",
+                        ca_cert.simplified_display()
+                    );
                     client_builder
                 }
             }
@@ -301,12 +461,52 @@ impl<'a> BaseClientBuilder<'a> {
             client_builder
         };
 
+        // Configure mTLS, preferring a client certificate that's specific to the index over the
+        // global `SSL_CLIENT_CERT`.
+        let client_builder =
+            if let Some(client_cert) = tls.and_then(|tls| tls.client_cert.as_deref()) {
+                let client_key = tls.and_then(|tls| tls.client_key.as_deref());
+                match read_identity_with_key(client_cert, client_key) {
+                    Ok(identity) => client_builder.identity(identity),
+                    Err(err) => {
+                        warn_user_once!(
+                            "Ignoring invalid client certificate `{}`: {err}",
+                            client_cert.simplified_display()
+                        );
+                        client_builder
+                    }
+                }
+            } else if let Some(ssl_client_cert) = env::var_os(EnvVars::SSL_CLIENT_CERT) {
+                match read_identity(&ssl_client_cert) {
+                    Ok(identity) => client_builder.identity(identity),
+                    Err(err) => {
+                        warn_user_once!("Ignoring invalid `SSL_CLIENT_CERT`: {err}");
+                        client_builder
+                    }
+                }
+            } else {
+                client_builder
+            };
+
+        // Configure any proxies, unless they're bypassed for the realm.
+        let client_builder = match proxies {
+            Proxies::Configured => match self.proxies.as_ref() {
+                Some(proxies) => proxies.apply(client_builder),
+                None => client_builder,
+            },
+            Proxies::Bypass => client_builder.no_proxy(),
+        };
+
         client_builder
             .build()
             .expect("Failed to build HTTP client.")
     }
 
-    fn apply_middleware(&self, client: Client) -> ClientWithMiddleware {
+    fn apply_middleware(
+        &self,
+        client: Client,
+        rate_limits: &Arc<RateLimits>,
+    ) -> ClientWithMiddleware {
         match self.connectivity {
             Connectivity::Online => {
                 let mut client = reqwest_middleware::ClientBuilder::new(client);
@@ -315,22 +515,29 @@ impl<'a> BaseClientBuilder<'a> {
                 if self.retries > 0 {
                     // Initialize the retry strategy.
                     let retry_strategy = RetryTransientMiddleware::new_with_policy_and_strategy(
-                        self.retry_policy(),
+                        rate_limits.backoff().policy(self.retries),
                         UvRetryableStrategy,
                     );
                     client = client.with(retry_strategy);
                 }
 
+                // Initialize the per-host rate limiting, within the retry middleware.
+                client = client.with(RateLimitMiddleware::new(rate_limits.clone()));
+
                 // Initialize the authentication middleware to set headers.
                 match self.auth_integration {
                     AuthIntegration::Default => {
-                        client = client
-                            .with(AuthMiddleware::new().with_keyring(self.keyring.to_provider()));
+                        client = client.with(
+                            AuthMiddleware::new()
+                                .with_keyring(self.keyring.to_provider())
+                                .with_credential_helpers(self.credential_helpers.clone()),
+                        );
                     }
                     AuthIntegration::OnlyAuthenticated => {
                         client = client.with(
                             AuthMiddleware::new()
                                 .with_keyring(self.keyring.to_provider())
+                                .with_credential_helpers(self.credential_helpers.clone())
                                 .with_only_authenticated(true),
                         );
                     }
@@ -370,10 +577,41 @@ pub struct BaseClient {
     connectivity: Connectivity,
     /// Configured client timeout, in seconds.
     timeout: Duration,
+    /// Dedicated HTTP clients for indexes with their own TLS or proxy settings.
+    index_clients: Vec<IndexClient>,
     /// Hosts that are trusted to use the insecure client.
     allow_insecure_host: Vec<TrustedHost>,
     /// The number of retries to attempt on transient errors.
     retries: u32,
+    /// The rate limiting state, shared with any clients that wrap this one.
+    rate_limits: Arc<RateLimits>,
+    /// The proxies configured for the client, if any.
+    proxies: Option<ProxySettings>,
+    /// The recorder for the decisions made by the HTTP cache.
+    cache_stats: CacheStatsRecorder,
+}
+
+/// The HTTP clients to use for requests to a specific realm.
+#[derive(Debug, Clone)]
+struct IndexClient {
+    /// The realm to which the clients apply.
+    realm: Realm,
+    /// The underlying HTTP client that enforces valid certificates.
+    client: ClientWithMiddleware,
+    /// The underlying HTTP client that accepts invalid certificates.
+    dangerous_client: ClientWithMiddleware,
+    /// The HTTP client without middleware.
+    raw_client: Client,
+    /// The HTTP client that accepts invalid certificates without middleware.
+    raw_dangerous_client: Client,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Proxies {
+    /// The client should use the configured proxies, or those in the environment.
+    Configured,
+    /// The client should bypass all proxies.
+    Bypass,
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -385,13 +623,26 @@ enum Security {
 }
 
 impl BaseClient {
-    /// Selects the appropriate client based on the host's trustworthiness.
+    /// Selects the appropriate client based on the host's trustworthiness and any TLS or proxy
+    /// settings specific to its realm.
     pub fn for_host(&self, url: &Url) -> &ClientWithMiddleware {
-        if self
+        let insecure = self
             .allow_insecure_host
             .iter()
-            .any(|allow_insecure_host| allow_insecure_host.matches(url))
-        {
+            .any(|allow_insecure_host| allow_insecure_host.matches(url));
+
+        if !self.index_clients.is_empty() {
+            let realm = Realm::from(url);
+            if let Some(index) = self.index_clients.iter().find(|index| index.realm == realm) {
+                return if insecure {
+                    &index.dangerous_client
+                } else {
+                    &index.client
+                };
+            }
+        }
+
+        if insecure {
             &self.dangerous_client
         } else {
             &self.client
@@ -408,9 +659,19 @@ impl BaseClient {
         self.connectivity
     }
 
+    /// The configured proxies, if any.
+    pub fn proxies(&self) -> Option<&ProxySettings> {
+        self.proxies.as_ref()
+    }
+
+    /// The recorder for the decisions made by the HTTP cache.
+    pub(crate) fn cache_stats(&self) -> &CacheStatsRecorder {
+        &self.cache_stats
+    }
+
     /// The [`RetryPolicy`] for the client.
     pub fn retry_policy(&self) -> ExponentialBackoff {
-        ExponentialBackoff::builder().build_with_max_retries(self.retries)
+        self.rate_limits.backoff().policy(self.retries)
     }
 }
 
@@ -486,3 +747,143 @@ fn find_source<E: Error + 'static>(orig: &dyn Error) -> Option<&E> {
     }
     None
 }
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+    use std::ptr;
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn for_host_index_realm() {
+        let index = Url::parse("https://pypi.internal.example.com/simple").unwrap();
+        let client = BaseClientBuilder::new()
+            .allow_insecure_host(vec![TrustedHost::from_str("insecure.example.com").unwrap()])
+            .index_tls(vec![(index, TlsSettings::default())])
+            .build();
+        let index = &client.index_clients[0];
+
+        // Any URL in the index's realm uses the dedicated client, including distribution URLs.
+        for url in [
+            "https://pypi.internal.example.com/simple/anyio/",
+            "https://pypi.internal.example.com/files/anyio-4.3.0-py3-none-any.whl",
+            "https://pypi.internal.example.com:443/simple/",
+        ] {
+            let url = Url::parse(url).unwrap();
+            assert!(ptr::eq(client.for_host(&url), &index.client), "{url}");
+        }
+
+        // URLs in other realms, i.e., with a different scheme, host, or port, use the default.
+        for url in [
+            "http://pypi.internal.example.com/simple/anyio/",
+            "https://pypi.internal.example.com:8443/simple/anyio/",
+            "https://files.internal.example.com/anyio-4.3.0-py3-none-any.whl",
+            "https://pypi.org/simple/anyio/",
+        ] {
+            let url = Url::parse(url).unwrap();
+            assert!(ptr::eq(client.for_host(&url), &client.client), "{url}");
+        }
+
+        let url = Url::parse("https://insecure.example.com/simple/anyio/").unwrap();
+        assert!(ptr::eq(client.for_host(&url), &client.dangerous_client));
+    }
+
+    #[test]
+    fn for_host_index_realm_insecure() {
+        let index = Url::parse("https://pypi.internal.example.com/simple").unwrap();
+        let client = BaseClientBuilder::new()
+            .allow_insecure_host(vec![
+                TrustedHost::from_str("pypi.internal.example.com").unwrap()
+            ])
+            .index_tls(vec![(index, TlsSettings::default())])
+            .build();
+        let index = &client.index_clients[0];
+
+        // A trusted host in the index's realm uses the dedicated insecure client.
+        let url = Url::parse("https://pypi.internal.example.com/simple/anyio/").unwrap();
+        assert!(ptr::eq(client.for_host(&url), &index.dangerous_client));
+    }
+
+    #[test]
+    fn index_tls_duplicate_realm() {
+        let client = BaseClientBuilder::new()
+            .index_tls(vec![
+                (
+                    Url::parse("https://pypi.internal.example.com/simple").unwrap(),
+                    TlsSettings::default(),
+                ),
+                (
+                    Url::parse("https://pypi.internal.example.com/other/simple").unwrap(),
+                    TlsSettings::default(),
+                ),
+            ])
+            .build();
+
+        // Only the first settings for a realm are used.
+        assert_eq!(client.index_clients.len(), 1);
+    }
+
+    #[test]
+    fn for_host_index_no_proxy() {
+        let client = BaseClientBuilder::new()
+            .index_no_proxy(vec![
+                Url::parse("https://pypi.internal.example.com/simple").unwrap()
+            ])
+            .build();
+        assert_eq!(client.index_clients.len(), 1);
+        let index = &client.index_clients[0];
+
+        // Requests to the index's realm use the client that bypasses proxies.
+        let url = Url::parse("https://pypi.internal.example.com/simple/anyio/").unwrap();
+        assert!(ptr::eq(client.for_host(&url), &index.client));
+
+        // Requests to other realms use the default client, with the configured proxies.
+        let url = Url::parse("https://pypi.org/simple/anyio/").unwrap();
+        assert!(ptr::eq(client.for_host(&url), &client.client));
+    }
+
+    #[test]
+    fn index_no_proxy_with_tls() {
+        let client = BaseClientBuilder::new()
+            .index_tls(vec![(
+                Url::parse("https://pypi.internal.example.com/simple").unwrap(),
+                TlsSettings::default(),
+            )])
+            .index_no_proxy(vec![
+                Url::parse("https://pypi.internal.example.com/other/simple").unwrap(),
+                Url::parse("https://proxy-free.example.com/simple").unwrap(),
+            ])
+            .build();
+
+        // Bypassing proxies for a realm with its own TLS settings reuses the same client.
+        assert_eq!(client.index_clients.len(), 2);
+        let url = Url::parse("https://pypi.internal.example.com/simple/anyio/").unwrap();
+        assert!(ptr::eq(
+            client.for_host(&url),
+            &client.index_clients[0].client
+        ));
+        let url = Url::parse("https://proxy-free.example.com/simple/anyio/").unwrap();
+        assert!(ptr::eq(
+            client.for_host(&url),
+            &client.index_clients[1].client
+        ));
+    }
+
+    #[test]
+    fn index_tls_invalid_paths() {
+        let index = Url::parse("https://pypi.internal.example.com/simple").unwrap();
+        let tls = TlsSettings {
+            client_cert: Some(PathBuf::from("missing-client-cert.pem")),
+            client_key: None,
+            ca_cert: Some(PathBuf::from("missing-ca-cert.pem")),
+        };
+
+        // Invalid files are ignored (with a warning), rather than failing to build the client.
+        let client = BaseClientBuilder::new()
+            .index_tls(vec![(index, tls)])
+            .build();
+        assert_eq!(client.index_clients.len(), 1);
+    }
+}
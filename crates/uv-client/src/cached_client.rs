@@ -14,6 +14,7 @@ use uv_cache::{CacheEntry, Freshness};
 use uv_fs::write_atomic;
 
 use crate::base_client::is_extended_transient_error;
+use crate::cache_stats::CacheOutcome;
 use crate::BaseClient;
 use crate::{
     httpcache::{AfterResponse, BeforeRequest, CachePolicy, CachePolicyBuilder},
@@ -472,6 +473,11 @@ impl CachedClient {
         Ok(match cached.cache_policy.before_request(&mut req) {
             BeforeRequest::Fresh => {
                 debug!("Found fresh response for: {}", req.url());
+                self.0.cache_stats().record(
+                    req.url(),
+                    CacheOutcome::Fresh,
+                    cached.data.len() as u64,
+                );
                 CachedResponse::FreshCache(cached)
             }
             BeforeRequest::Stale(new_cache_policy_builder) => match cache_control {
@@ -482,6 +488,11 @@ impl CachedClient {
                 }
                 CacheControl::AllowStale => {
                     debug!("Found stale (but allowed) response for: {}", req.url());
+                    self.0.cache_stats().record(
+                        req.url(),
+                        CacheOutcome::Fresh,
+                        cached.data.len() as u64,
+                    );
                     CachedResponse::FreshCache(cached)
                 }
             },
@@ -523,6 +534,11 @@ impl CachedClient {
         {
             AfterResponse::NotModified(new_policy) => {
                 debug!("Found not-modified response for: {url}");
+                self.0.cache_stats().record(
+                    &url,
+                    CacheOutcome::NotModified,
+                    cached.data.len() as u64,
+                );
                 Ok(CachedResponse::NotModified {
                     cached,
                     new_policy: Box::new(new_policy),
@@ -530,6 +546,11 @@ impl CachedClient {
             }
             AfterResponse::Modified(new_policy) => {
                 debug!("Found modified response for: {url}");
+                self.0.cache_stats().record(
+                    &url,
+                    CacheOutcome::Modified,
+                    response.content_length().unwrap_or(0),
+                );
                 Ok(CachedResponse::ModifiedOrNew {
                     response,
                     cache_policy: new_policy
@@ -557,6 +578,11 @@ impl CachedClient {
             .map_err(|err| ErrorKind::from_reqwest_middleware(url.clone(), err))?
             .error_for_status()
             .map_err(|err| ErrorKind::from_reqwest(url.clone(), err))?;
+        self.0.cache_stats().record(
+            &url,
+            CacheOutcome::Miss,
+            response.content_length().unwrap_or(0),
+        );
         let cache_policy = cache_policy_builder.build(&response);
         let cache_policy = if cache_policy.to_archived().is_storable() {
             Some(Box::new(cache_policy))
@@ -2,17 +2,22 @@ pub use base_client::{
     is_extended_transient_error, AuthIntegration, BaseClient, BaseClientBuilder,
     UvRetryableStrategy, DEFAULT_RETRIES,
 };
+pub use cache_stats::{CacheCounters, CacheStats, CacheStatsRecorder};
 pub use cached_client::{CacheControl, CachedClient, CachedClientError, DataWithCachePolicy};
 pub use error::{Error, ErrorKind, WrappedReqwestError};
 pub use flat_index::{FlatIndexClient, FlatIndexEntries, FlatIndexError};
 pub use linehaul::LineHaul;
+pub use proxy::ProxySettings;
+pub use rate_limit::RetryBackoff;
 pub use registry_client::{
     Connectivity, RegistryClient, RegistryClientBuilder, SimpleMetadata, SimpleMetadatum,
     VersionFiles,
 };
 pub use rkyvutil::{Deserializer, OwnedArchive, Serializer, Validator};
+pub use tls::TlsSettings;
 
 mod base_client;
+mod cache_stats;
 mod cached_client;
 mod error;
 mod flat_index;
@@ -20,6 +25,8 @@ mod html;
 mod httpcache;
 mod linehaul;
 mod middleware;
+mod proxy;
+mod rate_limit;
 mod registry_client;
 mod remote_metadata;
 mod rkyvutil;
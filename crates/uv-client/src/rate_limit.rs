@@ -0,0 +1,244 @@
+//! Rate limiting for HTTP requests.
+//!
+//! The retry backoff and the per-host concurrency limit are configured on the
+//! [`crate::BaseClientBuilder`]; the resulting state is shared by any clients that wrap the same
+//! underlying client.
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use http::Extensions;
+use reqwest::header::RETRY_AFTER;
+use reqwest::{Request, Response, StatusCode};
+use reqwest_middleware::{Middleware, Next};
+use reqwest_retry::policies::ExponentialBackoff;
+use tokio::sync::Semaphore;
+use tracing::debug;
+
+use uv_auth::Realm;
+
+/// The exponential backoff between retries of a failed request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryBackoff {
+    /// The delay before the first retry, which doubles with each subsequent retry.
+    pub base: Duration,
+    /// The maximum delay before a retry, including any delay requested by the server with a
+    /// `Retry-After` header.
+    pub max: Duration,
+}
+
+impl Default for RetryBackoff {
+    fn default() -> Self {
+        Self {
+            base: Self::DEFAULT_BASE,
+            max: Self::DEFAULT_MAX,
+        }
+    }
+}
+
+impl RetryBackoff {
+    /// The default delay before the first retry.
+    pub const DEFAULT_BASE: Duration = Duration::from_secs(1);
+
+    /// The default maximum delay before a retry.
+    pub const DEFAULT_MAX: Duration = Duration::from_secs(30 * 60);
+
+    /// Create an [`ExponentialBackoff`] policy with the given number of retries.
+    pub(crate) fn policy(self, retries: u32) -> ExponentialBackoff {
+        ExponentialBackoff::builder()
+            .retry_bounds(self.base, self.max.max(self.base))
+            .build_with_max_retries(retries)
+    }
+}
+
+/// The rate limiting state for every host contacted by a client.
+#[derive(Debug)]
+pub(crate) struct RateLimits {
+    /// The backoff between retries of a failed request.
+    backoff: RetryBackoff,
+    /// The maximum number of concurrent requests to a single host, if any.
+    concurrency_per_host: Option<usize>,
+    /// The state for each host, keyed by realm.
+    hosts: Mutex<HashMap<Realm, Arc<Host>>>,
+}
+
+impl RateLimits {
+    pub(crate) fn new(backoff: RetryBackoff, concurrency_per_host: Option<usize>) -> Self {
+        Self {
+            backoff,
+            concurrency_per_host,
+            hosts: Mutex::default(),
+        }
+    }
+
+    /// Return the backoff between retries of a failed request.
+    pub(crate) fn backoff(&self) -> RetryBackoff {
+        self.backoff
+    }
+
+    /// Return the state for the given host, creating it on first use.
+    fn host(&self, realm: &Realm) -> Arc<Host> {
+        let mut hosts = self.hosts.lock().unwrap();
+        if let Some(host) = hosts.get(realm) {
+            return host.clone();
+        }
+        let host = Arc::new(Host {
+            semaphore: self.concurrency_per_host.map(Semaphore::new),
+            not_before: Mutex::new(None),
+        });
+        hosts.insert(realm.clone(), host.clone());
+        host
+    }
+}
+
+/// The rate limiting state for a single host.
+#[derive(Debug)]
+struct Host {
+    /// Limits the number of concurrent requests to the host, if configured.
+    semaphore: Option<Semaphore>,
+    /// The earliest time at which the next request may be sent, as requested by the server.
+    not_before: Mutex<Option<Instant>>,
+}
+
+impl Host {
+    /// Wait until the host accepts requests again, if it asked us to back off.
+    async fn wait(&self, realm: &Realm) {
+        let not_before = *self.not_before.lock().unwrap();
+        if let Some(delay) =
+            not_before.and_then(|instant| instant.checked_duration_since(Instant::now()))
+        {
+            debug!(
+                "Waiting {:.1}s before sending a request to {realm}, as requested by `Retry-After`",
+                delay.as_secs_f64()
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Hold back any requests to the host for the given duration.
+    fn defer(&self, delay: Duration) {
+        let until = Instant::now() + delay;
+        let mut not_before = self.not_before.lock().unwrap();
+        if not_before.is_none_or(|instant| instant < until) {
+            *not_before = Some(until);
+        }
+    }
+}
+
+/// A middleware that limits the number of concurrent requests to each host, and holds back
+/// requests to a host that responded with a `Retry-After` header.
+///
+/// The middleware runs within the retry middleware, such that a retry waits for the longer of
+/// the backoff and the delay requested by the server.
+pub(crate) struct RateLimitMiddleware(Arc<RateLimits>);
+
+impl RateLimitMiddleware {
+    pub(crate) fn new(rate_limits: Arc<RateLimits>) -> Self {
+        Self(rate_limits)
+    }
+}
+
+#[async_trait::async_trait]
+impl Middleware for RateLimitMiddleware {
+    async fn handle(
+        &self,
+        req: Request,
+        extensions: &mut Extensions,
+        next: Next<'_>,
+    ) -> reqwest_middleware::Result<Response> {
+        let realm = Realm::from(req.url());
+        let host = self.0.host(&realm);
+
+        host.wait(&realm).await;
+
+        // The permit is held until the response headers are received.
+        let permit = match &host.semaphore {
+            Some(semaphore) => Some(
+                semaphore
+                    .acquire()
+                    .await
+                    .expect("the semaphore is never closed"),
+            ),
+            None => None,
+        };
+
+        let response = next.run(req, extensions).await;
+        drop(permit);
+
+        if let Ok(response) = &response {
+            if matches!(
+                response.status(),
+                StatusCode::TOO_MANY_REQUESTS | StatusCode::SERVICE_UNAVAILABLE
+            ) {
+                if let Some(delay) = response
+                    .headers()
+                    .get(RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| parse_retry_after(value, jiff::Timestamp::now()))
+                {
+                    let delay = delay.min(self.0.backoff().max);
+                    debug!(
+                        "Received `{}` from {realm}, holding back requests for {}s",
+                        response.status(),
+                        delay.as_secs()
+                    );
+                    host.defer(delay);
+                }
+            }
+        }
+
+        response
+    }
+}
+
+/// Parse the value of a `Retry-After` header, which is either a number of seconds or an HTTP
+/// date.
+///
+/// See: <https://www.rfc-editor.org/rfc/rfc9110#section-10.2.3>
+fn parse_retry_after(value: &str, now: jiff::Timestamp) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+    let date = jiff::fmt::rfc2822::DateTimeParser::new()
+        .parse_timestamp(value)
+        .ok()?;
+    let seconds = date.as_second().saturating_sub(now.as_second());
+    Some(Duration::from_secs(u64::try_from(seconds).unwrap_or(0)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_after() {
+        let now: jiff::Timestamp = "2015-10-21T07:28:00Z".parse().unwrap();
+        assert_eq!(
+            parse_retry_after("120", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:30:00 GMT", now),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(
+            parse_retry_after("Wed, 21 Oct 2015 07:00:00 GMT", now),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+
+    #[test]
+    fn policy_bounds() {
+        // A maximum below the base is raised to the base, rather than panicking.
+        let backoff = RetryBackoff {
+            base: Duration::from_secs(10),
+            max: Duration::from_secs(1),
+        };
+        let policy = backoff.policy(3);
+        assert_eq!(policy.min_retry_interval, Duration::from_secs(10));
+        assert_eq!(policy.max_retry_interval, Duration::from_secs(10));
+    }
+}
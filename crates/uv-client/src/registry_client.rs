@@ -12,6 +12,7 @@ use std::time::Duration;
 use tracing::{info_span, instrument, trace, warn, Instrument};
 use url::Url;
 
+use uv_auth::CredentialHelper;
 use uv_cache::{Cache, CacheBucket, CacheEntry, WheelCache};
 use uv_configuration::KeyringProviderType;
 use uv_configuration::{IndexStrategy, TrustedHost};
@@ -27,10 +28,14 @@ use uv_platform_tags::Platform;
 use uv_pypi_types::{ResolutionMetadata, SimpleJson};
 
 use crate::base_client::{BaseClientBuilder, ExtraMiddleware};
+use crate::cache_stats::CacheStatsRecorder;
 use crate::cached_client::CacheControl;
 use crate::html::SimpleHtml;
+use crate::proxy::ProxySettings;
+use crate::rate_limit::RetryBackoff;
 use crate::remote_metadata::wheel_metadata_from_remote_zip;
 use crate::rkyvutil::OwnedArchive;
+use crate::tls::TlsSettings;
 use crate::{BaseClient, CachedClient, CachedClientError, Error, ErrorKind};
 
 /// A builder for an [`RegistryClient`].
@@ -92,12 +97,38 @@ impl<'a> RegistryClientBuilder<'a> {
         self
     }
 
+    #[must_use]
+    pub fn retry_backoff(mut self, retry_backoff: RetryBackoff) -> Self {
+        self.base_client_builder = self.base_client_builder.retry_backoff(retry_backoff);
+        self
+    }
+
+    #[must_use]
+    pub fn concurrency_per_host(mut self, concurrency_per_host: Option<usize>) -> Self {
+        self.base_client_builder = self
+            .base_client_builder
+            .concurrency_per_host(concurrency_per_host);
+        self
+    }
+
     #[must_use]
     pub fn native_tls(mut self, native_tls: bool) -> Self {
         self.base_client_builder = self.base_client_builder.native_tls(native_tls);
         self
     }
 
+    #[must_use]
+    pub fn proxies(mut self, proxies: Option<ProxySettings>) -> Self {
+        self.base_client_builder = self.base_client_builder.proxies(proxies);
+        self
+    }
+
+    #[must_use]
+    pub fn cache_stats(mut self, cache_stats: CacheStatsRecorder) -> Self {
+        self.base_client_builder = self.base_client_builder.cache_stats(cache_stats);
+        self
+    }
+
     #[must_use]
     pub fn cache(mut self, cache: Cache) -> Self {
         self.cache = cache;
@@ -129,10 +160,31 @@ impl<'a> RegistryClientBuilder<'a> {
     }
 
     pub fn build(self) -> RegistryClient {
-        // Build a base client
-        let builder = self.base_client_builder;
+        // Build a base client, with any TLS and proxy settings that are specific to an index.
+        let builder = self.base_client_builder.index_tls(
+            self.index_urls
+                .defined_indexes()
+                .filter_map(|index| {
+                    let tls = TlsSettings {
+                        client_cert: index.client_cert.clone(),
+                        client_key: index.client_key.clone(),
+                        ca_cert: index.ca_cert.clone(),
+                    };
+                    (!tls.is_empty()).then(|| (index.raw_url().clone(), tls))
+                })
+                .collect(),
+        );
+        let builder = builder.index_no_proxy(
+            self.index_urls
+                .defined_indexes()
+                .filter(|index| index.proxy == Some(false))
+                .map(|index| index.raw_url().clone())
+                .collect(),
+        );
+        let builder = builder.credential_helpers(credential_helpers(&self.index_urls));
 
         let client = builder.build();
+        register_indexes(client.cache_stats(), &self.index_urls);
 
         let timeout = client.timeout();
         let connectivity = client.connectivity();
@@ -153,7 +205,11 @@ impl<'a> RegistryClientBuilder<'a> {
     /// Share the underlying client between two different middleware configurations.
     pub fn wrap_existing(self, existing: &BaseClient) -> RegistryClient {
         // Wrap in any relevant middleware and handle connectivity.
-        let client = self.base_client_builder.wrap_existing(existing);
+        let client = self
+            .base_client_builder
+            .credential_helpers(credential_helpers(&self.index_urls))
+            .wrap_existing(existing);
+        register_indexes(client.cache_stats(), &self.index_urls);
 
         let timeout = client.timeout();
         let connectivity = client.connectivity();
@@ -172,6 +228,24 @@ impl<'a> RegistryClientBuilder<'a> {
     }
 }
 
+/// Return the [`CredentialHelper`] configured for each index, if any.
+fn credential_helpers(index_urls: &IndexUrls) -> Vec<(Url, CredentialHelper)> {
+    index_urls
+        .defined_indexes()
+        .filter_map(|index| {
+            let command = index.credential_helper.as_ref()?;
+            Some((index.raw_url().clone(), CredentialHelper::new(command)))
+        })
+        .collect()
+}
+
+/// Register each index with the HTTP cache statistics, such that requests are attributed to it.
+fn register_indexes(cache_stats: &CacheStatsRecorder, index_urls: &IndexUrls) {
+    for index in index_urls.indexes() {
+        cache_stats.register_index(index.url().url());
+    }
+}
+
 impl<'a> TryFrom<BaseClientBuilder<'a>> for RegistryClientBuilder<'a> {
     type Error = std::io::Error;
 
@@ -218,6 +292,11 @@ impl RegistryClient {
         self.connectivity
     }
 
+    /// Return the proxies configured for this client, if any.
+    pub fn proxies(&self) -> Option<&ProxySettings> {
+        self.client.uncached().proxies()
+    }
+
     /// Return the timeout this client is configured with, in seconds.
     pub fn timeout(&self) -> Duration {
         self.timeout
@@ -1,2 +1,4 @@
+mod rate_limit;
 mod remote_metadata;
+mod retry_after;
 mod user_agent_version;
@@ -0,0 +1,84 @@
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::Result;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+use url::Url;
+use uv_cache::Cache;
+use uv_client::RegistryClientBuilder;
+
+#[tokio::test]
+async fn test_concurrency_per_host() -> Result<()> {
+    // Set up the TCP listener on a random available port
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+
+    // Delay each response, tracking the number of requests that are awaiting a response.
+    let active = Arc::new(AtomicUsize::new(0));
+    let max_active = Arc::new(AtomicUsize::new(0));
+    let (server_active, server_max_active) = (active.clone(), max_active.clone());
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let socket = TokioIo::new(socket);
+            let active = server_active.clone();
+            let max_active = server_max_active.clone();
+            tokio::task::spawn(async move {
+                let svc = service_fn(move |_req: Request<hyper::body::Incoming>| {
+                    let active = active.clone();
+                    let max_active = max_active.clone();
+                    async move {
+                        let current = active.fetch_add(1, Ordering::SeqCst) + 1;
+                        max_active.fetch_max(current, Ordering::SeqCst);
+                        tokio::time::sleep(Duration::from_millis(200)).await;
+                        active.fetch_sub(1, Ordering::SeqCst);
+                        Ok::<_, hyper::Error>(Response::new(Full::new(Bytes::from("ok"))))
+                    }
+                });
+                http1::Builder::new()
+                    .serve_connection(socket, svc)
+                    .await
+                    .expect("Server Started");
+            });
+        }
+    });
+
+    // Initialize uv-client, limiting the number of concurrent requests to each host.
+    let cache = Cache::temp()?.init()?;
+    let client = RegistryClientBuilder::new(cache)
+        .concurrency_per_host(Some(1))
+        .build();
+
+    // Send several requests to our dummy server at once.
+    let url = Url::from_str(&format!("http://{addr}"))?;
+    let bodies = future::try_join_all((0..3).map(|_| {
+        let url = url.clone();
+        let client = &client;
+        async move {
+            let res = client
+                .cached_client()
+                .uncached()
+                .for_host(&url)
+                .get(url)
+                .send()
+                .await?;
+            anyhow::Ok(res.text().await?)
+        }
+    }))
+    .await?;
+
+    // The requests were sent one at a time.
+    assert_eq!(bodies, vec!["ok"; 3]);
+    assert_eq!(max_active.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
@@ -0,0 +1,76 @@
+use std::str::FromStr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use futures::future;
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper::header::RETRY_AFTER;
+use hyper::server::conn::http1;
+use hyper::service::service_fn;
+use hyper::{Request, Response, StatusCode};
+use hyper_util::rt::TokioIo;
+use tokio::net::TcpListener;
+use url::Url;
+use uv_cache::Cache;
+use uv_client::RegistryClientBuilder;
+
+#[tokio::test]
+async fn test_retry_after() -> Result<()> {
+    // Set up the TCP listener on a random available port
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let addr = listener.local_addr()?;
+
+    // Respond to the first request with `429 Too Many Requests`, and to any others with `200 OK`.
+    let requests = Arc::new(AtomicUsize::new(0));
+    let server_requests = requests.clone();
+    tokio::spawn(async move {
+        loop {
+            let (socket, _) = listener.accept().await.unwrap();
+            let socket = TokioIo::new(socket);
+            let requests = server_requests.clone();
+            tokio::task::spawn(async move {
+                let svc = service_fn(move |_req: Request<hyper::body::Incoming>| {
+                    let response = if requests.fetch_add(1, Ordering::SeqCst) == 0 {
+                        Response::builder()
+                            .status(StatusCode::TOO_MANY_REQUESTS)
+                            .header(RETRY_AFTER, "2")
+                            .body(Full::new(Bytes::new()))
+                    } else {
+                        Response::builder().body(Full::new(Bytes::from("ok")))
+                    };
+                    future::ok::<_, hyper::Error>(response.unwrap())
+                });
+                http1::Builder::new()
+                    .serve_connection(socket, svc)
+                    .await
+                    .expect("Server Started");
+            });
+        }
+    });
+
+    // Initialize uv-client
+    let cache = Cache::temp()?.init()?;
+    let client = RegistryClientBuilder::new(cache).build();
+
+    // Send request to our dummy server
+    let url = Url::from_str(&format!("http://{addr}"))?;
+    let start = Instant::now();
+    let res = client
+        .cached_client()
+        .uncached()
+        .for_host(&url)
+        .get(url)
+        .send()
+        .await?;
+
+    // The request is retried, after the delay requested by the server.
+    assert!(res.status().is_success());
+    assert_eq!(res.text().await?, "ok");
+    assert_eq!(requests.load(Ordering::SeqCst), 2);
+    assert!(start.elapsed() >= Duration::from_secs(2));
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use uv_auth::{self, KeyringProvider};
+use uv_auth::{self, KeyringProvider, NativeKeyring};
 
 /// Keyring provider type to use for credential lookup.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -11,6 +11,8 @@ pub enum KeyringProviderType {
     Disabled,
     /// Use the `keyring` command for credential lookup.
     Subprocess,
+    /// Use uv's built-in, encrypted credential store for credential lookup.
+    Native,
     // /// Not yet implemented
     // Auto,
     // /// Not implemented yet. Maybe use <https://docs.rs/keyring/latest/keyring/> for this?
@@ -23,6 +25,7 @@ impl KeyringProviderType {
         match self {
             Self::Disabled => None,
             Self::Subprocess => Some(KeyringProvider::subprocess()),
+            Self::Native => NativeKeyring::from_default_location().map(KeyringProvider::native),
         }
     }
 }
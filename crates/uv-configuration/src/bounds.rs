@@ -1,3 +1,5 @@
+use uv_pep440::{Operator, Version, VersionSpecifier, VersionSpecifiers};
+
 #[derive(Debug, Default, Copy, Clone)]
 pub enum LowerBound {
     /// Allow missing lower bounds.
@@ -6,3 +8,167 @@ pub enum LowerBound {
     /// Warn about missing lower bounds.
     Warn,
 }
+
+/// The style of version bounds to write for dependencies added without a version specifier.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, serde::Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+#[cfg_attr(feature = "clap", derive(clap::ValueEnum))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum AddBoundsKind {
+    /// Only a lower bound, e.g., `>=1.2.3`.
+    #[default]
+    Lower,
+    /// Allow the same major version, similar to the semver caret, e.g., `>=1.2.3, <2.0.0`.
+    ///
+    /// Leading zeroes are skipped, e.g., `>=0.1.2, <0.2.0`.
+    Major,
+    /// Allow the same minor version, similar to the semver tilde, e.g., `>=1.2.3, <1.3.0`.
+    ///
+    /// Leading zeroes are skipped, e.g., `>=0.1.2, <0.1.3`.
+    Minor,
+    /// Pin the exact version, e.g., `==1.2.3`.
+    ///
+    /// This option is not recommended, as versions are already pinned in the uv lockfile.
+    Exact,
+}
+
+impl AddBoundsKind {
+    /// Return the version specifiers for the given (resolved) version.
+    pub fn specifiers(self, version: Version) -> VersionSpecifiers {
+        // Drop the local version identifier, which isn't permitted in `>=` constraints.
+        // For example, convert `1.2.3+local` to `1.2.3`.
+        let version = version.without_local();
+
+        match self {
+            Self::Lower => {
+                VersionSpecifiers::from(VersionSpecifier::greater_than_equal_version(version))
+            }
+            Self::Major => Self::range(version, 0),
+            Self::Minor => Self::range(version, 1),
+            Self::Exact => VersionSpecifiers::from(VersionSpecifier::equals_version(version)),
+        }
+    }
+
+    /// Infer the kind of bounds from existing version specifiers, if they match one of the
+    /// supported styles.
+    ///
+    /// For example, `>=1.2.3, <2` is inferred as [`AddBoundsKind::Major`], while `>=1.2.3, !=1.3.0`
+    /// doesn't match any style.
+    pub fn from_specifiers(specifiers: &VersionSpecifiers) -> Option<Self> {
+        match &**specifiers {
+            [specifier] => match specifier.operator() {
+                Operator::GreaterThanEqual => Some(Self::Lower),
+                Operator::Equal => Some(Self::Exact),
+                _ => None,
+            },
+            [first, second] => {
+                let (lower, upper) = if first.operator() == &Operator::LessThan {
+                    (second, first)
+                } else {
+                    (first, second)
+                };
+                if lower.operator() != &Operator::GreaterThanEqual
+                    || upper.operator() != &Operator::LessThan
+                {
+                    return None;
+                }
+                if *upper.version() == Self::upper(lower.version(), 0) {
+                    Some(Self::Major)
+                } else if *upper.version() == Self::upper(lower.version(), 1) {
+                    Some(Self::Minor)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Return a `>=` bound on the given version, along with an exclusive upper bound that
+    /// increments the release segment `offset` places after the first non-zero segment.
+    fn range(version: Version, offset: usize) -> VersionSpecifiers {
+        let upper = Self::upper(&version, offset);
+        VersionSpecifiers::from_iter([
+            VersionSpecifier::greater_than_equal_version(version),
+            VersionSpecifier::less_than_version(upper),
+        ])
+    }
+
+    /// Return the exclusive upper bound for the given version, incrementing the release segment
+    /// `offset` places after the first non-zero segment.
+    fn upper(version: &Version, offset: usize) -> Version {
+        let release = version.release();
+        let first_nonzero = release
+            .iter()
+            .position(|segment| *segment != 0)
+            .unwrap_or(release.len() - 1);
+        let index = first_nonzero + offset;
+
+        // Pad the release to include the incremented segment, e.g., `2` becomes `2.0` for `minor`.
+        let mut upper = release.to_vec();
+        if upper.len() <= index {
+            upper.resize(index + 1, 0);
+        }
+        upper[index] += 1;
+        for segment in &mut upper[index + 1..] {
+            *segment = 0;
+        }
+
+        Version::new(upper)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use uv_pep440::{Version, VersionSpecifiers};
+
+    use super::AddBoundsKind;
+
+    fn specifiers(bounds: AddBoundsKind, version: &str) -> String {
+        bounds
+            .specifiers(Version::from_str(version).unwrap())
+            .to_string()
+    }
+
+    #[test]
+    fn specifiers_for_bounds() {
+        assert_eq!(specifiers(AddBoundsKind::Lower, "1.2.3+local"), ">=1.2.3");
+        assert_eq!(specifiers(AddBoundsKind::Exact, "1.2.3"), "==1.2.3");
+
+        assert_eq!(specifiers(AddBoundsKind::Major, "1.2.3"), ">=1.2.3, <2.0.0");
+        assert_eq!(specifiers(AddBoundsKind::Major, "0.1.2"), ">=0.1.2, <0.2.0");
+        assert_eq!(specifiers(AddBoundsKind::Major, "0.0.1"), ">=0.0.1, <0.0.2");
+        assert_eq!(specifiers(AddBoundsKind::Major, "2"), ">=2, <3");
+        assert_eq!(
+            specifiers(AddBoundsKind::Major, "1.0.0b1"),
+            ">=1.0.0b1, <2.0.0"
+        );
+
+        assert_eq!(specifiers(AddBoundsKind::Minor, "1.2.3"), ">=1.2.3, <1.3.0");
+        assert_eq!(specifiers(AddBoundsKind::Minor, "0.1.2"), ">=0.1.2, <0.1.3");
+        assert_eq!(specifiers(AddBoundsKind::Minor, "2"), ">=2, <2.1");
+        assert_eq!(
+            specifiers(AddBoundsKind::Minor, "2024.1"),
+            ">=2024.1, <2024.2"
+        );
+    }
+
+    fn infer(specifiers: &str) -> Option<AddBoundsKind> {
+        AddBoundsKind::from_specifiers(&VersionSpecifiers::from_str(specifiers).unwrap())
+    }
+
+    #[test]
+    fn infer_bounds() {
+        assert_eq!(infer(">=1.2.3"), Some(AddBoundsKind::Lower));
+        assert_eq!(infer("==1.2.3"), Some(AddBoundsKind::Exact));
+        assert_eq!(infer(">=1.2.3, <2"), Some(AddBoundsKind::Major));
+        assert_eq!(infer("<2.0.0, >=1.2.3"), Some(AddBoundsKind::Major));
+        assert_eq!(infer(">=0.1.2, <0.2"), Some(AddBoundsKind::Major));
+        assert_eq!(infer(">=1.2.3, <1.3"), Some(AddBoundsKind::Minor));
+        assert_eq!(infer(">=1.2.3, <3"), None);
+        assert_eq!(infer(">=1.2.3, !=1.3.0"), None);
+        assert_eq!(infer("~=1.2"), None);
+    }
+}
@@ -15,6 +15,8 @@ pub struct Concurrency {
     ///
     /// Note this value must be non-zero.
     pub installs: usize,
+    /// The maximum number of concurrent requests to a single host, if any.
+    pub downloads_per_host: Option<usize>,
 }
 
 impl Default for Concurrency {
@@ -23,6 +25,7 @@ impl Default for Concurrency {
             downloads: Concurrency::DEFAULT_DOWNLOADS,
             builds: Concurrency::threads(),
             installs: Concurrency::threads(),
+            downloads_per_host: None,
         }
     }
 }
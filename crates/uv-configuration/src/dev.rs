@@ -243,6 +243,65 @@ impl DevGroupsSpecification {
         }
     }
 
+    /// Return a new [`DevGroupsSpecification`] that additionally includes the given groups.
+    ///
+    /// The groups are included even if they were excluded via `--no-group`.
+    #[must_use]
+    pub fn with_groups(self, groups: Vec<GroupName>) -> Self {
+        if groups.is_empty() {
+            return self;
+        }
+
+        let groups = match self.groups {
+            None => GroupsSpecification::Include {
+                include: IncludeGroups::Some(groups),
+                exclude: Vec::new(),
+            },
+            Some(GroupsSpecification::Include {
+                include: IncludeGroups::All,
+                mut exclude,
+            }) => {
+                exclude.retain(|group| !groups.contains(group));
+                GroupsSpecification::Include {
+                    include: IncludeGroups::All,
+                    exclude,
+                }
+            }
+            Some(GroupsSpecification::Include {
+                include: IncludeGroups::Some(mut include),
+                mut exclude,
+            }) => {
+                exclude.retain(|group| !groups.contains(group));
+                for group in groups {
+                    if !include.contains(&group) {
+                        include.push(group);
+                    }
+                }
+                GroupsSpecification::Include {
+                    include: IncludeGroups::Some(include),
+                    exclude,
+                }
+            }
+            Some(GroupsSpecification::Only {
+                mut include,
+                mut exclude,
+            }) => {
+                exclude.retain(|group| !groups.contains(group));
+                for group in groups {
+                    if !include.contains(&group) {
+                        include.push(group);
+                    }
+                }
+                GroupsSpecification::Only { include, exclude }
+            }
+        };
+
+        Self {
+            dev: self.dev,
+            groups: Some(groups),
+        }
+    }
+
     /// Returns `true` if the specification allows for production dependencies.
     pub fn prod(&self) -> bool {
         self.dev.as_ref().map_or(true, DevMode::prod)
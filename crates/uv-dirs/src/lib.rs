@@ -59,6 +59,15 @@ pub fn legacy_user_cache_dir() -> Option<PathBuf> {
         })
 }
 
+/// Returns an appropriate user-level directory for storing configuration.
+///
+/// Corresponds to `$XDG_CONFIG_HOME/uv` on Unix.
+pub fn user_config_dir() -> Option<PathBuf> {
+    etcetera::base_strategy::choose_base_strategy()
+        .ok()
+        .map(|dirs| dirs.config_dir().join("uv"))
+}
+
 /// Returns an appropriate user-level directory for storing application state.
 ///
 /// Corresponds to `$XDG_DATA_HOME/uv` on Unix.
@@ -1,3 +1,4 @@
+use std::path::PathBuf;
 use std::str::FromStr;
 
 use thiserror::Error;
@@ -7,6 +8,7 @@ use uv_auth::Credentials;
 
 use crate::index_name::{IndexName, IndexNameError};
 use crate::origin::Origin;
+use crate::package_name_pattern::PackageNamePattern;
 use crate::{IndexUrl, IndexUrlError};
 
 #[derive(Debug, Clone, Hash, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
@@ -81,6 +83,85 @@ pub struct Index {
     /// publish-url = "https://upload.pypi.org/legacy/"
     /// ```
     pub publish_url: Option<Url>,
+    /// The path to a PEM file containing the client certificate to present to the index, for
+    /// mutual TLS (mTLS) authentication.
+    ///
+    /// The file may also contain the certificate's private key; otherwise, the key should be
+    /// provided via `client-key`. Takes precedence over the `SSL_CLIENT_CERT` environment
+    /// variable for requests to the index.
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// client-cert = "/etc/ssl/private/internal.pem"
+    /// client-key = "/etc/ssl/private/internal.key"
+    /// ```
+    pub client_cert: Option<PathBuf>,
+    /// The path to a PEM file containing the private key for the `client-cert`, if the key isn't
+    /// included in the certificate file.
+    pub client_key: Option<PathBuf>,
+    /// The path to a PEM file containing additional CA certificates to trust for requests to the
+    /// index.
+    ///
+    /// The certificates are only trusted for hosts that match the index URL's scheme, host, and
+    /// port, rather than globally.
+    pub ca_cert: Option<PathBuf>,
+    /// A command that provides credentials for the index, akin to a Git credential helper.
+    ///
+    /// The command is run in the system shell, and receives a JSON object with the `url` being
+    /// requested on stdin. It must print a JSON object with a `username` and `password` (or a
+    /// `token`, which implies the `__token__` username) to stdout, optionally with an
+    /// `expires_at` timestamp (in RFC 3339 format) after which the helper is run again.
+    ///
+    /// The helper takes precedence over all other sources of credentials for requests to the
+    /// index's realm, unless credentials are included in the request URL.
+    ///
+    /// Since the helper runs an arbitrary command, it's only accepted in the user or system
+    /// `uv.toml`, and ignored in project configuration (i.e., a `pyproject.toml` or a project
+    /// `uv.toml`). To use a helper for an index defined by a project, define an explicit index
+    /// with the same URL in the user configuration:
+    ///
+    /// ```toml
+    /// [[index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// explicit = true
+    /// credential-helper = "internal-credential-helper --format json"
+    /// ```
+    pub credential_helper: Option<String>,
+    /// The packages to resolve exclusively from the index, as a list of package names or
+    /// patterns in which `*` matches any sequence of characters.
+    ///
+    /// Matching packages are pinned to the index, as if they were pinned via
+    /// `[tool.uv.sources]`, including when they're transitive dependencies. This applies to both
+    /// the project and `uv pip` interfaces. If a package matches the patterns of multiple indexes,
+    /// the first matching index is used; an index pinned in `[tool.uv.sources]` takes precedence
+    /// over any patterns.
+    ///
+    /// Combine with `explicit = true` to _only_ use the index for matching packages:
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// packages = ["acme-*", "internal-utils"]
+    /// explicit = true
+    /// ```
+    pub packages: Option<Vec<PackageNamePattern>>,
+    /// Whether to send requests to the index through the configured proxies.
+    ///
+    /// Set `proxy = false` to connect to the index directly, bypassing the `http-proxy` and
+    /// `https-proxy` settings and any proxies in the environment (e.g., `HTTPS_PROXY`). The bypass
+    /// applies to all requests to the index's realm (i.e., its scheme, host, and port).
+    ///
+    /// ```toml
+    /// [[tool.uv.index]]
+    /// name = "internal"
+    /// url = "https://pypi.internal.example.com/simple"
+    /// proxy = false
+    /// ```
+    pub proxy: Option<bool>,
 }
 
 // #[derive(
@@ -105,6 +186,12 @@ impl Index {
             default: true,
             origin: None,
             publish_url: None,
+            client_cert: None,
+            client_key: None,
+            ca_cert: None,
+            credential_helper: None,
+            packages: None,
+            proxy: None,
         }
     }
 
@@ -117,6 +204,12 @@ impl Index {
             default: false,
             origin: None,
             publish_url: None,
+            client_cert: None,
+            client_key: None,
+            ca_cert: None,
+            credential_helper: None,
+            packages: None,
+            proxy: None,
         }
     }
 
@@ -129,6 +222,12 @@ impl Index {
             default: false,
             origin: None,
             publish_url: None,
+            client_cert: None,
+            client_key: None,
+            ca_cert: None,
+            credential_helper: None,
+            packages: None,
+            proxy: None,
         }
     }
 
@@ -184,6 +283,12 @@ impl FromStr for Index {
                     default: false,
                     origin: None,
                     publish_url: None,
+                    client_cert: None,
+                    client_key: None,
+                    ca_cert: None,
+                    credential_helper: None,
+                    packages: None,
+                    proxy: None,
                 });
             }
         }
@@ -197,6 +302,12 @@ impl FromStr for Index {
             default: false,
             origin: None,
             publish_url: None,
+            client_cert: None,
+            client_key: None,
+            ca_cert: None,
+            credential_helper: None,
+            packages: None,
+            proxy: None,
         })
     }
 }
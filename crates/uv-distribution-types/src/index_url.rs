@@ -413,6 +413,11 @@ impl<'a> IndexUrls {
             .chain(self.default_index())
             .filter(|index| !index.explicit)
     }
+
+    /// Return an iterator over all user-defined [`Index`] entries, including explicit indexes.
+    pub fn defined_indexes(&'a self) -> impl Iterator<Item = &'a Index> + 'a {
+        self.indexes.iter()
+    }
 }
 
 bitflags::bitflags! {
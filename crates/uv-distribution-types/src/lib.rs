@@ -65,6 +65,7 @@ pub use crate::index_name::*;
 pub use crate::index_url::*;
 pub use crate::installed::*;
 pub use crate::origin::*;
+pub use crate::package_name_pattern::*;
 pub use crate::pip_index::*;
 pub use crate::prioritized_distribution::*;
 pub use crate::resolution::*;
@@ -88,6 +89,7 @@ mod index_name;
 mod index_url;
 mod installed;
 mod origin;
+mod package_name_pattern;
 mod pip_index;
 mod prioritized_distribution;
 mod resolution;
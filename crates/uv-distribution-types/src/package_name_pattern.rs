@@ -0,0 +1,137 @@
+use std::str::FromStr;
+
+use thiserror::Error;
+
+use uv_normalize::PackageName;
+
+/// A pattern that matches package names, in which `*` matches any sequence of characters.
+///
+/// Patterns are normalized in the same way as package names, such that `Foo_Bar-*` matches
+/// `foo-bar-baz`.
+#[derive(Debug, Clone, Hash, Eq, PartialEq, serde::Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct PackageNamePattern(String);
+
+impl PackageNamePattern {
+    /// Validates and normalizes the given pattern.
+    pub fn new(pattern: &str) -> Result<Self, PackageNamePatternError> {
+        if pattern.is_empty() {
+            return Err(PackageNamePatternError::Empty);
+        }
+
+        let mut normalized = String::with_capacity(pattern.len());
+        let mut last = None;
+        for c in pattern.chars() {
+            match c {
+                'A'..='Z' => normalized.push(c.to_ascii_lowercase()),
+                'a'..='z' | '0'..='9' | '*' => normalized.push(c),
+                '-' | '_' | '.' => {
+                    // Runs of separators are normalized to a single `-`.
+                    if !matches!(last, Some('-' | '_' | '.')) {
+                        normalized.push('-');
+                    }
+                }
+                c => {
+                    return Err(PackageNamePatternError::UnsupportedCharacter(
+                        c,
+                        pattern.to_string(),
+                    ))
+                }
+            }
+            last = Some(c);
+        }
+        Ok(Self(normalized))
+    }
+
+    /// Returns `true` if the pattern matches the given package name.
+    pub fn matches(&self, name: &PackageName) -> bool {
+        let pattern = self.0.as_bytes();
+        let name = name.as_ref().as_bytes();
+
+        // The position of the last `*` in the pattern, and the position in the name it matched up
+        // to, for backtracking.
+        let mut star = None;
+        let (mut p, mut n) = (0, 0);
+        while n < name.len() {
+            if p < pattern.len() && pattern[p] == b'*' {
+                star = Some((p, n));
+                p += 1;
+            } else if p < pattern.len() && pattern[p] == name[n] {
+                p += 1;
+                n += 1;
+            } else if let Some((star_p, star_n)) = star {
+                // Extend the match of the last `*` by one character.
+                p = star_p + 1;
+                n = star_n + 1;
+                star = Some((star_p, star_n + 1));
+            } else {
+                return false;
+            }
+        }
+        pattern[p..].iter().all(|&c| c == b'*')
+    }
+}
+
+impl FromStr for PackageNamePattern {
+    type Err = PackageNamePatternError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for PackageNamePattern {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let pattern = String::deserialize(deserializer)?;
+        PackageNamePattern::new(&pattern).map_err(serde::de::Error::custom)
+    }
+}
+
+impl std::fmt::Display for PackageNamePattern {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// An error that can occur when parsing a [`PackageNamePattern`].
+#[derive(Error, Debug)]
+pub enum PackageNamePatternError {
+    #[error("Package name patterns must not be empty")]
+    Empty,
+    #[error("Package name patterns may only contain letters, digits, hyphens, underscores, periods, and `*`, but found unsupported character (`{0}`) in: `{1}`")]
+    UnsupportedCharacter(char, String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(pattern: &str, name: &str) -> bool {
+        PackageNamePattern::new(pattern)
+            .unwrap()
+            .matches(&PackageName::from_str(name).unwrap())
+    }
+
+    #[test]
+    fn pattern_matches() {
+        assert!(matches("anyio", "anyio"));
+        assert!(matches("Any_IO", "any-io"));
+        assert!(matches("acme-*", "acme-utils"));
+        assert!(matches("acme.*", "acme_utils"));
+        assert!(matches("*-plugin", "acme-plugin"));
+        assert!(matches("a*b*c", "aXbYbZc"));
+        assert!(matches("*", "anything"));
+        assert!(!matches("acme-*", "acme"));
+        assert!(!matches("acme-*", "other-acme-utils"));
+        assert!(!matches("anyio", "anyio2"));
+    }
+
+    #[test]
+    fn pattern_invalid() {
+        assert!(PackageNamePattern::new("").is_err());
+        assert!(PackageNamePattern::new("acme/*").is_err());
+    }
+}
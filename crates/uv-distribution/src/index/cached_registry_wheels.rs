@@ -0,0 +1,93 @@
+use std::path::{Path, PathBuf};
+
+use uv_cache::{Cache, CacheBucket};
+use uv_distribution_filename::WheelFilename;
+use uv_fs::{directories, files, symlinks};
+
+use crate::index::cached_wheel::CachedWheel;
+use crate::source::{HttpRevisionPointer, LocalRevisionPointer, HTTP_REVISION, LOCAL_REVISION};
+
+/// A wheel in the cache that originates from a registry, like `PyPI`.
+#[derive(Debug, Clone)]
+pub struct CachedRegistryWheel {
+    /// The filename of the wheel.
+    pub filename: WheelFilename,
+    /// The path to the unzipped wheel in the cache.
+    pub path: PathBuf,
+    /// Whether the wheel was built from source (true), or downloaded from the registry directly (false).
+    pub built: bool,
+}
+
+/// Return every wheel in the cache that originates from a registry, across all indexes and
+/// platforms.
+///
+/// Unlike the [`crate::RegistryWheelIndex`], wheels are not filtered by compatibility or hash
+/// policy, and the index from which each wheel was fetched is not retained.
+pub fn cached_registry_wheels(cache: &Cache) -> Vec<CachedRegistryWheel> {
+    let mut wheels = vec![];
+
+    // Index all the wheels that were downloaded directly from the registry. The cache structure
+    // is: `<index>/<package-name>/<wheel>.http` or `<index>/<package-name>/<wheel>.rev`.
+    for root in registry_roots(&cache.bucket(CacheBucket::Wheels)) {
+        for package_dir in directories(root) {
+            for file in files(&package_dir) {
+                let wheel = match file.extension().and_then(|ext| ext.to_str()) {
+                    Some("http") => CachedWheel::from_http_pointer(&file, cache),
+                    Some("rev") => CachedWheel::from_local_pointer(&file, cache),
+                    _ => None,
+                };
+                let Some(wheel) = wheel else {
+                    continue;
+                };
+
+                // The pointer may outlive the archive (e.g., after `uv cache prune`).
+                if !wheel.entry.path().is_dir() {
+                    continue;
+                }
+
+                wheels.push(CachedRegistryWheel {
+                    filename: wheel.filename,
+                    path: wheel.entry.into_path_buf(),
+                    built: false,
+                });
+            }
+        }
+    }
+
+    // Index all the built wheels, created by downloading and building source distributions from
+    // the registry. The cache structure is: `<index>/<package-name>/<version>/`.
+    for root in registry_roots(&cache.bucket(CacheBucket::SourceDistributions)) {
+        for package_dir in directories(root) {
+            for version_dir in directories(package_dir) {
+                let revision = if let Ok(Some(pointer)) =
+                    HttpRevisionPointer::read_from(version_dir.join(HTTP_REVISION))
+                {
+                    pointer.into_revision()
+                } else if let Ok(Some(pointer)) =
+                    LocalRevisionPointer::read_from(version_dir.join(LOCAL_REVISION))
+                {
+                    pointer.into_revision()
+                } else {
+                    continue;
+                };
+
+                for wheel_dir in symlinks(version_dir.join(revision.id())) {
+                    if let Some(wheel) = CachedWheel::from_built_source(wheel_dir) {
+                        wheels.push(CachedRegistryWheel {
+                            filename: wheel.filename,
+                            path: wheel.entry.into_path_buf(),
+                            built: true,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    wheels
+}
+
+/// Return the per-index directories within a cache bucket: `pypi` and `index/<digest>`.
+fn registry_roots(bucket: &Path) -> impl Iterator<Item = PathBuf> {
+    std::iter::once(bucket.join("pypi")).chain(directories(bucket.join("index")))
+}
@@ -1,6 +1,8 @@
 pub use built_wheel_index::BuiltWheelIndex;
+pub use cached_registry_wheels::{cached_registry_wheels, CachedRegistryWheel};
 pub use registry_wheel_index::RegistryWheelIndex;
 
 mod built_wheel_index;
+mod cached_registry_wheels;
 mod cached_wheel;
 mod registry_wheel_index;
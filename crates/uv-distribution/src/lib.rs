@@ -1,7 +1,7 @@
 pub use distribution_database::{DistributionDatabase, HttpArchivePointer, LocalArchivePointer};
 pub use download::LocalWheel;
 pub use error::Error;
-pub use index::{BuiltWheelIndex, RegistryWheelIndex};
+pub use index::{cached_registry_wheels, BuiltWheelIndex, CachedRegistryWheel, RegistryWheelIndex};
 pub use metadata::{
     ArchiveMetadata, BuildRequires, LoweredRequirement, Metadata, MetadataError, RequiresDist,
 };
@@ -33,6 +33,7 @@ use uv_distribution_types::{
 };
 use uv_extract::hash::Hasher;
 use uv_fs::{rename_with_retry, write_atomic, LockedFile};
+use uv_git::GitProxyEnv;
 use uv_metadata::read_archive_metadata;
 use uv_normalize::PackageName;
 use uv_pep440::{release_specifiers_to_ranges, Version};
@@ -1318,6 +1319,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .fetch(
                 resource.git,
                 client.unmanaged.uncached_client(resource.url).clone(),
+                git_proxy_env(client.unmanaged),
                 self.build_context.cache().bucket(CacheBucket::Git),
                 self.reporter.clone().map(Facade::from),
             )
@@ -1415,6 +1417,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
             .fetch(
                 resource.git,
                 client.unmanaged.uncached_client(resource.url).clone(),
+                git_proxy_env(client.unmanaged),
                 self.build_context.cache().bucket(CacheBucket::Git),
                 self.reporter.clone().map(Facade::from),
             )
@@ -1591,6 +1594,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .fetch(
                         &source.git,
                         client.unmanaged.uncached_client(&source.url).clone(),
+                        git_proxy_env(client.unmanaged),
                         self.build_context.cache().bucket(CacheBucket::Git),
                         self.reporter.clone().map(Facade::from),
                     )
@@ -1602,6 +1606,7 @@ impl<'a, T: BuildContext> SourceDistributionBuilder<'a, T> {
                     .fetch(
                         source.git,
                         client.unmanaged.uncached_client(source.url).clone(),
+                        git_proxy_env(client.unmanaged),
                         self.build_context.cache().bucket(CacheBucket::Git),
                         self.reporter.clone().map(Facade::from),
                     )
@@ -2570,3 +2575,17 @@ async fn lock_shard(cache_shard: &CacheShard) -> Result<LockedFile, Error> {
 
     Ok(lock)
 }
+
+/// Return the proxy environment for any `git` commands, matching the proxies of the HTTP client.
+fn git_proxy_env(client: &RegistryClient) -> GitProxyEnv {
+    client
+        .proxies()
+        .map(|proxies| {
+            GitProxyEnv::new(
+                proxies.http.as_ref(),
+                proxies.https.as_ref(),
+                &proxies.no_proxy,
+            )
+        })
+        .unwrap_or_default()
+}
@@ -37,6 +37,42 @@ pub static GIT: LazyLock<Result<PathBuf, GitError>> = LazyLock::new(|| {
     })
 });
 
+/// The proxy environment variables to set on any `git` command that accesses the network.
+///
+/// The proxies are passed to `git` via the environment variables understood by `libcurl`, in both
+/// upper and lower case, since `libcurl` only reads `http_proxy` in lower case and prefers the
+/// lower-case variants of the others.
+#[derive(Debug, Default, Clone)]
+pub struct GitProxyEnv(Vec<(String, String)>);
+
+impl GitProxyEnv {
+    /// Create the proxy environment for the given proxies.
+    pub fn new(http: Option<&Url>, https: Option<&Url>, no_proxy: &[String]) -> Self {
+        let mut env = Vec::new();
+        if let Some(http) = http {
+            env.push((EnvVars::HTTP_PROXY.to_lowercase(), http.to_string()));
+        }
+        if let Some(https) = https {
+            env.push((EnvVars::HTTPS_PROXY.to_string(), https.to_string()));
+            env.push((EnvVars::HTTPS_PROXY.to_lowercase(), https.to_string()));
+        }
+        if !no_proxy.is_empty() {
+            let no_proxy = no_proxy.join(",");
+            env.push((EnvVars::NO_PROXY.to_string(), no_proxy.clone()));
+            env.push((EnvVars::NO_PROXY.to_lowercase(), no_proxy));
+        }
+        Self(env)
+    }
+
+    /// Set the proxy environment variables on a `git` command.
+    fn apply<'a>(&self, cmd: &'a mut ProcessBuilder) -> &'a mut ProcessBuilder {
+        for (key, value) in &self.0 {
+            cmd.env(key, value);
+        }
+        cmd
+    }
+}
+
 /// A reference to commit or commit-ish.
 #[derive(
     Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
@@ -248,12 +284,19 @@ impl GitRemote {
         reference: &GitReference,
         locked_rev: Option<GitOid>,
         client: &ClientWithMiddleware,
+        proxy_env: &GitProxyEnv,
     ) -> Result<(GitDatabase, GitOid)> {
         let locked_ref = locked_rev.map(|oid| GitReference::FullCommit(oid.to_string()));
         let reference = locked_ref.as_ref().unwrap_or(reference);
         if let Some(mut db) = db {
-            fetch(&mut db.repo, self.url.as_str(), reference, client)
-                .with_context(|| format!("failed to fetch into: {}", into.user_display()))?;
+            fetch(
+                &mut db.repo,
+                self.url.as_str(),
+                reference,
+                client,
+                proxy_env,
+            )
+            .with_context(|| format!("failed to fetch into: {}", into.user_display()))?;
 
             let resolved_commit_hash = match locked_rev {
                 Some(rev) => db.contains(rev).then_some(rev),
@@ -274,7 +317,7 @@ impl GitRemote {
 
         paths::create_dir_all(into)?;
         let mut repo = GitRepository::init(into)?;
-        fetch(&mut repo, self.url.as_str(), reference, client)
+        fetch(&mut repo, self.url.as_str(), reference, client, proxy_env)
             .with_context(|| format!("failed to clone into: {}", into.user_display()))?;
         let rev = match locked_rev {
             Some(rev) => rev,
@@ -294,7 +337,12 @@ impl GitRemote {
 
 impl GitDatabase {
     /// Checkouts to a revision at `destination` from this database.
-    pub(crate) fn copy_to(&self, rev: GitOid, destination: &Path) -> Result<GitCheckout> {
+    pub(crate) fn copy_to(
+        &self,
+        rev: GitOid,
+        destination: &Path,
+        proxy_env: &GitProxyEnv,
+    ) -> Result<GitCheckout> {
         // If the existing checkout exists, and it is fresh, use it.
         // A non-fresh checkout can happen if the checkout operation was
         // interrupted. In that case, the checkout gets deleted and a new
@@ -305,7 +353,7 @@ impl GitDatabase {
             .filter(GitCheckout::is_fresh)
         {
             Some(co) => co,
-            None => GitCheckout::clone_into(destination, self, rev)?,
+            None => GitCheckout::clone_into(destination, self, rev, proxy_env)?,
         };
         Ok(checkout)
     }
@@ -379,7 +427,12 @@ impl GitCheckout {
 
     /// Clone a repo for a `revision` into a local path from a `database`.
     /// This is a filesystem-to-filesystem clone.
-    fn clone_into(into: &Path, database: &GitDatabase, revision: GitOid) -> Result<Self> {
+    fn clone_into(
+        into: &Path,
+        database: &GitDatabase,
+        revision: GitOid,
+        proxy_env: &GitProxyEnv,
+    ) -> Result<Self> {
         let dirname = into.parent().unwrap();
         paths::create_dir_all(dirname)?;
         if into.exists() {
@@ -401,7 +454,7 @@ impl GitCheckout {
 
         let repo = GitRepository::open(into)?;
         let checkout = GitCheckout::new(revision, repo);
-        checkout.reset()?;
+        checkout.reset(proxy_env)?;
         Ok(checkout)
     }
 
@@ -429,7 +482,7 @@ impl GitCheckout {
     /// *doesn't* exist, and then once we're done we create the file.
     ///
     /// [`.cargo-ok`]: CHECKOUT_READY_LOCK
-    fn reset(&self) -> Result<()> {
+    fn reset(&self, proxy_env: &GitProxyEnv) -> Result<()> {
         let ok_file = self.repo.path.join(CHECKOUT_READY_LOCK);
         let _ = paths::remove_file(&ok_file);
         debug!("reset {} to {}", self.repo.path.display(), self.revision);
@@ -443,12 +496,15 @@ impl GitCheckout {
             .exec_with_output()?;
 
         // Update submodules (`git submodule update --recursive`).
-        ProcessBuilder::new(GIT.as_ref()?)
-            .arg("submodule")
-            .arg("update")
-            .arg("--recursive")
-            .arg("--init")
-            .cwd(&self.repo.path)
+        proxy_env
+            .apply(
+                ProcessBuilder::new(GIT.as_ref()?)
+                    .arg("submodule")
+                    .arg("update")
+                    .arg("--recursive")
+                    .arg("--init")
+                    .cwd(&self.repo.path),
+            )
             .exec_with_output()
             .map(drop)?;
 
@@ -470,6 +526,7 @@ pub(crate) fn fetch(
     remote_url: &str,
     reference: &GitReference,
     client: &ClientWithMiddleware,
+    proxy_env: &GitProxyEnv,
 ) -> Result<()> {
     let oid_to_fetch = match github_fast_path(repo, remote_url, reference, client) {
         Ok(FastPathRev::UpToDate) => return Ok(()),
@@ -556,14 +613,21 @@ pub(crate) fn fetch(
 
     debug!("Performing a Git fetch for: {remote_url}");
     let result = match refspec_strategy {
-        RefspecStrategy::All => fetch_with_cli(repo, remote_url, refspecs.as_slice(), tags),
+        RefspecStrategy::All => {
+            fetch_with_cli(repo, remote_url, refspecs.as_slice(), tags, proxy_env)
+        }
         RefspecStrategy::First => {
             // Try each refspec
             let mut errors = refspecs
                 .iter()
                 .map_while(|refspec| {
-                    let fetch_result =
-                        fetch_with_cli(repo, remote_url, std::slice::from_ref(refspec), tags);
+                    let fetch_result = fetch_with_cli(
+                        repo,
+                        remote_url,
+                        std::slice::from_ref(refspec),
+                        tags,
+                        proxy_env,
+                    );
 
                     // Stop after the first success and log failures
                     match fetch_result {
@@ -608,6 +672,7 @@ fn fetch_with_cli(
     url: &str,
     refspecs: &[String],
     tags: bool,
+    proxy_env: &GitProxyEnv,
 ) -> Result<()> {
     let mut cmd = ProcessBuilder::new(GIT.as_ref()?);
     cmd.arg("fetch");
@@ -630,6 +695,7 @@ fn fetch_with_cli(
         .env_remove(EnvVars::GIT_OBJECT_DIRECTORY)
         .env_remove(EnvVars::GIT_ALTERNATE_OBJECT_DIRECTORIES)
         .cwd(&repo.path);
+    proxy_env.apply(&mut cmd);
 
     // We capture the output to avoid streaming it to the user's console during clones.
     // The required `on...line` callbacks currently do nothing.
@@ -782,3 +848,49 @@ fn is_short_hash_of(rev: &str, oid: GitOid) -> bool {
         None => false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn proxy_env() {
+        let http = Url::parse("http://proxy.example.com:3128").unwrap();
+        let https = Url::parse("http://secure-proxy.example.com:3128").unwrap();
+        let proxy_env = GitProxyEnv::new(
+            Some(&http),
+            Some(&https),
+            &["localhost".to_string(), ".internal".to_string()],
+        );
+
+        let mut cmd = ProcessBuilder::new("git");
+        proxy_env.apply(&mut cmd);
+        let env = cmd
+            .get_envs()
+            .iter()
+            .map(|(key, value)| {
+                (
+                    key.as_str(),
+                    value.as_ref().and_then(|value| value.to_str()),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        // `libcurl` ignores the upper-case `HTTP_PROXY`, so only the lower-case variant is set.
+        assert_eq!(
+            env,
+            vec![
+                ("HTTPS_PROXY", Some("http://secure-proxy.example.com:3128/")),
+                ("NO_PROXY", Some("localhost,.internal")),
+                ("http_proxy", Some("http://proxy.example.com:3128/")),
+                ("https_proxy", Some("http://secure-proxy.example.com:3128/")),
+                ("no_proxy", Some("localhost,.internal")),
+            ]
+        );
+
+        // Without any proxies, the environment is left untouched.
+        let mut cmd = ProcessBuilder::new("git");
+        GitProxyEnv::default().apply(&mut cmd);
+        assert!(cmd.get_envs().is_empty());
+    }
+}
@@ -11,7 +11,7 @@ use reqwest_middleware::ClientWithMiddleware;
 use uv_cache_key::{cache_digest, RepositoryUrl};
 use uv_fs::LockedFile;
 
-use crate::{Fetch, GitReference, GitSha, GitSource, GitUrl, Reporter};
+use crate::{Fetch, GitProxyEnv, GitReference, GitSha, GitSource, GitUrl, Reporter};
 
 #[derive(Debug, thiserror::Error)]
 pub enum GitResolverError {
@@ -43,6 +43,7 @@ impl GitResolver {
         &self,
         url: &GitUrl,
         client: ClientWithMiddleware,
+        proxy_env: GitProxyEnv,
         cache: PathBuf,
         reporter: Option<impl Reporter + 'static>,
     ) -> Result<GitSha, GitResolverError> {
@@ -67,9 +68,11 @@ impl GitResolver {
 
         // Fetch the Git repository.
         let source = if let Some(reporter) = reporter {
-            GitSource::new(url.clone(), client, cache).with_reporter(reporter)
-        } else {
             GitSource::new(url.clone(), client, cache)
+                .with_proxy_env(proxy_env)
+                .with_reporter(reporter)
+        } else {
+            GitSource::new(url.clone(), client, cache).with_proxy_env(proxy_env)
         };
         let precise = tokio::task::spawn_blocking(move || source.resolve())
             .await?
@@ -87,6 +90,7 @@ impl GitResolver {
         &self,
         url: &GitUrl,
         client: ClientWithMiddleware,
+        proxy_env: GitProxyEnv,
         cache: PathBuf,
         reporter: Option<impl Reporter + 'static>,
     ) -> Result<Fetch, GitResolverError> {
@@ -116,9 +120,11 @@ impl GitResolver {
 
         // Fetch the Git repository.
         let source = if let Some(reporter) = reporter {
-            GitSource::new(url.as_ref().clone(), client, cache).with_reporter(reporter)
-        } else {
             GitSource::new(url.as_ref().clone(), client, cache)
+                .with_proxy_env(proxy_env)
+                .with_reporter(reporter)
+        } else {
+            GitSource::new(url.as_ref().clone(), client, cache).with_proxy_env(proxy_env)
         };
         let fetch = tokio::task::spawn_blocking(move || source.fetch())
             .await?
@@ -12,7 +12,7 @@ use url::Url;
 
 use uv_cache_key::{cache_digest, RepositoryUrl};
 
-use crate::git::GitRemote;
+use crate::git::{GitProxyEnv, GitRemote};
 use crate::{GitOid, GitSha, GitUrl, GIT_STORE};
 
 /// A remote Git source that can be checked out locally.
@@ -21,6 +21,8 @@ pub struct GitSource {
     git: GitUrl,
     /// The HTTP client to use for fetching.
     client: ClientWithMiddleware,
+    /// The proxy environment for any `git` commands.
+    proxy_env: GitProxyEnv,
     /// The path to the Git source database.
     cache: PathBuf,
     /// The reporter to use for this source.
@@ -37,6 +39,7 @@ impl GitSource {
         Self {
             git,
             client: client.into(),
+            proxy_env: GitProxyEnv::default(),
             cache: cache.into(),
             reporter: None,
         }
@@ -51,6 +54,12 @@ impl GitSource {
         }
     }
 
+    /// Set the proxy environment to use for any `git` commands.
+    #[must_use]
+    pub fn with_proxy_env(self, proxy_env: GitProxyEnv) -> Self {
+        Self { proxy_env, ..self }
+    }
+
     /// Resolve a Git source to a specific revision.
     #[instrument(skip(self), fields(repository = %self.git.repository, rev = ?self.git.precise))]
     pub fn resolve(self) -> Result<GitSha> {
@@ -95,6 +104,7 @@ impl GitSource {
                     &self.git.reference,
                     locked_rev.map(GitOid::from),
                     &self.client,
+                    &self.proxy_env,
                 )?;
 
                 (db, GitSha::from(actual_rev), task)
@@ -157,6 +167,7 @@ impl GitSource {
                     &self.git.reference,
                     locked_rev.map(GitOid::from),
                     &self.client,
+                    &self.proxy_env,
                 )?;
 
                 (db, GitSha::from(actual_rev), task)
@@ -176,7 +187,7 @@ impl GitSource {
             .join(&ident)
             .join(short_id.as_str());
 
-        db.copy_to(actual_rev.into(), &checkout_path)?;
+        db.copy_to(actual_rev.into(), &checkout_path, &self.proxy_env)?;
 
         // Report the checkout operation to the reporter.
         if let Some(task) = task {
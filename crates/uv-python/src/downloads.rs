@@ -646,6 +646,19 @@ impl ManagedPythonDownload {
         self.key.version()
     }
 
+    /// Returns `true` if the distribution would be read from a local `file://` URL, i.e., if the
+    /// relevant mirror points to a directory on disk.
+    ///
+    /// Local downloads don't require network access, so they're permitted in offline mode.
+    pub fn is_local(
+        &self,
+        python_install_mirror: Option<&str>,
+        pypy_install_mirror: Option<&str>,
+    ) -> bool {
+        self.download_url(python_install_mirror, pypy_install_mirror)
+            .is_ok_and(|url| url.scheme() == "file")
+    }
+
     /// Return the [`Url`] to use when downloading the distribution. If a mirror is set via the
     /// appropriate environment variable, use it instead.
     fn download_url(
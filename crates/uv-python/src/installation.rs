@@ -98,7 +98,12 @@ impl PythonInstallation {
             Err(Error::MissingPython(err))
                 if preference.allows_managed()
                     && python_downloads.is_automatic()
-                    && client_builder.connectivity.is_online() =>
+                    && (client_builder.connectivity.is_online()
+                        || is_local_download(
+                            request,
+                            python_install_mirror,
+                            pypy_install_mirror,
+                        )) =>
             {
                 if let Some(request) = PythonDownloadRequest::from_request(request) {
                     debug!("Requested Python not found, checking for available download...");
@@ -235,6 +240,19 @@ impl PythonInstallation {
     }
 }
 
+/// Returns `true` if the download for the given request would be read from a local mirror, and
+/// can thus be performed without network access.
+fn is_local_download(
+    request: &PythonRequest,
+    python_install_mirror: Option<&str>,
+    pypy_install_mirror: Option<&str>,
+) -> bool {
+    PythonDownloadRequest::from_request(request)
+        .and_then(|request| request.fill().ok())
+        .and_then(|request| ManagedPythonDownload::from_request(&request).ok())
+        .is_some_and(|download| download.is_local(python_install_mirror, pypy_install_mirror))
+}
+
 #[derive(Error, Debug)]
 pub enum PythonInstallationKeyError {
     #[error("Failed to parse Python installation key `{0}`: {1}")]
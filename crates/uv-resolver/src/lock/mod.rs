@@ -859,10 +859,102 @@ impl Lock {
         Ok(doc.to_string())
     }
 
+    /// Returns the names of all packages that depend on any of the given packages, directly or
+    /// transitively, including the given packages themselves.
+    ///
+    /// Edges from optional dependencies and dependency groups are included.
+    pub fn dependents(&self, names: &BTreeSet<PackageName>) -> BTreeSet<PackageName> {
+        // Invert the dependency graph.
+        let mut reverse: FxHashMap<&PackageId, Vec<&PackageId>> = FxHashMap::default();
+        for package in &self.packages {
+            for dependency in package
+                .dependencies
+                .iter()
+                .chain(package.optional_dependencies.values().flatten())
+                .chain(package.dependency_groups.values().flatten())
+            {
+                reverse
+                    .entry(&dependency.package_id)
+                    .or_default()
+                    .push(&package.id);
+            }
+        }
+
+        // Walk the inverted graph, starting from the given packages.
+        let mut seen = FxHashSet::default();
+        let mut queue: VecDeque<&PackageId> = self
+            .packages
+            .iter()
+            .filter(|package| names.contains(&package.id.name))
+            .map(|package| &package.id)
+            .collect();
+        while let Some(id) = queue.pop_front() {
+            if !seen.insert(id) {
+                continue;
+            }
+            if let Some(dependents) = reverse.get(id) {
+                queue.extend(dependents.iter().copied());
+            }
+        }
+
+        seen.into_iter().map(|id| id.name.clone()).collect()
+    }
+
+    /// Returns the direct dependencies of the given package, including its optional dependencies
+    /// and dependency groups.
+    pub fn direct_dependencies<'lock>(
+        &'lock self,
+        package: &'lock Package,
+    ) -> impl Iterator<Item = &'lock Package> + 'lock {
+        package
+            .dependencies
+            .iter()
+            .chain(package.optional_dependencies.values().flatten())
+            .chain(package.dependency_groups.values().flatten())
+            .map(|dependency| self.find_by_id(&dependency.package_id))
+    }
+
+    /// Returns the given package along with all packages it depends on, directly or transitively.
+    ///
+    /// Edges from optional dependencies are only followed for the extras that are enabled by a
+    /// dependent; dependency groups are excluded.
+    pub fn transitive_dependencies<'lock>(
+        &'lock self,
+        package: &'lock Package,
+    ) -> Vec<&'lock Package> {
+        let mut seen: FxHashSet<(&PackageId, Option<&ExtraName>)> = FxHashSet::default();
+        let mut packages = Vec::new();
+        let mut queue: VecDeque<(&Package, Option<&ExtraName>)> = VecDeque::from([(package, None)]);
+        while let Some((package, extra)) = queue.pop_front() {
+            if !seen.insert((&package.id, extra)) {
+                continue;
+            }
+            let dependencies = match extra {
+                None => {
+                    packages.push(package);
+                    package.dependencies.as_slice()
+                }
+                Some(extra) => package
+                    .optional_dependencies
+                    .get(extra)
+                    .map(Vec::as_slice)
+                    .unwrap_or_default(),
+            };
+            for dependency in dependencies {
+                let dependency_package = self.find_by_id(&dependency.package_id);
+                queue.push_back((dependency_package, None));
+                for extra in &dependency.extra {
+                    queue.push_back((dependency_package, Some(extra)));
+                }
+            }
+        }
+        packages
+    }
+
     /// Returns the package with the given name. If there are multiple
     /// matching packages, then an error is returned. If there are no
     /// matching packages, then `Ok(None)` is returned.
-    fn find_by_name(&self, name: &PackageName) -> Result<Option<&Package>, String> {
+    pub fn find_by_name(&self, name: &PackageName) -> Result<Option<&Package>, String> {
         let mut found_dist = None;
         for dist in &self.packages {
             if &dist.id.name == name {
@@ -1122,6 +1214,78 @@ impl Lock {
                 .collect::<BTreeSet<_>>()
         });
 
+        // Collect the indexes that packages are pinned to via `packages` patterns, in priority
+        // order.
+        let patterns = indexes
+            .into_iter()
+            .flat_map(IndexLocations::simple_indexes)
+            .filter_map(|index| {
+                let patterns = index.packages.as_ref()?;
+                let source = match &index.url {
+                    IndexUrl::Pypi(_) | IndexUrl::Url(_) => {
+                        RegistrySource::Url(UrlString::from(index.url.redacted()))
+                    }
+                    IndexUrl::Path(url) => {
+                        let path = url.to_file_path().ok()?;
+                        let path = relative_to(&path, root)
+                            .or_else(|_| std::path::absolute(path))
+                            .ok()?;
+                        RegistrySource::Path(path)
+                    }
+                };
+                Some((patterns, source))
+            })
+            .collect::<Vec<_>>();
+
+        if !patterns.is_empty() {
+            // Packages that are pinned to an index via `tool.uv.sources` take precedence over any
+            // patterns.
+            let pinned = self
+                .manifest
+                .requirements
+                .iter()
+                .chain(self.manifest.dependency_groups.values().flatten())
+                .chain(self.packages.iter().flat_map(|package| {
+                    package
+                        .metadata
+                        .requires_dist
+                        .iter()
+                        .chain(package.metadata.dependency_groups.values().flatten())
+                }))
+                .filter(|requirement| {
+                    matches!(
+                        requirement.source,
+                        RequirementSource::Registry { index: Some(_), .. }
+                    )
+                })
+                .map(|requirement| &requirement.name)
+                .collect::<FxHashSet<_>>();
+
+            // If a package matches the patterns of an index, it must be locked to that index.
+            for package in &self.packages {
+                let Source::Registry(source) = &package.id.source else {
+                    continue;
+                };
+                if pinned.contains(&package.id.name) {
+                    continue;
+                }
+                let Some((_, expected)) = patterns.iter().find(|(patterns, _)| {
+                    patterns
+                        .iter()
+                        .any(|pattern| pattern.matches(&package.id.name))
+                }) else {
+                    continue;
+                };
+                if source != expected {
+                    return Ok(SatisfiesResult::MismatchedIndex(
+                        &package.id.name,
+                        &package.id.version,
+                        expected.to_string(),
+                    ));
+                }
+            }
+        }
+
         // Add the workspace packages to the queue.
         for root_name in packages.keys() {
             let root = self
@@ -1379,6 +1543,9 @@ pub enum SatisfiesResult<'lock> {
     MissingRemoteIndex(&'lock PackageName, &'lock Version, &'lock UrlString),
     /// The lockfile referenced a local index that was not provided
     MissingLocalIndex(&'lock PackageName, &'lock Version, &'lock PathBuf),
+    /// A package in the lockfile was resolved from a different index than the one its name is
+    /// pinned to via the `packages` patterns of an index.
+    MismatchedIndex(&'lock PackageName, &'lock Version, String),
     /// A package in the lockfile contains different `requires-dist` metadata than expected.
     MismatchedPackageRequirements(
         &'lock PackageName,
@@ -2276,6 +2443,29 @@ impl Package {
         }
     }
 
+    /// Returns the files for all distributions of the package, if it is a registry source.
+    ///
+    /// Includes the source distribution, if any, and the wheels for all platforms.
+    pub fn registry_files(
+        &self,
+        workspace_root: &Path,
+    ) -> Result<Vec<uv_distribution_types::File>, LockError> {
+        let Source::Registry(source) = &self.id.source else {
+            return Ok(Vec::new());
+        };
+
+        let mut files = Vec::with_capacity(self.wheels.len() + 1);
+        if let Some(uv_distribution_types::SourceDist::Registry(sdist)) =
+            self.to_source_dist(workspace_root)?
+        {
+            files.push(*sdist.file);
+        }
+        for wheel in &self.wheels {
+            files.push(*wheel.to_registry_dist(source, workspace_root)?.file);
+        }
+        Ok(files)
+    }
+
     /// Returns all the hashes associated with this [`Package`].
     fn hashes(&self) -> Vec<HashDigest> {
         let mut hashes = Vec::new();
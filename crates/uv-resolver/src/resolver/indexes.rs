@@ -1,4 +1,4 @@
-use uv_distribution_types::IndexUrl;
+use uv_distribution_types::{IndexLocations, IndexUrl, PackageNamePattern};
 use uv_normalize::PackageName;
 use uv_pep508::VerbatimUrl;
 use uv_pypi_types::{ConflictItem, RequirementSource};
@@ -19,8 +19,21 @@ use crate::{DependencyMode, Manifest, ResolverEnvironment};
 /// ```
 ///
 /// [`Indexes`] would contain a single entry mapping `torch` to `https://download.pytorch.org/whl/cu121`.
+///
+/// Packages can also be pinned by matching the `packages` patterns of an index, as in:
+/// ```toml
+/// [[tool.uv.index]]
+/// name = "internal"
+/// url = "https://pypi.internal.example.com/simple"
+/// packages = ["acme-*"]
+/// ```
+///
+/// Pins in the [`Manifest`] take precedence over patterns.
 #[derive(Debug, Default, Clone)]
-pub(crate) struct Indexes(ForkMap<Entry>);
+pub(crate) struct Indexes {
+    pins: ForkMap<Entry>,
+    patterns: Vec<(PackageNamePattern, IndexUrl)>,
+}
 
 #[derive(Debug, Clone)]
 struct Entry {
@@ -29,9 +42,11 @@ struct Entry {
 }
 
 impl Indexes {
-    /// Determine the set of explicit, pinned indexes in the [`Manifest`].
+    /// Determine the set of explicit, pinned indexes in the [`Manifest`] and the
+    /// [`IndexLocations`].
     pub(crate) fn from_manifest(
         manifest: &Manifest,
+        locations: &IndexLocations,
         env: &ResolverEnvironment,
         dependencies: DependencyMode,
     ) -> Self {
@@ -51,18 +66,33 @@ impl Indexes {
             indexes.add(&requirement, Entry { index, conflict });
         }
 
-        Self(indexes)
+        // Indexes are listed in priority order, such that the first matching pattern wins.
+        let patterns = locations
+            .simple_indexes()
+            .flat_map(|index| {
+                index
+                    .packages
+                    .iter()
+                    .flatten()
+                    .map(|pattern| (pattern.clone(), index.url.clone()))
+            })
+            .collect();
+
+        Self {
+            pins: indexes,
+            patterns,
+        }
     }
 
     /// Returns `true` if the map contains any indexes for a package.
     pub(crate) fn contains_key(&self, name: &PackageName) -> bool {
-        self.0.contains_key(name)
+        self.pins.contains_key(name) || self.pattern(name).is_some()
     }
 
     /// Return the explicit index used for a package in the given fork.
     pub(crate) fn get(&self, name: &PackageName, env: &ResolverEnvironment) -> Vec<&IndexUrl> {
-        let entries = self.0.get(name, env);
-        entries
+        let entries = self.pins.get(name, env);
+        let indexes = entries
             .iter()
             .filter(|entry| {
                 entry
@@ -71,6 +101,19 @@ impl Indexes {
                     .map_or(true, |conflict| env.included_by_group(conflict.as_ref()))
             })
             .map(|entry| &entry.index)
-            .collect()
+            .collect::<Vec<_>>();
+        if indexes.is_empty() {
+            self.pattern(name).into_iter().collect()
+        } else {
+            indexes
+        }
+    }
+
+    /// Return the index whose `packages` patterns match the package, if any.
+    fn pattern(&self, name: &PackageName) -> Option<&IndexUrl> {
+        self.patterns
+            .iter()
+            .find(|(pattern, _)| pattern.matches(name))
+            .map(|(_, index)| index)
     }
 }
@@ -221,7 +221,7 @@ impl<Provider: ResolverProvider, InstalledPackages: InstalledPackagesProvider>
             selector: CandidateSelector::for_resolution(&options, &manifest, &env),
             dependency_mode: options.dependency_mode,
             urls: Urls::from_manifest(&manifest, &env, git, options.dependency_mode),
-            indexes: Indexes::from_manifest(&manifest, &env, options.dependency_mode),
+            indexes: Indexes::from_manifest(&manifest, locations, &env, options.dependency_mode),
             project: manifest.project,
             workspace_members: manifest.workspace_members,
             requirements: manifest.requirements,
@@ -6,7 +6,7 @@ use etcetera::BaseStrategy;
 
 use uv_fs::Simplified;
 use uv_static::EnvVars;
-use uv_warnings::warn_user;
+use uv_warnings::{warn_user, warn_user_once};
 
 pub use crate::combine::*;
 pub use crate::settings::*;
@@ -119,7 +119,9 @@ impl FilesystemOptions {
 
                 tracing::debug!("Found workspace configuration at `{}`", path.display());
                 validate_uv_toml(&path, &options)?;
-                return Ok(Some(Self(options)));
+                return Ok(Some(
+                    Self(options).without_credential_helpers(path.user_display()),
+                ));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
             Err(err) => return Err(err.into()),
@@ -148,7 +150,9 @@ impl FilesystemOptions {
                 };
 
                 tracing::debug!("Found workspace configuration at `{}`", path.display());
-                return Ok(Some(Self(options)));
+                return Ok(Some(
+                    Self(options).without_credential_helpers(path.user_display()),
+                ));
             }
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
             Err(err) => return Err(err.into()),
@@ -161,6 +165,26 @@ impl FilesystemOptions {
     pub fn from_file(path: impl AsRef<Path>) -> Result<Self, Error> {
         Ok(Self(read_file(path.as_ref())?))
     }
+
+    /// Ignore any credential helpers defined on the indexes, e.g., in project configuration read
+    /// from the given source.
+    ///
+    /// Credential helpers run arbitrary commands, so, as with Git, they're only accepted from the
+    /// user or system configuration (or a `--config-file`), and not from project configuration or
+    /// script metadata, which may come from an untrusted repository.
+    #[must_use]
+    pub fn without_credential_helpers(mut self, source: impl std::fmt::Display) -> Self {
+        for index in self.0.top_level.index.iter_mut().flatten() {
+            if index.credential_helper.take().is_some() {
+                warn_user_once!(
+                    "Ignoring the `credential-helper` for `{}` in `{}`; credential helpers can only be configured in the user or system `uv.toml`",
+                    index.url(),
+                    source.cyan()
+                );
+            }
+        }
+        self
+    }
 }
 
 impl From<Options> for FilesystemOptions {
@@ -272,6 +296,15 @@ fn validate_uv_toml(path: &Path, options: &Options) -> Result<(), Error> {
             "default-groups",
         ));
     }
+    if options.scripts.is_some() {
+        return Err(Error::PyprojectOnlyField(path.to_path_buf(), "scripts"));
+    }
+    if options.allow_unused.is_some() {
+        return Err(Error::PyprojectOnlyField(
+            path.to_path_buf(),
+            "allow-unused",
+        ));
+    }
     if options.managed.is_some() {
         return Err(Error::PyprojectOnlyField(path.to_path_buf(), "managed"));
     }
@@ -3,8 +3,8 @@ use std::{fmt::Debug, num::NonZeroUsize, path::PathBuf};
 use url::Url;
 use uv_cache_info::CacheKey;
 use uv_configuration::{
-    ConfigSettings, IndexStrategy, KeyringProviderType, PackageNameSpecifier, TargetTriple,
-    TrustedHost, TrustedPublishing,
+    AddBoundsKind, ConfigSettings, IndexStrategy, KeyringProviderType, PackageNameSpecifier,
+    TargetTriple, TrustedHost, TrustedPublishing,
 };
 use uv_distribution_types::{
     Index, IndexUrl, PipExtraIndex, PipFindLinks, PipIndex, StaticMetadata,
@@ -96,6 +96,23 @@ pub struct Options {
     )]
     cache_keys: Option<Vec<CacheKey>>,
 
+    /// The default version specifier to use when adding dependencies.
+    ///
+    /// When adding a dependency without a version specifier via `uv add`, uv will add bounds based
+    /// on the resolved version. By default, only a lower bound is added (e.g., `>=1.2.3`).
+    ///
+    /// When set, the bounds are also recorded for tools installed via `uv tool install`, such
+    /// that `uv tool upgrade` respects them.
+    #[option(
+        default = "\"lower\"",
+        value_type = "str",
+        example = r#"
+            bounds = "major"
+        "#,
+        possible_values = true
+    )]
+    pub bounds: Option<AddBoundsKind>,
+
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
     // They're respected in both `pyproject.toml` and `uv.toml` files.
@@ -126,6 +143,12 @@ pub struct Options {
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub default_groups: Option<serde::de::IgnoredAny>,
 
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub scripts: Option<serde::de::IgnoredAny>,
+
+    #[cfg_attr(feature = "schemars", schemars(skip))]
+    pub allow_unused: Option<serde::de::IgnoredAny>,
+
     #[cfg_attr(feature = "schemars", schemars(skip))]
     pub managed: Option<serde::de::IgnoredAny>,
 
@@ -197,6 +220,30 @@ pub struct GlobalOptions {
         "#
     )]
     pub cache_dir: Option<PathBuf>,
+    /// The maximum number of reusable environments (e.g., for `uvx` and `uv run --with`) to
+    /// retain in the cache.
+    ///
+    /// When the limit is exceeded, the least recently used environments are removed.
+    #[option(
+        default = "64",
+        value_type = "int",
+        example = r#"
+            cache-max-environments = 16
+        "#
+    )]
+    pub cache_max_environments: Option<usize>,
+    /// The time, in seconds, after its last use at which a reusable environment in the cache
+    /// expires.
+    ///
+    /// Expired environments are rebuilt on their next use, and removed by `uv cache prune`.
+    #[option(
+        default = "2592000",
+        value_type = "int",
+        example = r#"
+            cache-environment-max-age = 604800
+        "#
+    )]
+    pub cache_environment_max_age: Option<u64>,
     /// Whether to enable experimental, preview features.
     #[option(
         default = "false",
@@ -260,6 +307,43 @@ pub struct GlobalOptions {
         "#
     )]
     pub concurrent_installs: Option<NonZeroUsize>,
+    /// The maximum number of in-flight concurrent requests that uv will send to any single host
+    /// at any given time.
+    ///
+    /// A request is in-flight until its response headers have been received. Requests are also
+    /// subject to the global `concurrent-downloads` limit. By default, there is no limit per host.
+    #[option(
+        default = "None",
+        value_type = "int",
+        example = r#"
+            concurrent-downloads-per-host = 8
+        "#
+    )]
+    pub concurrent_downloads_per_host: Option<NonZeroUsize>,
+    /// The delay, in seconds, before retrying a failed request for the first time.
+    ///
+    /// The delay doubles with each subsequent retry, up to `retry-backoff-max`.
+    #[option(
+        default = "1",
+        value_type = "int",
+        example = r#"
+            retry-backoff-base = 2
+        "#
+    )]
+    pub retry_backoff_base: Option<u64>,
+    /// The maximum delay, in seconds, before retrying a failed request.
+    ///
+    /// When a server responds with `429 Too Many Requests` or `503 Service Unavailable` and a
+    /// `Retry-After` header, uv holds back all requests to that host for the requested duration,
+    /// up to this maximum.
+    #[option(
+        default = "1800",
+        value_type = "int",
+        example = r#"
+            retry-backoff-max = 60
+        "#
+    )]
+    pub retry_backoff_max: Option<u64>,
     /// Allow insecure connections to host.
     ///
     /// Expects to receive either a hostname (e.g., `localhost`), a host-port pair (e.g.,
@@ -276,6 +360,53 @@ pub struct GlobalOptions {
         "#
     )]
     pub allow_insecure_host: Option<Vec<TrustedHost>>,
+    /// The proxy to use for HTTP requests.
+    ///
+    /// Supports `http://`, `https://`, `socks5://`, and `socks5h://` proxy URLs. If the URL doesn't
+    /// include a password, credentials for the proxy are read from the uv credentials file (see
+    /// `uv auth login`) or the netrc file.
+    ///
+    /// Takes precedence over the `HTTP_PROXY` and `ALL_PROXY` environment variables. The proxy
+    /// applies to requests to indexes, Git repositories, and Python downloads, except for indexes
+    /// that set `proxy = false`.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            http-proxy = "http://proxy.example.com:8080"
+        "#
+    )]
+    pub http_proxy: Option<Url>,
+    /// The proxy to use for HTTPS requests.
+    ///
+    /// Supports `http://`, `https://`, `socks5://`, and `socks5h://` proxy URLs. If the URL doesn't
+    /// include a password, credentials for the proxy are read from the uv credentials file (see
+    /// `uv auth login`) or the netrc file.
+    ///
+    /// Takes precedence over the `HTTPS_PROXY` and `ALL_PROXY` environment variables. The proxy
+    /// applies to requests to indexes, Git repositories, and Python downloads, except for indexes
+    /// that set `proxy = false`.
+    #[option(
+        default = "None",
+        value_type = "str",
+        example = r#"
+            https-proxy = "socks5h://proxy.example.com:1080"
+        "#
+    )]
+    pub https_proxy: Option<Url>,
+    /// Hosts to connect to directly, bypassing the `http-proxy` and `https-proxy`.
+    ///
+    /// Accepts hostnames (e.g., `localhost`), domain suffixes (e.g., `.example.com`), IP addresses,
+    /// and CIDR ranges (e.g., `10.0.0.0/8`), in addition to any hosts in the `NO_PROXY`
+    /// environment variable.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            no-proxy = ["localhost", ".internal.example.com"]
+        "#
+    )]
+    pub no_proxy: Option<Vec<String>>,
 }
 
 /// Settings relevant to all installer operations.
@@ -454,8 +585,9 @@ pub struct ResolverInstallerOptions {
     pub index_strategy: Option<IndexStrategy>,
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to use uv's built-in credential store, as
+    /// managed by `uv auth`.
     #[option(
         default = "\"disabled\"",
         value_type = "str",
@@ -924,8 +1056,9 @@ pub struct PipOptions {
     pub index_strategy: Option<IndexStrategy>,
     /// Attempt to use `keyring` for authentication for index URLs.
     ///
-    /// At present, only `--keyring-provider subprocess` is supported, which configures uv to
-    /// use the `keyring` CLI to handle authentication.
+    /// Use `--keyring-provider subprocess` to configure uv to use the `keyring` CLI to handle
+    /// authentication, or `--keyring-provider native` to use uv's built-in credential store, as
+    /// managed by `uv auth`.
     #[option(
         default = "disabled",
         value_type = "str",
@@ -1627,12 +1760,20 @@ pub struct OptionsWire {
     offline: Option<bool>,
     no_cache: Option<bool>,
     cache_dir: Option<PathBuf>,
+    cache_max_environments: Option<usize>,
+    cache_environment_max_age: Option<u64>,
     preview: Option<bool>,
     python_preference: Option<PythonPreference>,
     python_downloads: Option<PythonDownloads>,
     concurrent_downloads: Option<NonZeroUsize>,
     concurrent_builds: Option<NonZeroUsize>,
     concurrent_installs: Option<NonZeroUsize>,
+    concurrent_downloads_per_host: Option<NonZeroUsize>,
+    retry_backoff_base: Option<u64>,
+    retry_backoff_max: Option<u64>,
+    http_proxy: Option<Url>,
+    https_proxy: Option<Url>,
+    no_proxy: Option<Vec<String>>,
 
     // #[serde(flatten)]
     // top_level: ResolverInstallerOptions
@@ -1677,6 +1818,7 @@ pub struct OptionsWire {
 
     pip: Option<PipOptions>,
     cache_keys: Option<Vec<CacheKey>>,
+    bounds: Option<AddBoundsKind>,
 
     // NOTE(charlie): These fields are shared with `ToolUv` in
     // `crates/uv-workspace/src/pyproject.rs`. The documentation lives on that struct.
@@ -1694,6 +1836,8 @@ pub struct OptionsWire {
     managed: Option<serde::de::IgnoredAny>,
     r#package: Option<serde::de::IgnoredAny>,
     default_groups: Option<serde::de::IgnoredAny>,
+    scripts: Option<serde::de::IgnoredAny>,
+    allow_unused: Option<serde::de::IgnoredAny>,
     dev_dependencies: Option<serde::de::IgnoredAny>,
 
     // Build backend
@@ -1708,6 +1852,8 @@ impl From<OptionsWire> for Options {
             offline,
             no_cache,
             cache_dir,
+            cache_max_environments,
+            cache_environment_max_age,
             preview,
             python_preference,
             python_downloads,
@@ -1716,6 +1862,12 @@ impl From<OptionsWire> for Options {
             concurrent_downloads,
             concurrent_builds,
             concurrent_installs,
+            concurrent_downloads_per_host,
+            retry_backoff_base,
+            retry_backoff_max,
+            http_proxy,
+            https_proxy,
+            no_proxy,
             index,
             index_url,
             extra_index_url,
@@ -1745,6 +1897,7 @@ impl From<OptionsWire> for Options {
             no_binary_package,
             pip,
             cache_keys,
+            bounds,
             override_dependencies,
             constraint_dependencies,
             environments,
@@ -1755,6 +1908,8 @@ impl From<OptionsWire> for Options {
             workspace,
             sources,
             default_groups,
+            scripts,
+            allow_unused,
             dev_dependencies,
             managed,
             package,
@@ -1768,14 +1923,22 @@ impl From<OptionsWire> for Options {
                 offline,
                 no_cache,
                 cache_dir,
+                cache_max_environments,
+                cache_environment_max_age,
                 preview,
                 python_preference,
                 python_downloads,
                 concurrent_downloads,
                 concurrent_builds,
                 concurrent_installs,
+                concurrent_downloads_per_host,
+                retry_backoff_base,
+                retry_backoff_max,
                 // Used twice for backwards compatibility
                 allow_insecure_host: allow_insecure_host.clone(),
+                http_proxy,
+                https_proxy,
+                no_proxy,
             },
             top_level: ResolverInstallerOptions {
                 index,
@@ -1807,6 +1970,7 @@ impl From<OptionsWire> for Options {
             },
             pip,
             cache_keys,
+            bounds,
             override_dependencies,
             constraint_dependencies,
             environments,
@@ -1824,6 +1988,8 @@ impl From<OptionsWire> for Options {
             sources,
             dev_dependencies,
             default_groups,
+            scripts,
+            allow_unused,
             managed,
             package,
         }
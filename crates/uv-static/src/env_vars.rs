@@ -38,6 +38,14 @@ impl EnvVars {
     /// cache for any operations.
     pub const UV_NO_CACHE: &'static str = "UV_NO_CACHE";
 
+    /// Equivalent to the `cache-max-environments` setting. The maximum number of reusable
+    /// environments (e.g., for `uvx` and `uv run --with`) to retain in the cache. (default: 64)
+    pub const UV_CACHE_MAX_ENVIRONMENTS: &'static str = "UV_CACHE_MAX_ENVIRONMENTS";
+
+    /// Equivalent to the `cache-environment-max-age` setting. The time (in seconds) after its
+    /// last use at which a reusable environment in the cache expires. (default: 30 days)
+    pub const UV_CACHE_ENVIRONMENT_MAX_AGE: &'static str = "UV_CACHE_ENVIRONMENT_MAX_AGE";
+
     /// Equivalent to the `--resolution` command-line argument. For example, if set to
     /// `lowest-direct`, uv will install the lowest compatible versions of all direct dependencies.
     pub const UV_RESOLUTION: &'static str = "UV_RESOLUTION";
@@ -107,6 +115,12 @@ impl EnvVars {
     /// script, to include the name of the wrapper script in the output file.
     pub const UV_CUSTOM_COMPILE_COMMAND: &'static str = "UV_CUSTOM_COMPILE_COMMAND";
 
+    /// The password used to encrypt the credential store of the `native` keyring provider.
+    ///
+    /// If unset, uv generates a random key and stores it alongside the credential store, which only
+    /// guards against casual inspection of the store.
+    pub const UV_KEYRING_PASSWORD: &'static str = "UV_KEYRING_PASSWORD";
+
     /// Equivalent to the `--keyring-provider` command-line argument. If set, uv
     /// will use this value as the keyring provider.
     pub const UV_KEYRING_PROVIDER: &'static str = "UV_KEYRING_PROVIDER";
@@ -197,6 +211,16 @@ impl EnvVars {
     /// packages.
     pub const UV_CONCURRENT_INSTALLS: &'static str = "UV_CONCURRENT_INSTALLS";
 
+    /// Sets the maximum number of in-flight concurrent requests that uv will
+    /// send to any single host at any given time.
+    pub const UV_CONCURRENT_DOWNLOADS_PER_HOST: &'static str = "UV_CONCURRENT_DOWNLOADS_PER_HOST";
+
+    /// The delay (in seconds) before retrying a failed HTTP request for the first time.
+    pub const UV_RETRY_BACKOFF_BASE: &'static str = "UV_RETRY_BACKOFF_BASE";
+
+    /// The maximum delay (in seconds) before retrying a failed HTTP request.
+    pub const UV_RETRY_BACKOFF_MAX: &'static str = "UV_RETRY_BACKOFF_MAX";
+
     /// Equivalent to the `--no-progress` command-line argument. Disables all progress output. For
     /// example, spinners and progress bars.
     pub const UV_NO_PROGRESS: &'static str = "UV_NO_PROGRESS";
@@ -296,6 +320,10 @@ impl EnvVars {
     #[attr_hidden]
     pub const UV_INTERNAL__TEST_DIR: &'static str = "UV_INTERNAL__TEST_DIR";
 
+    /// Used to indicate that a dynamic shell completion request was forwarded from `uvx`.
+    #[attr_hidden]
+    pub const UV_INTERNAL__UVX_COMPLETE: &'static str = "UV_INTERNAL__UVX_COMPLETE";
+
     /// Path to system-level configuration directory on Unix systems.
     pub const XDG_CONFIG_DIRS: &'static str = "XDG_CONFIG_DIRS";
 
@@ -330,6 +358,9 @@ impl EnvVars {
     /// General proxy for all network requests.
     pub const ALL_PROXY: &'static str = "ALL_PROXY";
 
+    /// Hosts that bypass the HTTP and HTTPS proxies.
+    pub const NO_PROXY: &'static str = "NO_PROXY";
+
     /// Timeout (in seconds) for HTTP requests. (default: 30 s)
     pub const UV_HTTP_TIMEOUT: &'static str = "UV_HTTP_TIMEOUT";
 
@@ -406,6 +437,10 @@ impl EnvVars {
     /// The standard `SHELL` posix env var.
     pub const SHELL: &'static str = "SHELL";
 
+    /// Used to request dynamic shell completions, e.g., `COMPLETE=bash uv` emits the
+    /// registration script for Bash.
+    pub const COMPLETE: &'static str = "COMPLETE";
+
     /// The standard `PWD` posix env var.
     pub const PWD: &'static str = "PWD";
 
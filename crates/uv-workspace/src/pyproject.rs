@@ -208,6 +208,9 @@ pub struct Project {
     pub name: PackageName,
     /// The version of the project
     pub version: Option<Version>,
+    /// The fields of the project that are declared as dynamic.
+    #[serde(default, skip_serializing)]
+    pub dynamic: Option<Vec<String>>,
     /// The Python versions this project is compatible with.
     pub requires_python: Option<VersionSpecifiers>,
     /// The dependencies of the project.
@@ -256,6 +259,7 @@ impl TryFrom<ProjectWire> for Project {
         Ok(Project {
             name,
             version: value.version,
+            dynamic: value.dynamic,
             requires_python: value.requires_python,
             dependencies: value.dependencies,
             optional_dependencies: value.optional_dependencies,
@@ -380,6 +384,46 @@ pub struct ToolUv {
     )]
     pub default_groups: Option<Vec<GroupName>>,
 
+    /// Named tasks that can be executed with `uv run <name>`.
+    ///
+    /// Each task is either a shell command (as a string), a list of arguments to execute directly,
+    /// or a table with a `cmd` key alongside additional settings:
+    ///
+    /// - `env`: Environment variables to set when running the task.
+    /// - `cwd`: The directory in which to run the task, relative to the `pyproject.toml`.
+    /// - `depends-on`: Other tasks to run, in order, before the task itself.
+    /// - `groups`: Dependency groups to sync into the project environment before running the task.
+    /// - `help`: A description of the task.
+    ///
+    /// Tasks are run in the project environment, after the environment has been synced. Any
+    /// arguments following the task name are appended to the task's command. Tasks take
+    /// precedence over commands of the same name in the project environment.
+    #[option(
+        default = "{}",
+        value_type = "dict",
+        example = r#"
+            [tool.uv.scripts]
+            lint = "ruff check ."
+            test = { cmd = ["pytest", "-x"], groups = ["test"], env = { CI = "1" } }
+            check = { depends-on = ["lint", "test"] }
+        "#
+    )]
+    pub scripts: Option<BTreeMap<String, ToolUvScript>>,
+
+    /// Dependencies to retain when removing unused dependencies with `uv remove --unused`.
+    ///
+    /// uv considers a dependency unused if none of the modules it provides are imported by the
+    /// project's Python sources. Dependencies that are loaded indirectly (e.g., plugins discovered
+    /// via entry points, or modules imported dynamically) should be listed here.
+    #[option(
+        default = "[]",
+        value_type = "list[str]",
+        example = r#"
+            allow-unused = ["pytest-cov", "psycopg2-binary"]
+        "#
+    )]
+    pub allow_unused: Option<Vec<PackageName>>,
+
     /// The project's development dependencies.
     ///
     /// Development dependencies will be installed by default in `uv run` and `uv sync`, but will
@@ -605,6 +649,108 @@ impl<'de> serde::de::Deserialize<'de> for ToolUvSources {
     }
 }
 
+/// A named task, as declared in `tool.uv.scripts`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(
+    untagged,
+    expecting = "a command string, a list of arguments, or a table with a `cmd` key"
+)]
+pub enum ToolUvScript {
+    /// A command to execute, e.g., `lint = "ruff check ."`.
+    Command(ScriptCommand),
+    /// A command to execute, along with its settings, e.g.,
+    /// `test = { cmd = "pytest", groups = ["test"] }`.
+    Table(ScriptTable),
+}
+
+impl ToolUvScript {
+    /// The command to execute, if any.
+    ///
+    /// A task without a command only runs its dependencies.
+    pub fn command(&self) -> Option<&ScriptCommand> {
+        match self {
+            Self::Command(command) => Some(command),
+            Self::Table(table) => table.cmd.as_ref(),
+        }
+    }
+
+    /// The environment variables to set when running the task.
+    pub fn env(&self) -> impl Iterator<Item = (&String, &String)> {
+        match self {
+            Self::Command(_) => None,
+            Self::Table(table) => table.env.as_ref(),
+        }
+        .into_iter()
+        .flatten()
+    }
+
+    /// The directory in which to run the task, relative to the `pyproject.toml`.
+    pub fn cwd(&self) -> Option<&Path> {
+        match self {
+            Self::Command(_) => None,
+            Self::Table(table) => table.cwd.as_deref(),
+        }
+    }
+
+    /// The tasks to run before this task.
+    pub fn depends_on(&self) -> &[String] {
+        match self {
+            Self::Command(_) => &[],
+            Self::Table(table) => table.depends_on.as_deref().unwrap_or_default(),
+        }
+    }
+
+    /// The dependency groups to sync before running the task.
+    pub fn groups(&self) -> &[GroupName] {
+        match self {
+            Self::Command(_) => &[],
+            Self::Table(table) => table.groups.as_deref().unwrap_or_default(),
+        }
+    }
+
+    /// The description of the task, if any.
+    pub fn help(&self) -> Option<&str> {
+        match self {
+            Self::Command(_) => None,
+            Self::Table(table) => table.help.as_deref(),
+        }
+    }
+}
+
+/// The command for a task in `tool.uv.scripts`.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum ScriptCommand {
+    /// A command to execute via the system shell, e.g., `"ruff check . && ruff format --check ."`.
+    Shell(String),
+    /// A list of arguments to execute directly, without a shell, e.g., `["pytest", "-x"]`.
+    Args(Vec<String>),
+}
+
+/// A task in `tool.uv.scripts`, declared as a table.
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub struct ScriptTable {
+    /// The command to execute.
+    pub cmd: Option<ScriptCommand>,
+    /// Environment variables to set when running the task.
+    pub env: Option<BTreeMap<String, String>>,
+    /// The directory in which to run the task, relative to the `pyproject.toml`.
+    pub cwd: Option<PathBuf>,
+    /// The tasks to run before this task.
+    pub depends_on: Option<Vec<String>>,
+    /// The dependency groups to sync before running the task.
+    pub groups: Option<Vec<GroupName>>,
+    /// A description of the task.
+    pub help: Option<String>,
+}
+
 #[derive(Deserialize, OptionsMetadata, Default, Debug, Clone, PartialEq, Eq)]
 #[cfg_attr(test, derive(Serialize))]
 #[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
@@ -637,6 +783,35 @@ pub struct ToolUvWorkspace {
         "#
     )]
     pub exclude: Option<Vec<SerdePattern>>,
+    /// The virtual environment layout to use for the workspace.
+    ///
+    /// By default (`shared`), all workspace members share a single virtual environment at the
+    /// workspace root (`.venv`).
+    ///
+    /// With `per-member`, each workspace member uses its own virtual environment in the member's
+    /// directory (`<member>/.venv`), e.g., when running `uv sync --package <member>` or
+    /// `uv run --package <member>`. All environments are synced from the same `uv.lock`.
+    #[option(
+        default = r#""shared""#,
+        value_type = "str",
+        example = r#"
+            venv = "per-member"
+        "#
+    )]
+    pub venv: Option<WorkspaceVenv>,
+}
+
+/// The virtual environment layout for a workspace.
+#[derive(Deserialize, Default, Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(test, derive(Serialize))]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+pub enum WorkspaceVenv {
+    /// Use a single virtual environment at the workspace root.
+    #[default]
+    Shared,
+    /// Use a separate virtual environment in the directory of each workspace member.
+    PerMember,
 }
 
 /// (De)serialize globs as strings.
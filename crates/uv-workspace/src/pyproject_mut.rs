@@ -14,7 +14,7 @@ use uv_cache_key::CanonicalUrl;
 use uv_distribution_types::Index;
 use uv_fs::PortablePath;
 use uv_normalize::GroupName;
-use uv_pep440::{Version, VersionSpecifier, VersionSpecifiers};
+use uv_pep440::{Version, VersionSpecifiers};
 use uv_pep508::{ExtraName, MarkerTree, PackageName, Requirement, VersionOrUrl};
 
 use crate::pyproject::{DependencyType, Source};
@@ -42,6 +42,10 @@ pub enum Error {
     MalformedSources,
     #[error("Workspace in `pyproject.toml` is malformed")]
     MalformedWorkspace,
+    #[error("Version in `pyproject.toml` is malformed")]
+    MalformedVersion,
+    #[error("Field `project.{0}` in `pyproject.toml` is malformed")]
+    MalformedProjectField(&'static str),
     #[error("Expected a dependency at index {0}")]
     MissingDependency(usize),
     #[error("Cannot perform ambiguous update; found multiple entries with matching package names")]
@@ -158,6 +162,75 @@ impl PyProjectTomlMut {
         Ok(doc)
     }
 
+    /// Sets the `project.version` field, preserving any surrounding comments and whitespace.
+    pub fn set_version(&mut self, version: &Version) -> Result<(), Error> {
+        let value = self
+            .project_mut()?
+            .ok_or(Error::MalformedVersion)?
+            .get_mut("version")
+            .and_then(Item::as_value_mut)
+            .filter(|value| value.is_str())
+            .ok_or(Error::MalformedVersion)?;
+
+        let decor = value.decor().clone();
+        *value = Value::from(version.to_string());
+        *value.decor_mut() = decor;
+
+        Ok(())
+    }
+
+    /// Sets the `project.name` field, creating it if necessary.
+    pub fn set_name(&mut self, name: &PackageName) -> Result<(), Error> {
+        self.set_project_field("name", name.to_string())
+    }
+
+    /// Sets the `project.description` field, creating it if necessary.
+    pub fn set_description(&mut self, description: &str) -> Result<(), Error> {
+        self.set_project_field("description", description.to_string())
+    }
+
+    /// Sets the `project.requires-python` field, creating it if necessary.
+    pub fn set_requires_python(&mut self, specifiers: &VersionSpecifiers) -> Result<(), Error> {
+        self.set_project_field("requires-python", specifiers.to_string())
+    }
+
+    /// Returns `true` if the `project.requires-python` field is present.
+    pub fn has_requires_python(&self) -> bool {
+        self.doc
+            .get("project")
+            .and_then(Item::as_table)
+            .and_then(|project| project.get("requires-python"))
+            .is_some()
+    }
+
+    /// Sets a string field in the `project` table, preserving any surrounding comments and
+    /// whitespace if the field already exists.
+    fn set_project_field(&mut self, key: &'static str, value: String) -> Result<(), Error> {
+        let project = self
+            .doc
+            .entry("project")
+            .or_insert(Item::Table(Table::new()))
+            .as_table_mut()
+            .ok_or(Error::MalformedProjectField(key))?;
+
+        match project.get_mut(key) {
+            Some(item) => {
+                let existing = item
+                    .as_value_mut()
+                    .filter(|value| value.is_str())
+                    .ok_or(Error::MalformedProjectField(key))?;
+                let decor = existing.decor().clone();
+                *existing = Value::from(value);
+                *existing.decor_mut() = decor;
+            }
+            None => {
+                project.insert(key, Item::Value(Value::from(value)));
+            }
+        }
+
+        Ok(())
+    }
+
     /// Adds a dependency to `project.dependencies`.
     ///
     /// Returns `true` if the dependency was added, `false` if it was updated.
@@ -478,11 +551,11 @@ impl PyProjectTomlMut {
         Ok(added)
     }
 
-    /// Set the minimum version for an existing dependency in `project.dependencies`.
-    pub fn set_dependency_minimum_version(
+    /// Set the version specifiers for an existing dependency in `project.dependencies`.
+    pub fn set_dependency_bounds(
         &mut self,
         index: usize,
-        version: Version,
+        specifiers: VersionSpecifiers,
     ) -> Result<(), Error> {
         // Get or create `project.dependencies`.
         let dependencies = self
@@ -500,19 +573,17 @@ impl PyProjectTomlMut {
             .as_str()
             .and_then(try_parse_requirement)
             .ok_or(Error::MalformedDependencies)?;
-        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(VersionSpecifiers::from(
-            VersionSpecifier::greater_than_equal_version(version),
-        )));
+        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers));
         dependencies.replace(index, req.to_string());
 
         Ok(())
     }
 
-    /// Set the minimum version for an existing dependency in `tool.uv.dev-dependencies`.
-    pub fn set_dev_dependency_minimum_version(
+    /// Set the version specifiers for an existing dependency in `tool.uv.dev-dependencies`.
+    pub fn set_dev_dependency_bounds(
         &mut self,
         index: usize,
-        version: Version,
+        specifiers: VersionSpecifiers,
     ) -> Result<(), Error> {
         // Get or create `tool.uv.dev-dependencies`.
         let dev_dependencies = self
@@ -538,20 +609,18 @@ impl PyProjectTomlMut {
             .as_str()
             .and_then(try_parse_requirement)
             .ok_or(Error::MalformedDependencies)?;
-        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(VersionSpecifiers::from(
-            VersionSpecifier::greater_than_equal_version(version),
-        )));
+        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers));
         dev_dependencies.replace(index, req.to_string());
 
         Ok(())
     }
 
-    /// Set the minimum version for an existing dependency in `project.optional-dependencies`.
-    pub fn set_optional_dependency_minimum_version(
+    /// Set the version specifiers for an existing dependency in `project.optional-dependencies`.
+    pub fn set_optional_dependency_bounds(
         &mut self,
         group: &ExtraName,
         index: usize,
-        version: Version,
+        specifiers: VersionSpecifiers,
     ) -> Result<(), Error> {
         // Get or create `project.optional-dependencies`.
         let optional_dependencies = self
@@ -575,20 +644,18 @@ impl PyProjectTomlMut {
             .as_str()
             .and_then(try_parse_requirement)
             .ok_or(Error::MalformedDependencies)?;
-        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(VersionSpecifiers::from(
-            VersionSpecifier::greater_than_equal_version(version),
-        )));
+        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers));
         group.replace(index, req.to_string());
 
         Ok(())
     }
 
-    /// Set the minimum version for an existing dependency in `dependency-groups`.
-    pub fn set_dependency_group_requirement_minimum_version(
+    /// Set the version specifiers for an existing dependency in `dependency-groups`.
+    pub fn set_dependency_group_requirement_bounds(
         &mut self,
         group: &GroupName,
         index: usize,
-        version: Version,
+        specifiers: VersionSpecifiers,
     ) -> Result<(), Error> {
         // Get or create `dependency-groups`.
         let dependency_groups = self
@@ -612,9 +679,7 @@ impl PyProjectTomlMut {
             .as_str()
             .and_then(try_parse_requirement)
             .ok_or(Error::MalformedDependencies)?;
-        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(VersionSpecifiers::from(
-            VersionSpecifier::greater_than_equal_version(version),
-        )));
+        req.version_or_url = Some(VersionOrUrl::VersionSpecifier(specifiers));
         group.replace(index, req.to_string());
 
         Ok(())
@@ -896,6 +961,75 @@ impl PyProjectTomlMut {
 
         types
     }
+
+    /// Returns all the dependencies in this `pyproject.toml`, along with their dependency type and
+    /// position in the corresponding array.
+    ///
+    /// This method searches `project.dependencies`, `project.optional-dependencies`,
+    /// `dependency-groups`, and `tool.uv.dev-dependencies`. Entries that can't be parsed as
+    /// requirements (e.g., `include-group` tables) are skipped.
+    pub fn dependencies(&self) -> Vec<(DependencyType, usize, Requirement)> {
+        let mut dependencies = Vec::new();
+
+        if let Some(project) = self.doc.get("project").and_then(Item::as_table) {
+            // Read `project.dependencies`.
+            if let Some(deps) = project.get("dependencies").and_then(Item::as_array) {
+                for (index, req) in parse_dependencies(deps) {
+                    dependencies.push((DependencyType::Production, index, req));
+                }
+            }
+
+            // Read `project.optional-dependencies`.
+            if let Some(extras) = project
+                .get("optional-dependencies")
+                .and_then(Item::as_table)
+            {
+                for (extra, deps) in extras {
+                    let Some(deps) = deps.as_array() else {
+                        continue;
+                    };
+                    let Ok(extra) = ExtraName::new(extra.to_string()) else {
+                        continue;
+                    };
+                    for (index, req) in parse_dependencies(deps) {
+                        dependencies.push((DependencyType::Optional(extra.clone()), index, req));
+                    }
+                }
+            }
+        }
+
+        // Read `dependency-groups`.
+        if let Some(groups) = self.doc.get("dependency-groups").and_then(Item::as_table) {
+            for (group, deps) in groups {
+                let Some(deps) = deps.as_array() else {
+                    continue;
+                };
+                let Ok(group) = GroupName::new(group.to_string()) else {
+                    continue;
+                };
+                for (index, req) in parse_dependencies(deps) {
+                    dependencies.push((DependencyType::Group(group.clone()), index, req));
+                }
+            }
+        }
+
+        // Read `tool.uv.dev-dependencies`.
+        if let Some(deps) = self
+            .doc
+            .get("tool")
+            .and_then(Item::as_table)
+            .and_then(|tool| tool.get("uv"))
+            .and_then(Item::as_table)
+            .and_then(|uv| uv.get("dev-dependencies"))
+            .and_then(Item::as_array)
+        {
+            for (index, req) in parse_dependencies(deps) {
+                dependencies.push((DependencyType::Dev, index, req));
+            }
+        }
+
+        dependencies
+    }
 }
 
 /// Returns an implicit table.
@@ -1182,6 +1316,14 @@ fn find_dependencies(
     to_replace
 }
 
+/// Returns an iterator over the dependencies in the array that can be parsed as requirements,
+/// along with their positions in the array.
+fn parse_dependencies(deps: &Array) -> impl Iterator<Item = (usize, Requirement)> + '_ {
+    deps.iter()
+        .enumerate()
+        .filter_map(|(i, dep)| Some((i, dep.as_str().and_then(try_parse_requirement)?)))
+}
+
 /// Returns the key in `tool.uv.sources` that matches the given package name.
 fn find_source(name: &PackageName, sources: &Table) -> Option<String> {
     for (key, _) in sources {
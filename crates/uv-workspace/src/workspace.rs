@@ -19,7 +19,7 @@ use uv_warnings::warn_user_once;
 use crate::dependency_groups::{DependencyGroupError, FlatDependencyGroups};
 use crate::pyproject::{
     DependencyGroups, Project, PyProjectToml, PyprojectTomlError, Sources, ToolUvSources,
-    ToolUvWorkspace,
+    ToolUvWorkspace, WorkspaceVenv,
 };
 
 #[derive(thiserror::Error, Debug)]
@@ -519,15 +519,17 @@ impl Workspace {
         &self.install_path
     }
 
-    /// The path to the workspace virtual environment.
+    /// The path to the project virtual environment for the given workspace member.
     ///
-    /// Uses `.venv` in the install path directory by default.
+    /// Uses `.venv` in the install path directory by default. If the workspace uses per-member
+    /// environments (`tool.uv.workspace.venv = "per-member"`) and a member is provided, uses
+    /// `.venv` in the member's directory instead.
     ///
     /// If `UV_PROJECT_ENVIRONMENT` is set, it will take precedence. If a relative path is provided,
-    /// it is resolved relative to the install path.
-    pub fn venv(&self) -> PathBuf {
+    /// it is resolved relative to the install path (or the member's directory).
+    pub fn venv(&self, member: Option<&PackageName>) -> PathBuf {
         /// Resolve the `UV_PROJECT_ENVIRONMENT` value, if any.
-        fn from_project_environment_variable(workspace: &Workspace) -> Option<PathBuf> {
+        fn from_project_environment_variable(root: &Path) -> Option<PathBuf> {
             let value = std::env::var_os(EnvVars::UV_PROJECT_ENVIRONMENT)?;
 
             if value.is_empty() {
@@ -539,8 +541,8 @@ impl Workspace {
                 return Some(path);
             };
 
-            // Resolve the path relative to the install path.
-            Some(workspace.install_path.join(path))
+            // Resolve the path relative to the environment root.
+            Some(root.join(path))
         }
 
         // Resolve the `VIRTUAL_ENV` variable, if any.
@@ -586,9 +588,17 @@ impl Workspace {
             None
         }
 
+        // Determine the directory containing the environment.
+        let root = member
+            .filter(|_| self.venv_layout() == WorkspaceVenv::PerMember)
+            .and_then(|member| self.packages.get(member))
+            .map_or(self.install_path.as_path(), |member| {
+                member.root().as_path()
+            });
+
         // Determine the default value
-        let project_env = from_project_environment_variable(self)
-            .unwrap_or_else(|| self.install_path.join(".venv"));
+        let project_env =
+            from_project_environment_variable(root).unwrap_or_else(|| root.join(".venv"));
 
         // Warn if it conflicts with `VIRTUAL_ENV`
         if let Some(from_virtual_env) = from_virtual_env_variable() {
@@ -604,6 +614,17 @@ impl Workspace {
         project_env
     }
 
+    /// The virtual environment layout of the workspace, as set by `tool.uv.workspace.venv`.
+    pub fn venv_layout(&self) -> WorkspaceVenv {
+        self.pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.workspace.as_ref())
+            .and_then(|workspace| workspace.venv)
+            .unwrap_or_default()
+    }
+
     /// The members of the workspace.
     pub fn packages(&self) -> &BTreeMap<PackageName, WorkspaceMember> {
         &self.packages
@@ -1735,11 +1756,14 @@ mod tests {
                         "members": [
                           "packages/*"
                         ],
-                        "exclude": null
+                        "exclude": null,
+                        "venv": null
                       },
                       "managed": null,
                       "package": null,
                       "default-groups": null,
+                      "scripts": null,
+                      "allow-unused": null,
                       "dev-dependencies": null,
                       "override-dependencies": null,
                       "constraint-dependencies": null,
@@ -1827,11 +1851,14 @@ mod tests {
                     "members": [
                       "packages/*"
                     ],
-                    "exclude": null
+                    "exclude": null,
+                    "venv": null
                   },
                   "managed": null,
                   "package": null,
                   "default-groups": null,
+                  "scripts": null,
+                  "allow-unused": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -2038,6 +2065,8 @@ mod tests {
                   "managed": null,
                   "package": null,
                   "default-groups": null,
+                  "scripts": null,
+                  "allow-unused": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -2142,6 +2171,8 @@ mod tests {
                   "managed": null,
                   "package": null,
                   "default-groups": null,
+                  "scripts": null,
+                  "allow-unused": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -2259,6 +2290,8 @@ mod tests {
                   "managed": null,
                   "package": null,
                   "default-groups": null,
+                  "scripts": null,
+                  "allow-unused": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -2350,6 +2383,8 @@ mod tests {
                   "managed": null,
                   "package": null,
                   "default-groups": null,
+                  "scripts": null,
+                  "allow-unused": null,
                   "dev-dependencies": null,
                   "override-dependencies": null,
                   "constraint-dependencies": null,
@@ -4,6 +4,8 @@ use std::{
     process::{Command, ExitCode, ExitStatus},
 };
 
+use uv_static::EnvVars;
+
 /// Spawns a command exec style.
 fn exec_spawn(cmd: &mut Command) -> std::io::Result<Infallible> {
     #[cfg(unix)]
@@ -31,6 +33,16 @@ fn run() -> std::io::Result<ExitStatus> {
         ));
     };
     let uv = bin.join("uv");
+
+    // Forward dynamic shell completion requests (e.g., `COMPLETE=bash uvx`) to uv as-is, since
+    // the completion protocol expects the arguments to be passed verbatim.
+    if std::env::var_os(EnvVars::COMPLETE).is_some_and(|value| !value.is_empty()) {
+        let mut cmd = Command::new(uv);
+        cmd.args(std::env::args_os().skip(1))
+            .env(EnvVars::UV_INTERNAL__UVX_COMPLETE, "1");
+        match exec_spawn(&mut cmd)? {}
+    }
+
     let args = ["tool", "uvx"]
         .iter()
         .map(OsString::from)
@@ -0,0 +1,38 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+
+use uv_cli::AuthStore;
+
+use crate::commands::auth::CredentialStore;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// List the stored credentials.
+pub(crate) fn list(store: AuthStore, printer: Printer) -> Result<ExitStatus> {
+    let entries = match CredentialStore::open(store)? {
+        CredentialStore::File(file) => file
+            .iter()
+            .map(|(realm, credentials)| match credentials.username() {
+                Some(username) => format!("{realm} ({username})"),
+                None => realm.to_string(),
+            })
+            .collect::<Vec<_>>(),
+        CredentialStore::Native(store) => store
+            .entries()?
+            .into_iter()
+            .map(|entry| format!("{} ({})", entry.service, entry.username))
+            .collect(),
+    };
+
+    if entries.is_empty() {
+        writeln!(printer.stderr(), "No credentials found")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    for entry in entries {
+        writeln!(printer.stdout(), "{entry}")?;
+    }
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,57 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Context, Result};
+use console::Term;
+use owo_colors::OwoColorize;
+
+use uv_auth::Realm;
+use uv_cli::AuthStore;
+use uv_distribution_types::Index;
+
+use crate::commands::auth::{resolve_service, service_url, CredentialStore};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Store credentials for an index or URL.
+pub(crate) fn login(
+    service: &str,
+    username: Option<String>,
+    password: Option<String>,
+    store: AuthStore,
+    indexes: &[Index],
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let store = CredentialStore::open(store)?;
+    let service = resolve_service(service, indexes);
+
+    let Some(username) = username else {
+        bail!("A username is required; pass `--username` or `--token`");
+    };
+
+    let password = if let Some(password) = password {
+        password
+    } else {
+        let term = Term::stderr();
+        if !term.is_term() {
+            bail!("A password is required when not running in a terminal; pass `--password`");
+        }
+        uv_console::password("Enter password: ", &term).context("Failed to read password")?
+    };
+
+    let target = match store {
+        CredentialStore::File(mut file) => {
+            let realm = Realm::from(&service_url(&service)?);
+            let target = format!("{username}@{realm}");
+            file.update(|file| file.insert(realm, Some(username), password))?;
+            target
+        }
+        CredentialStore::Native(store) => {
+            let entry = store.insert(&service, &username, &password)?;
+            format!("{}@{}", entry.username, entry.service)
+        }
+    };
+
+    writeln!(printer.stderr(), "Stored credentials for {}", target.cyan())?;
+
+    Ok(ExitStatus::Success)
+}
@@ -0,0 +1,66 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+
+use uv_auth::Realm;
+use uv_cli::AuthStore;
+use uv_distribution_types::Index;
+
+use crate::commands::auth::{resolve_service, service_url, CredentialStore};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Remove the stored credentials for an index or URL.
+pub(crate) fn logout(
+    service: &str,
+    username: Option<&str>,
+    store: AuthStore,
+    indexes: &[Index],
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let store = CredentialStore::open(store)?;
+    let service = resolve_service(service, indexes);
+
+    let removed = match store {
+        CredentialStore::File(mut file) => {
+            let url = service_url(&service)?;
+            let realm = Realm::from(&url);
+            file.update(|file| {
+                if file.get(&url, username).is_none() {
+                    return Vec::new();
+                }
+                let Some(credentials) = file.remove(&realm) else {
+                    return Vec::new();
+                };
+                let target = match credentials.username() {
+                    Some(username) => format!("{username}@{realm}"),
+                    None => realm.to_string(),
+                };
+                vec![target]
+            })?
+        }
+        CredentialStore::Native(store) => store
+            .remove(&service, username)?
+            .into_iter()
+            .map(|entry| format!("{}@{}", entry.username, entry.service))
+            .collect(),
+    };
+
+    if removed.is_empty() {
+        if let Some(username) = username {
+            bail!("No credentials found for `{username}@{service}`");
+        }
+        bail!("No credentials found for `{service}`");
+    }
+
+    for target in removed {
+        writeln!(
+            printer.stderr(),
+            "Removed credentials for {}",
+            target.cyan()
+        )?;
+    }
+
+    Ok(ExitStatus::Success)
+}
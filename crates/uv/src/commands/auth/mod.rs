@@ -0,0 +1,69 @@
+use anyhow::{bail, Context, Result};
+use url::Url;
+
+use uv_auth::{CredentialsFile, NativeKeyring};
+use uv_cli::AuthStore;
+use uv_distribution_types::Index;
+
+pub(crate) mod list;
+pub(crate) mod login;
+pub(crate) mod logout;
+pub(crate) mod token;
+
+/// A store that `uv auth` can read and write credentials to.
+enum CredentialStore {
+    /// uv's plaintext credentials file, keyed by realm.
+    File(CredentialsFile),
+    /// uv's encrypted credential store.
+    Native(NativeKeyring),
+}
+
+impl CredentialStore {
+    /// Open the given credential store.
+    fn open(store: AuthStore) -> Result<Self> {
+        match store {
+            AuthStore::File => {
+                let Some(file) = CredentialsFile::from_default_location()? else {
+                    bail!("Failed to determine the location of the credentials file");
+                };
+                Ok(Self::File(file))
+            }
+            AuthStore::Native => {
+                let Some(store) = NativeKeyring::from_default_location() else {
+                    bail!("Failed to determine the location of the credential store");
+                };
+                Ok(Self::Native(store))
+            }
+        }
+    }
+}
+
+/// Resolve a service, as provided by the user, to a URL or host.
+///
+/// If the service matches the name of a configured index, the index URL is used.
+fn resolve_service(service: &str, indexes: &[Index]) -> String {
+    indexes
+        .iter()
+        .find(|index| {
+            index
+                .name
+                .as_ref()
+                .is_some_and(|name| name.as_ref() == service)
+        })
+        .map(|index| index.url().url().to_string())
+        .unwrap_or_else(|| service.to_string())
+}
+
+/// Parse a resolved service as a URL, assuming `https` if the service is a bare host.
+fn service_url(service: &str) -> Result<Url> {
+    let url = if service.contains("://") {
+        Url::parse(service)
+    } else {
+        Url::parse(&format!("https://{service}"))
+    }
+    .with_context(|| format!("Invalid index or URL: `{service}`"))?;
+    if url.host_str().is_none() {
+        bail!("Invalid index or URL: `{service}` (expected a host)");
+    }
+    Ok(url)
+}
@@ -0,0 +1,42 @@
+use std::fmt::Write;
+
+use anyhow::{bail, Result};
+
+use uv_cli::AuthStore;
+use uv_distribution_types::Index;
+
+use crate::commands::auth::{resolve_service, service_url, CredentialStore};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// Show the stored token (i.e., password) for an index or URL.
+pub(crate) fn token(
+    service: &str,
+    username: Option<&str>,
+    store: AuthStore,
+    indexes: &[Index],
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let store = CredentialStore::open(store)?;
+    let service = resolve_service(service, indexes);
+    let url = service_url(&service)?;
+
+    let credentials = match store {
+        CredentialStore::File(file) => file.get(&url, username).cloned(),
+        CredentialStore::Native(store) => store.fetch(&url, username)?,
+    };
+
+    let Some(password) = credentials
+        .as_ref()
+        .and_then(|credentials| credentials.password())
+    else {
+        if let Some(username) = username {
+            bail!("No credentials found for `{username}@{service}`");
+        }
+        bail!("No credentials found for `{service}`");
+    };
+
+    writeln!(printer.stdout(), "{password}")?;
+
+    Ok(ExitStatus::Success)
+}
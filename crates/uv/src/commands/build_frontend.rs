@@ -15,7 +15,7 @@ use crate::commands::project::find_requires_python;
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
-use crate::settings::{ResolverSettings, ResolverSettingsRef};
+use crate::settings::{ClientOptions, ResolverSettings, ResolverSettingsRef};
 use uv_auth::store_credentials;
 use uv_build_backend::check_direct_build;
 use uv_cache::{Cache, CacheBucket};
@@ -112,6 +112,7 @@ pub(crate) async fn build_frontend(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
@@ -139,6 +140,7 @@ pub(crate) async fn build_frontend(
         connectivity,
         concurrency,
         native_tls,
+        client_options,
         allow_insecure_host,
         cache,
         printer,
@@ -184,6 +186,7 @@ async fn build_impl(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
@@ -220,6 +223,10 @@ async fn build_impl(
     let client_builder = BaseClientBuilder::default()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .allow_insecure_host(allow_insecure_host.to_vec());
 
     // Determine the source to build.
@@ -339,6 +346,7 @@ async fn build_impl(
             no_build_isolation,
             no_build_isolation_package,
             native_tls,
+            client_options,
             connectivity,
             index_strategy,
             keyring_provider,
@@ -419,6 +427,7 @@ async fn build_package(
     no_build_isolation: bool,
     no_build_isolation_package: &[PackageName],
     native_tls: bool,
+    client_options: &ClientOptions,
     connectivity: Connectivity,
     index_strategy: IndexStrategy,
     keyring_provider: KeyringProviderType,
@@ -524,6 +533,10 @@ async fn build_package(
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
@@ -32,7 +32,7 @@ pub(crate) fn cache_prune(ci: bool, cache: &Cache, printer: Printer) -> Result<E
     summary += uv_distribution::prune(cache)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
 
-    // Prune the remaining cache buckets.
+    // Prune the remaining cache buckets, retaining any recently used environments.
     summary += cache
         .prune(ci)
         .with_context(|| format!("Failed to prune cache at: {}", cache.root().user_display()))?;
@@ -0,0 +1,370 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io::Read;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tracing::{debug, warn};
+
+use uv_cache::Cache;
+use uv_distribution_filename::WheelFilename;
+use uv_extract::hash::Hasher;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+
+use crate::commands::simple_index::{self, IndexFile, HTML_CONTENT_TYPE, JSON_CONTENT_TYPE};
+use crate::commands::wheel_archive::write_wheel;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// The maximum size of a request line and its headers.
+const MAX_REQUEST_BYTES: u64 = 16 * 1024;
+
+/// Serve the wheels in the cache as a read-only simple repository.
+pub(crate) async fn cache_serve(
+    bind: SocketAddr,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    // Pack every wheel up-front, such that each is listed with its hash.
+    let server = {
+        let cache = cache.clone();
+        Arc::new(tokio::task::spawn_blocking(move || Server::from_cache(&cache)).await??)
+    };
+
+    let listener = TcpListener::bind(bind)
+        .await
+        .with_context(|| format!("Failed to bind to: {bind}"))?;
+    let addr = listener.local_addr()?;
+
+    let num_wheels = server
+        .projects
+        .values()
+        .map(|project| project.wheels.len())
+        .sum::<usize>();
+    writeln!(
+        printer.stderr(),
+        "Serving {} for {} from: {}",
+        format!(
+            "{num_wheels} wheel{}",
+            if num_wheels == 1 { "" } else { "s" }
+        )
+        .bold(),
+        format!(
+            "{} package{}",
+            server.projects.len(),
+            if server.projects.len() == 1 { "" } else { "s" }
+        )
+        .bold(),
+        cache.root().user_display().cyan()
+    )?;
+    writeln!(
+        printer.stderr(),
+        "Listening at: {}",
+        format!("http://{addr}/simple/").cyan()
+    )?;
+
+    loop {
+        let (stream, peer) = match listener.accept().await {
+            Ok(connection) => connection,
+            Err(err) => {
+                warn!("Failed to accept connection: {err}");
+                continue;
+            }
+        };
+        let server = server.clone();
+        tokio::spawn(async move {
+            if let Err(err) = server.handle(stream).await {
+                debug!("Failed to handle request from {peer}: {err}");
+            }
+        });
+    }
+}
+
+/// The wheels available for a single package.
+#[derive(Debug, Default)]
+struct Project {
+    /// The wheels in the cache, keyed by filename.
+    wheels: BTreeMap<String, Wheel>,
+}
+
+/// A wheel that was re-packed from the cache into the server's temporary directory.
+#[derive(Debug)]
+struct Wheel {
+    /// The packed wheel.
+    path: PathBuf,
+    /// The SHA-256 hash of the packed wheel.
+    hash: [HashDigest; 1],
+}
+
+#[derive(Debug)]
+struct Server {
+    projects: BTreeMap<PackageName, Project>,
+    /// The directory in which packed wheels are stored, removed when the server exits.
+    _temp_dir: tempfile::TempDir,
+}
+
+/// An HTTP response.
+#[derive(Debug)]
+struct Response {
+    status: &'static str,
+    headers: Vec<(&'static str, String)>,
+    body: Body,
+}
+
+#[derive(Debug)]
+enum Body {
+    Bytes(Vec<u8>),
+    File(PathBuf),
+}
+
+impl Response {
+    fn ok(content_type: &str, body: String) -> Self {
+        Self {
+            status: "200 OK",
+            headers: vec![
+                ("Content-Type", content_type.to_string()),
+                ("Cache-Control", "no-cache".to_string()),
+                ("Vary", "Accept".to_string()),
+            ],
+            body: Body::Bytes(body.into_bytes()),
+        }
+    }
+
+    fn file(path: PathBuf) -> Self {
+        Self {
+            status: "200 OK",
+            headers: vec![("Content-Type", "application/octet-stream".to_string())],
+            body: Body::File(path),
+        }
+    }
+
+    fn redirect(location: String) -> Self {
+        Self {
+            status: "301 Moved Permanently",
+            headers: vec![("Location", location)],
+            body: Body::Bytes(Vec::new()),
+        }
+    }
+
+    fn error(status: &'static str) -> Self {
+        Self {
+            status,
+            headers: vec![("Content-Type", "text/plain".to_string())],
+            body: Body::Bytes(format!("{status}\n").into_bytes()),
+        }
+    }
+}
+
+impl Server {
+    /// Index the registry wheels in the cache, including those built from source distributions.
+    ///
+    /// The cache stores wheels unzipped, so each wheel is re-packed (and hashed) into a temporary
+    /// directory owned by the server.
+    fn from_cache(cache: &Cache) -> Result<Self> {
+        let temp_dir = tempfile::tempdir()?;
+
+        let mut projects = BTreeMap::<PackageName, Project>::new();
+        for wheel in uv_distribution::cached_registry_wheels(cache) {
+            let filename = wheel.filename.to_string();
+            let project = projects.entry(wheel.filename.name.clone()).or_default();
+            if project.wheels.contains_key(&filename) {
+                debug!("Skipping duplicate wheel: {filename}");
+                continue;
+            }
+
+            let target = temp_dir.path().join(&filename);
+            let hash = match pack_wheel(&wheel.path, &target) {
+                Ok(hash) => hash,
+                Err(err) => {
+                    warn!(
+                        "Skipping wheel that couldn't be packed: {}: {err:#}",
+                        wheel.path.user_display()
+                    );
+                    continue;
+                }
+            };
+            project.wheels.insert(
+                filename,
+                Wheel {
+                    path: target,
+                    hash: [hash],
+                },
+            );
+        }
+        projects.retain(|_, project| !project.wheels.is_empty());
+
+        Ok(Self {
+            projects,
+            _temp_dir: temp_dir,
+        })
+    }
+
+    /// Handle a single request on the given connection.
+    async fn handle(&self, mut stream: TcpStream) -> Result<()> {
+        let (reader, mut writer) = stream.split();
+        let mut reader = BufReader::new(reader.take(MAX_REQUEST_BYTES));
+
+        // Read the request line, e.g., `GET /simple/ HTTP/1.1`.
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).await?;
+
+        // Read the headers, retaining only `Accept`.
+        let mut accept = String::new();
+        loop {
+            let mut line = String::new();
+            if reader.read_line(&mut line).await? == 0 {
+                break;
+            }
+            let line = line.trim_end();
+            if line.is_empty() {
+                break;
+            }
+            if let Some((name, value)) = line.split_once(':') {
+                if name.trim().eq_ignore_ascii_case("accept") {
+                    accept = value.trim().to_string();
+                }
+            }
+        }
+
+        let mut parts = request_line.split_whitespace();
+        let (method, target) = (parts.next(), parts.next());
+        debug!(
+            "{} {}",
+            method.unwrap_or_default(),
+            target.unwrap_or_default()
+        );
+
+        let (response, head) = match (method, target) {
+            (Some(method @ ("GET" | "HEAD")), Some(target)) => {
+                let path = target.split(['?', '#']).next().unwrap_or_default();
+                (self.route(path, &accept), method == "HEAD")
+            }
+            (Some(_), Some(_)) => (Response::error("405 Method Not Allowed"), false),
+            _ => (Response::error("400 Bad Request"), false),
+        };
+        write_response(&mut writer, response, head).await
+    }
+
+    /// Route a request path to a [`Response`].
+    ///
+    /// The repository is served at `/simple/`, with the wheels themselves under `/files/`.
+    fn route(&self, path: &str, accept: &str) -> Response {
+        let json = accept.contains(JSON_CONTENT_TYPE);
+
+        if path == "/" || path == "/simple" {
+            return Response::redirect("/simple/".to_string());
+        }
+
+        if path == "/simple/" {
+            let names = self.projects.keys().collect::<Vec<_>>();
+            return if json {
+                Response::ok(JSON_CONTENT_TYPE, simple_index::root_json(&names))
+            } else {
+                Response::ok(HTML_CONTENT_TYPE, simple_index::root_html(&names))
+            };
+        }
+
+        if let Some(name) = path.strip_prefix("/simple/") {
+            let Some(name) = name.strip_suffix('/') else {
+                return Response::redirect(format!("{path}/"));
+            };
+            let Ok(name) = PackageName::from_str(name) else {
+                return Response::error("404 Not Found");
+            };
+            let Some(project) = self.projects.get(&name) else {
+                return Response::error("404 Not Found");
+            };
+
+            let files = project
+                .wheels
+                .iter()
+                .map(|(filename, wheel)| IndexFile {
+                    filename,
+                    url: format!("../../files/{filename}"),
+                    hashes: &wheel.hash,
+                })
+                .collect::<Vec<_>>();
+            return if json {
+                Response::ok(JSON_CONTENT_TYPE, simple_index::project_json(&name, &files))
+            } else {
+                Response::ok(HTML_CONTENT_TYPE, simple_index::project_html(&name, &files))
+            };
+        }
+
+        if let Some(filename) = path.strip_prefix("/files/") {
+            let Ok(wheel) = WheelFilename::from_str(filename) else {
+                return Response::error("404 Not Found");
+            };
+            let Some(wheel) = self
+                .projects
+                .get(&wheel.name)
+                .and_then(|project| project.wheels.get(filename))
+            else {
+                return Response::error("404 Not Found");
+            };
+            return Response::file(wheel.path.clone());
+        }
+
+        Response::error("404 Not Found")
+    }
+}
+
+/// Pack an unzipped wheel from the cache into the target path, returning its SHA-256 hash.
+fn pack_wheel(source: &Path, target: &Path) -> Result<HashDigest> {
+    debug!("Packing wheel: {}", source.user_display());
+    write_wheel(source, target)?;
+
+    let mut hasher = Hasher::from(HashAlgorithm::Sha256);
+    let mut file = fs_err::File::open(target)?;
+    let mut buffer = vec![0; 64 * 1024];
+    loop {
+        let n = file.read(&mut buffer)?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buffer[..n]);
+    }
+    Ok(HashDigest::from(hasher))
+}
+
+/// Write a [`Response`] to the connection, omitting the body for `HEAD` requests.
+async fn write_response(
+    writer: &mut (impl tokio::io::AsyncWrite + Unpin),
+    response: Response,
+    head: bool,
+) -> Result<()> {
+    let length = match &response.body {
+        Body::Bytes(bytes) => bytes.len() as u64,
+        Body::File(path) => fs_err::tokio::metadata(path).await?.len(),
+    };
+
+    let mut header = format!("HTTP/1.1 {}\r\n", response.status);
+    for (name, value) in &response.headers {
+        write!(header, "{name}: {value}\r\n")?;
+    }
+    write!(
+        header,
+        "Content-Length: {length}\r\nConnection: close\r\n\r\n"
+    )?;
+    writer.write_all(header.as_bytes()).await?;
+
+    if !head {
+        match response.body {
+            Body::Bytes(bytes) => writer.write_all(&bytes).await?,
+            Body::File(path) => {
+                let mut file = fs_err::tokio::File::open(path).await?;
+                tokio::io::copy(&mut file, writer).await?;
+            }
+        }
+    }
+    writer.flush().await?;
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use std::fmt::Write;
+
+use anyhow::Result;
+use tracing::debug;
+
+use uv_client::CacheStatsRecorder;
+
+use crate::commands::human_readable_bytes;
+use crate::printer::Printer;
+
+/// Report the decisions made by the HTTP cache during this invocation.
+///
+/// A summary for each index is included in the verbose output; if `json` is set, the full
+/// statistics are also written to stdout as JSON.
+pub(crate) fn cache_stats(
+    recorder: &CacheStatsRecorder,
+    json: bool,
+    printer: Printer,
+) -> Result<()> {
+    let stats = recorder.summary();
+
+    for (index, counters) in &stats.indexes {
+        let (cached, cached_unit) = human_readable_bytes(counters.cached_bytes);
+        let (downloaded, downloaded_unit) = human_readable_bytes(counters.downloaded_bytes);
+        debug!(
+            "HTTP cache for {index}: {} fresh, {} revalidated ({} not modified), {} missed; {cached:.1}{cached_unit} from cache, {downloaded:.1}{downloaded_unit} downloaded",
+            counters.fresh, counters.revalidated, counters.not_modified, counters.misses,
+        );
+    }
+
+    if json {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            serde_json::to_string_pretty(&stats)?
+        )?;
+    }
+
+    Ok(())
+}
@@ -7,10 +7,16 @@ use std::path::Path;
 use std::time::Duration;
 use std::{fmt::Display, fmt::Write, process::ExitCode};
 
+pub(crate) use auth::list::list as auth_list;
+pub(crate) use auth::login::login as auth_login;
+pub(crate) use auth::logout::logout as auth_logout;
+pub(crate) use auth::token::token as auth_token;
 pub(crate) use build_frontend::build_frontend;
 pub(crate) use cache_clean::cache_clean;
 pub(crate) use cache_dir::cache_dir;
 pub(crate) use cache_prune::cache_prune;
+pub(crate) use cache_serve::cache_serve;
+pub(crate) use cache_stats::cache_stats;
 pub(crate) use help::help;
 pub(crate) use pip::check::pip_check;
 pub(crate) use pip::compile::pip_compile;
@@ -25,10 +31,14 @@ pub(crate) use project::add::add;
 pub(crate) use project::export::export;
 pub(crate) use project::init::{init, InitKind, InitProjectKind};
 pub(crate) use project::lock::lock;
+pub(crate) use project::mirror::mirror;
 pub(crate) use project::remove::remove;
 pub(crate) use project::run::{run, RunCommand};
 pub(crate) use project::sync::sync;
 pub(crate) use project::tree::tree;
+pub(crate) use project::upgrade::upgrade;
+pub(crate) use project::version::project_version;
+pub(crate) use project::workspace::list as workspace_list;
 pub(crate) use publish::publish;
 pub(crate) use python::dir::dir as python_dir;
 pub(crate) use python::find::find as python_find;
@@ -39,6 +49,8 @@ pub(crate) use python::uninstall::uninstall as python_uninstall;
 #[cfg(feature = "self-update")]
 pub(crate) use self_update::self_update;
 pub(crate) use tool::dir::dir as tool_dir;
+pub(crate) use tool::doctor::doctor as tool_doctor;
+pub(crate) use tool::export::export as tool_export;
 pub(crate) use tool::install::install as tool_install;
 pub(crate) use tool::list::list as tool_list;
 pub(crate) use tool::run::run as tool_run;
@@ -57,11 +69,14 @@ pub(crate) use version::version;
 
 use crate::printer::Printer;
 
+mod auth;
 pub(crate) mod build_backend;
 mod build_frontend;
 mod cache_clean;
 mod cache_dir;
 mod cache_prune;
+mod cache_serve;
+mod cache_stats;
 mod diagnostics;
 mod help;
 pub(crate) mod pip;
@@ -71,9 +86,11 @@ mod python;
 pub(crate) mod reporters;
 #[cfg(feature = "self-update")]
 mod self_update;
+mod simple_index;
 mod tool;
 mod venv;
 mod version;
+mod wheel_archive;
 
 #[derive(Copy, Clone)]
 pub(crate) enum ExitStatus {
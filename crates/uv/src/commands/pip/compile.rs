@@ -43,6 +43,7 @@ use crate::commands::pip::loggers::DefaultResolveLogger;
 use crate::commands::pip::{operations, resolution_environment};
 use crate::commands::{diagnostics, ExitStatus, OutputWriter};
 use crate::printer::Printer;
+use crate::settings::ClientOptions;
 
 /// Resolve a set of requirements into a set of pinned versions.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -95,6 +96,7 @@ pub(crate) async fn pip_compile(
     python_preference: PythonPreference,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     quiet: bool,
     cache: Cache,
     printer: Printer,
@@ -111,6 +113,10 @@ pub(crate) async fn pip_compile(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .keyring(keyring_provider)
         .allow_insecure_host(allow_insecure_host.to_vec());
 
@@ -30,6 +30,7 @@ use crate::commands::pip::operations::report_target_environment;
 use crate::commands::reporters::LatestVersionReporter;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
+use crate::settings::ClientOptions;
 
 /// Enumerate the installed packages in the current environment.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -50,6 +51,7 @@ pub(crate) async fn pip_list(
     python: Option<&str>,
     system: bool,
     native_tls: bool,
+    client_options: &ClientOptions,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -86,6 +88,10 @@ pub(crate) async fn pip_list(
         let client =
             RegistryClientBuilder::new(cache.clone().with_refresh(Refresh::All(Timestamp::now())))
                 .native_tls(native_tls)
+                .cache_stats(client_options.cache_stats.clone())
+                .retry_backoff(client_options.retry_backoff)
+                .concurrency_per_host(client_options.concurrency_per_host)
+                .proxies(client_options.proxies.clone())
                 .connectivity(connectivity)
                 .index_urls(index_locations.index_urls())
                 .index_strategy(index_strategy)
@@ -330,11 +336,11 @@ struct Entry {
 
 /// A column in a table.
 #[derive(Debug)]
-struct Column {
+pub(crate) struct Column {
     /// The header of the column.
-    header: String,
+    pub(crate) header: String,
     /// The rows of the column.
-    rows: Vec<String>,
+    pub(crate) rows: Vec<String>,
 }
 
 impl<'a> Column {
@@ -347,7 +353,7 @@ impl<'a> Column {
     }
 
     /// Return an iterator of the column, with the header and rows formatted to the maximum width.
-    fn fmt(&'a self) -> impl Iterator<Item = String> + 'a {
+    pub(crate) fn fmt(&'a self) -> impl Iterator<Item = String> + 'a {
         let max_width = self.max_width();
         let header = vec![
             format!("{0:width$}", self.header, width = max_width),
@@ -364,7 +370,7 @@ impl<'a> Column {
 ///
 /// A combination of [`itertools::multizip`] and [`itertools::izip`].
 #[derive(Debug)]
-struct MultiZip<T>(Vec<T>);
+pub(crate) struct MultiZip<T>(pub(crate) Vec<T>);
 
 impl<T> Iterator for MultiZip<T>
 where
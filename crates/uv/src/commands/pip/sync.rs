@@ -36,6 +36,7 @@ use crate::commands::pip::operations::{report_interpreter, report_target_environ
 use crate::commands::pip::{operations, resolution_markers, resolution_tags};
 use crate::commands::{diagnostics, ExitStatus};
 use crate::printer::Printer;
+use crate::settings::ClientOptions;
 
 /// Install a set of locked requirements into the current Python environment.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -71,6 +72,7 @@ pub(crate) async fn pip_sync(
     python_preference: PythonPreference,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     cache: Cache,
     dry_run: bool,
@@ -80,6 +82,10 @@ pub(crate) async fn pip_sync(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .keyring(keyring_provider)
         .allow_insecure_host(allow_insecure_host.to_vec());
 
@@ -27,6 +27,7 @@ use crate::commands::pip::operations::report_target_environment;
 use crate::commands::reporters::LatestVersionReporter;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
+use crate::settings::ClientOptions;
 
 /// Display the installed packages in the current environment as a dependency tree.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -50,6 +51,7 @@ pub(crate) async fn pip_tree(
     python: Option<&str>,
     system: bool,
     native_tls: bool,
+    client_options: &ClientOptions,
     cache: &Cache,
     printer: Printer,
 ) -> Result<ExitStatus> {
@@ -87,6 +89,10 @@ pub(crate) async fn pip_tree(
         let client =
             RegistryClientBuilder::new(cache.clone().with_refresh(Refresh::All(Timestamp::now())))
                 .native_tls(native_tls)
+                .cache_stats(client_options.cache_stats.clone())
+                .retry_backoff(client_options.retry_backoff)
+                .concurrency_per_host(client_options.concurrency_per_host)
+                .proxies(client_options.proxies.clone())
                 .connectivity(connectivity)
                 .index_urls(index_locations.index_urls())
                 .index_strategy(index_strategy)
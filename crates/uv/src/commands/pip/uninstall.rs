@@ -21,6 +21,7 @@ use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use crate::commands::pip::operations::report_target_environment;
 use crate::commands::{elapsed, ExitStatus};
 use crate::printer::Printer;
+use crate::settings::ClientOptions;
 
 /// Uninstall packages from the current environment.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -34,6 +35,7 @@ pub(crate) async fn pip_uninstall(
     cache: Cache,
     connectivity: Connectivity,
     native_tls: bool,
+    client_options: &ClientOptions,
     keyring_provider: KeyringProviderType,
     allow_insecure_host: &[TrustedHost],
     dry_run: bool,
@@ -44,6 +46,10 @@ pub(crate) async fn pip_uninstall(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .keyring(keyring_provider)
         .allow_insecure_host(allow_insecure_host.to_vec());
 
@@ -14,9 +14,9 @@ use uv_cache::Cache;
 use uv_cache_key::RepositoryUrl;
 use uv_client::{BaseClientBuilder, Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DevGroupsManifest, DevGroupsSpecification, DevMode, EditableMode,
-    ExtrasSpecification, GroupsSpecification, InstallOptions, LowerBound, PreviewMode,
-    SourceStrategy, TrustedHost,
+    AddBoundsKind, Concurrency, Constraints, DevGroupsManifest, DevGroupsSpecification, DevMode,
+    EditableMode, ExtrasSpecification, GroupsSpecification, InstallOptions, LowerBound,
+    PreviewMode, SourceStrategy, TrustedHost,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution::DistributionDatabase;
@@ -50,7 +50,7 @@ use crate::commands::project::{
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{diagnostics, project, ExitStatus};
 use crate::printer::Printer;
-use crate::settings::{ResolverInstallerSettings, ResolverInstallerSettingsRef};
+use crate::settings::{ClientOptions, ResolverInstallerSettings, ResolverInstallerSettingsRef};
 
 /// Add one or more packages to the project requirements.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -63,6 +63,7 @@ pub(crate) async fn add(
     editable: Option<bool>,
     dependency_type: DependencyType,
     raw_sources: bool,
+    bounds: Option<AddBoundsKind>,
     indexes: Vec<Index>,
     rev: Option<String>,
     tag: Option<String>,
@@ -79,6 +80,7 @@ pub(crate) async fn add(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
     cache: &Cache,
@@ -133,6 +135,10 @@ pub(crate) async fn add(
         let client_builder = BaseClientBuilder::new()
             .connectivity(connectivity)
             .native_tls(native_tls)
+            .cache_stats(client_options.cache_stats.clone())
+            .retry_backoff(client_options.retry_backoff)
+            .concurrency_per_host(client_options.concurrency_per_host)
+            .proxies(client_options.proxies.clone())
             .allow_insecure_host(allow_insecure_host.to_vec());
 
         // If we found a script, add to the existing metadata. Otherwise, create a new inline
@@ -164,6 +170,7 @@ pub(crate) async fn add(
             python_downloads,
             connectivity,
             native_tls,
+            client_options,
             allow_insecure_host,
             &install_mirrors,
             no_config,
@@ -206,12 +213,14 @@ pub(crate) async fn add(
             // Discover the interpreter.
             let interpreter = ProjectInterpreter::discover(
                 project.workspace(),
+                project.project_name(),
                 project_dir,
                 python.as_deref().map(PythonRequest::parse),
                 python_preference,
                 python_downloads,
                 connectivity,
                 native_tls,
+                client_options,
                 allow_insecure_host,
                 &install_mirrors,
                 no_config,
@@ -226,12 +235,14 @@ pub(crate) async fn add(
             // Discover or create the virtual environment.
             let venv = project::get_or_init_environment(
                 project.workspace(),
+                project.project_name(),
                 python.as_deref().map(PythonRequest::parse),
                 &install_mirrors,
                 python_preference,
                 python_downloads,
                 connectivity,
                 native_tls,
+                client_options,
                 allow_insecure_host,
                 no_config,
                 cache,
@@ -246,6 +257,10 @@ pub(crate) async fn add(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .keyring(settings.keyring_provider)
         .allow_insecure_host(allow_insecure_host.to_vec());
 
@@ -652,11 +667,13 @@ pub(crate) async fn add(
         locked,
         &dependency_type,
         raw_sources,
+        bounds.unwrap_or_default(),
         settings.as_ref(),
         installer_metadata,
         connectivity,
         concurrency,
         native_tls,
+        client_options,
         allow_insecure_host,
         cache,
         printer,
@@ -695,11 +712,13 @@ async fn lock_and_sync(
     locked: bool,
     dependency_type: &DependencyType,
     raw_sources: bool,
+    bounds: AddBoundsKind,
     settings: ResolverInstallerSettingsRef<'_>,
     installer_metadata: bool,
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
@@ -721,6 +740,7 @@ async fn lock_and_sync(
         connectivity,
         concurrency,
         native_tls,
+        client_options,
         allow_insecure_host,
         cache,
         printer,
@@ -749,15 +769,21 @@ async fn lock_and_sync(
             }
         }
 
-        // If any of the requirements were added without version specifiers, add a lower bound.
+        // If any of the requirements were added without version specifiers, add bounds based on
+        // the resolved version.
         let mut modified = false;
         for edit in edits {
-            // Only set a minimum version for newly-added dependencies (as opposed to updates).
-            let ArrayEdit::Add(index) = &edit.edit else {
-                continue;
+            // Only set bounds for newly-added dependencies (as opposed to updates), unless the
+            // dependency is being upgraded.
+            let index = match &edit.edit {
+                ArrayEdit::Add(index) => index,
+                ArrayEdit::Update(index) if settings.upgrade.contains(&edit.requirement.name) => {
+                    index
+                }
+                ArrayEdit::Update(_) => continue,
             };
 
-            // Only set a minimum version for registry requirements.
+            // Only set bounds for registry requirements.
             if edit
                 .source
                 .as_ref()
@@ -766,7 +792,7 @@ async fn lock_and_sync(
                 continue;
             }
 
-            // Only set a minimum version for registry requirements.
+            // Only set bounds for requirements without version specifiers.
             let is_empty = match edit.requirement.version_or_url.as_ref() {
                 Some(VersionOrUrl::VersionSpecifier(version)) => version.is_empty(),
                 Some(VersionOrUrl::Url(_)) => false,
@@ -776,27 +802,24 @@ async fn lock_and_sync(
                 continue;
             }
 
-            // Set the minimum version.
+            // Set the bounds, based on the minimum version.
             let Some(minimum) = minimum_version.get(&edit.requirement.name) else {
                 continue;
             };
-
-            // Drop the local version identifier, which isn't permitted in `>=` constraints.
-            // For example, convert `1.2.3+local` to `1.2.3`.
-            let minimum = (*minimum).clone().without_local();
+            let specifiers = bounds.specifiers((*minimum).clone());
 
             match edit.dependency_type {
                 DependencyType::Production => {
-                    toml.set_dependency_minimum_version(*index, minimum)?;
+                    toml.set_dependency_bounds(*index, specifiers)?;
                 }
                 DependencyType::Dev => {
-                    toml.set_dev_dependency_minimum_version(*index, minimum)?;
+                    toml.set_dev_dependency_bounds(*index, specifiers)?;
                 }
                 DependencyType::Optional(ref extra) => {
-                    toml.set_optional_dependency_minimum_version(extra, *index, minimum)?;
+                    toml.set_optional_dependency_bounds(extra, *index, specifiers)?;
                 }
                 DependencyType::Group(ref group) => {
-                    toml.set_dependency_group_requirement_minimum_version(group, *index, minimum)?;
+                    toml.set_dependency_group_requirement_bounds(group, *index, specifiers)?;
                 }
             }
 
@@ -840,6 +863,7 @@ async fn lock_and_sync(
                 connectivity,
                 concurrency,
                 native_tls,
+                client_options,
                 allow_insecure_host,
                 cache,
                 printer,
@@ -907,6 +931,7 @@ async fn lock_and_sync(
         connectivity,
         concurrency,
         native_tls,
+        client_options,
         allow_insecure_host,
         cache,
         printer,
@@ -1,3 +1,6 @@
+use std::fmt::{Display, Formatter};
+use std::path::Path;
+
 use tracing::debug;
 
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
@@ -5,7 +8,7 @@ use crate::commands::project::{
     resolve_environment, sync_environment, EnvironmentSpecification, ProjectError,
 };
 use crate::printer::Printer;
-use crate::settings::ResolverInstallerSettings;
+use crate::settings::{ClientOptions, ResolverInstallerSettings};
 use uv_cache::{Cache, CacheBucket};
 use uv_cache_key::{cache_digest, hash_digest};
 use uv_client::Connectivity;
@@ -15,12 +18,49 @@ use uv_distribution_types::{Name, Resolution};
 use uv_python::{Interpreter, PythonEnvironment};
 
 /// A [`PythonEnvironment`] stored in the cache.
+///
+/// Cached environments are content-addressed by the base interpreter and the hash of the resolved
+/// requirements, such that any invocation that resolves to the same set of distributions reuses
+/// the same environment. Each use is recorded, and environments are evicted according to the
+/// cache's [`uv_cache::EnvironmentRetention`] policy.
 #[derive(Debug)]
-pub(crate) struct CachedEnvironment(PythonEnvironment);
+pub(crate) struct CachedEnvironment {
+    environment: PythonEnvironment,
+    reuse: EnvironmentReuse,
+}
 
 impl From<CachedEnvironment> for PythonEnvironment {
     fn from(environment: CachedEnvironment) -> Self {
-        environment.0
+        environment.environment
+    }
+}
+
+/// Whether a [`CachedEnvironment`] was reused, and if not, why it was created.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum EnvironmentReuse {
+    /// An existing environment with the same requirements hash was reused.
+    Reused,
+    /// No environment with the same requirements hash existed in the cache.
+    Missing,
+    /// The cache was refreshed, so the existing environment was ignored.
+    Refreshed,
+    /// The existing environment hadn't been used within the retention period.
+    Expired,
+    /// The existing environment could not be loaded (e.g., its interpreter was removed).
+    Invalid,
+}
+
+impl Display for EnvironmentReuse {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Reused => f.write_str("reused, as the requirements hash matched"),
+            Self::Missing => {
+                f.write_str("created, as no environment matched the requirements hash")
+            }
+            Self::Refreshed => f.write_str("created, as the cache was refreshed"),
+            Self::Expired => f.write_str("created, as the cached environment had expired"),
+            Self::Invalid => f.write_str("created, as the cached environment was invalid"),
+        }
     }
 }
 
@@ -38,6 +78,7 @@ impl CachedEnvironment {
         connectivity: Connectivity,
         concurrency: Concurrency,
         native_tls: bool,
+        client_options: &ClientOptions,
         allow_insecure_host: &[TrustedHost],
         cache: &Cache,
         printer: Printer,
@@ -70,6 +111,7 @@ impl CachedEnvironment {
                 connectivity,
                 concurrency,
                 native_tls,
+                client_options,
                 allow_insecure_host,
                 cache,
                 printer,
@@ -94,13 +136,33 @@ impl CachedEnvironment {
         // Search in the content-addressed cache.
         let cache_entry = cache.entry(CacheBucket::Environments, interpreter_hash, resolution_hash);
 
-        if cache.refresh().is_none() {
+        let reuse = if cache.refresh().is_none() {
             if let Ok(root) = fs_err::read_link(cache_entry.path()) {
-                if let Ok(environment) = PythonEnvironment::from_root(root, cache) {
-                    return Ok(Self(environment));
+                if cache
+                    .environment_last_used(&cache_entry)
+                    .is_some_and(|last_used| cache.environment_retention().is_expired(last_used))
+                {
+                    debug!("Cached environment expired: `{}`", root.display());
+                    EnvironmentReuse::Expired
+                } else if let Ok(environment) = PythonEnvironment::from_root(root, cache) {
+                    cache.touch_environment(&cache_entry)?;
+                    return Ok(Self {
+                        environment,
+                        reuse: EnvironmentReuse::Reused,
+                    });
+                } else {
+                    EnvironmentReuse::Invalid
                 }
+            } else {
+                EnvironmentReuse::Missing
             }
-        }
+        } else {
+            EnvironmentReuse::Refreshed
+        };
+
+        // Evict any environments that fall outside the retention policy, skipping any that may
+        // still be in use by a concurrent invocation.
+        cache.evict_environments()?;
 
         // Create the environment in the cache, then relocate it to its content-addressed location.
         let temp_dir = cache.venv_dir()?;
@@ -124,6 +186,7 @@ impl CachedEnvironment {
             connectivity,
             concurrency,
             native_tls,
+            client_options,
             allow_insecure_host,
             cache,
             printer,
@@ -136,12 +199,26 @@ impl CachedEnvironment {
             .persist(temp_dir.into_path(), cache_entry.path())
             .await?;
         let root = cache.archive(&id);
+        cache.touch_environment(&cache_entry)?;
+
+        Ok(Self {
+            environment: PythonEnvironment::from_root(root, cache)?,
+            reuse,
+        })
+    }
+
+    /// Return the root of the [`CachedEnvironment`].
+    pub(crate) fn root(&self) -> &Path {
+        self.environment.root()
+    }
 
-        Ok(Self(PythonEnvironment::from_root(root, cache)?))
+    /// Return whether the [`CachedEnvironment`] was reused.
+    pub(crate) fn reuse(&self) -> EnvironmentReuse {
+        self.reuse
     }
 
     /// Convert the [`CachedEnvironment`] into an [`Interpreter`].
     pub(crate) fn into_interpreter(self) -> Interpreter {
-        self.0.into_interpreter()
+        self.environment.into_interpreter()
     }
 }
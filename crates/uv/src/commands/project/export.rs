@@ -27,7 +27,7 @@ use crate::commands::project::{
 };
 use crate::commands::{diagnostics, ExitStatus, OutputWriter};
 use crate::printer::Printer;
-use crate::settings::ResolverSettings;
+use crate::settings::{ClientOptions, ResolverSettings};
 
 /// Export the project's `uv.lock` in an alternate format.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -54,6 +54,7 @@ pub(crate) async fn export(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
     quiet: bool,
@@ -109,12 +110,14 @@ pub(crate) async fn export(
         // Find an interpreter for the project
         interpreter = ProjectInterpreter::discover(
             project.workspace(),
+            project.project_name(),
             project_dir,
             python.as_deref().map(PythonRequest::parse),
             python_preference,
             python_downloads,
             connectivity,
             native_tls,
+            client_options,
             allow_insecure_host,
             &install_mirrors,
             no_config,
@@ -145,6 +148,7 @@ pub(crate) async fn export(
         connectivity,
         concurrency,
         native_tls,
+        client_options,
         allow_insecure_host,
         cache,
         printer,
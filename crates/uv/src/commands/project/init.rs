@@ -28,10 +28,12 @@ use uv_warnings::warn_user_once;
 use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, Workspace, WorkspaceError};
 
+use crate::commands::project::template::{ProjectTemplate, TemplateVariables};
 use crate::commands::project::{find_requires_python, init_script_python_requirement};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
+use crate::settings::ClientOptions;
 
 /// Add one or more packages to the project requirements.
 #[allow(clippy::single_match_else, clippy::fn_params_excessive_bools)]
@@ -44,6 +46,7 @@ pub(crate) async fn init(
     description: Option<String>,
     vcs: Option<VersionControlSystem>,
     build_backend: Option<ProjectBuildBackend>,
+    template: Option<String>,
     no_readme: bool,
     author_from: Option<AuthorFrom>,
     no_pin_python: bool,
@@ -54,6 +57,7 @@ pub(crate) async fn init(
     python_downloads: PythonDownloads,
     connectivity: Connectivity,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
     cache: &Cache,
@@ -84,6 +88,7 @@ pub(crate) async fn init(
                 no_pin_python,
                 package,
                 native_tls,
+                client_options,
                 allow_insecure_host,
                 no_config,
             )
@@ -133,6 +138,7 @@ pub(crate) async fn init(
                 description,
                 vcs,
                 build_backend,
+                template.as_deref(),
                 no_readme,
                 author_from,
                 no_pin_python,
@@ -143,6 +149,7 @@ pub(crate) async fn init(
                 python_downloads,
                 connectivity,
                 native_tls,
+                client_options,
                 allow_insecure_host,
                 no_config,
                 cache,
@@ -197,6 +204,7 @@ async fn init_script(
     no_pin_python: bool,
     package: bool,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
 ) -> Result<()> {
@@ -215,6 +223,10 @@ async fn init_script(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .allow_insecure_host(allow_insecure_host.to_vec());
 
     let reporter = PythonDownloadReporter::single(printer);
@@ -275,6 +287,7 @@ async fn init_project(
     description: Option<String>,
     vcs: Option<VersionControlSystem>,
     build_backend: Option<ProjectBuildBackend>,
+    template: Option<&str>,
     no_readme: bool,
     author_from: Option<AuthorFrom>,
     no_pin_python: bool,
@@ -285,6 +298,7 @@ async fn init_project(
     python_downloads: PythonDownloads,
     connectivity: Connectivity,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
     cache: &Cache,
@@ -337,8 +351,18 @@ async fn init_project(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .allow_insecure_host(allow_insecure_host.to_vec());
 
+    // Fetch the project template, if any.
+    let template = match template {
+        Some(source) => Some(ProjectTemplate::fetch(source, &client_builder, cache).await?),
+        None => None,
+    };
+
     // First, determine if there is an request for Python
     let python_request = if let Some(request) = python {
         // (1) Explicit request from user
@@ -563,17 +587,29 @@ async fn init_project(
         (requires_python, python_request)
     };
 
-    project_kind.init(
-        name,
-        path,
-        &requires_python,
-        description.as_deref(),
-        vcs,
-        build_backend,
-        author_from,
-        no_readme,
-        package,
-    )?;
+    if let Some(template) = template {
+        init_template(
+            &template,
+            name,
+            path,
+            &requires_python,
+            description.as_deref(),
+            vcs,
+            no_readme,
+        )?;
+    } else {
+        project_kind.init(
+            name,
+            path,
+            &requires_python,
+            description.as_deref(),
+            vcs,
+            build_backend,
+            author_from,
+            no_readme,
+            package,
+        )?;
+    }
 
     if let Some(workspace) = workspace {
         if workspace.excludes(path)? {
@@ -844,6 +880,48 @@ impl InitProjectKind {
     }
 }
 
+/// Initialize a project at the target path from a template.
+fn init_template(
+    template: &ProjectTemplate,
+    name: &PackageName,
+    path: &Path,
+    requires_python: &RequiresPython,
+    description: Option<&str>,
+    vcs: Option<VersionControlSystem>,
+    no_readme: bool,
+) -> Result<()> {
+    fs_err::create_dir_all(path)?;
+
+    // Copy the template files, substituting any variables.
+    template.render(path, &TemplateVariables::new(name, requires_python))?;
+
+    // Use the `pyproject.toml` from the template, if it exists.
+    let pyproject_toml = path.join("pyproject.toml");
+    let pyproject = match fs_err::read_to_string(&pyproject_toml) {
+        Ok(pyproject) => pyproject,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            pyproject_project(name, requires_python, None, description, no_readme)
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    // Fill in the project metadata, retaining any `requires-python` from the template.
+    let mut pyproject = PyProjectTomlMut::from_toml(&pyproject, DependencyTarget::PyProjectToml)?;
+    pyproject.set_name(name)?;
+    if let Some(description) = description {
+        pyproject.set_description(description)?;
+    }
+    if !pyproject.has_requires_python() {
+        pyproject.set_requires_python(requires_python.specifiers())?;
+    }
+    fs_err::write(pyproject_toml, pyproject.to_string())?;
+
+    // Initialize the version control system.
+    init_vcs(path, vcs)?;
+
+    Ok(())
+}
+
 #[derive(Debug)]
 enum Author {
     Name(String),
@@ -42,7 +42,7 @@ use crate::commands::project::{ProjectError, ProjectInterpreter};
 use crate::commands::reporters::ResolverReporter;
 use crate::commands::{diagnostics, pip, ExitStatus};
 use crate::printer::Printer;
-use crate::settings::{ResolverSettings, ResolverSettingsRef};
+use crate::settings::{ClientOptions, ResolverSettings, ResolverSettingsRef};
 
 /// The result of running a lock operation.
 #[derive(Debug, Clone)]
@@ -84,6 +84,7 @@ pub(crate) async fn lock(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
     cache: &Cache,
@@ -100,12 +101,14 @@ pub(crate) async fn lock(
     } else {
         interpreter = ProjectInterpreter::discover(
             &workspace,
+            None,
             project_dir,
             python.as_deref().map(PythonRequest::parse),
             python_preference,
             python_downloads,
             connectivity,
             native_tls,
+            client_options,
             allow_insecure_host,
             &install_mirrors,
             no_config,
@@ -138,6 +141,7 @@ pub(crate) async fn lock(
         connectivity,
         concurrency,
         native_tls,
+        client_options,
         allow_insecure_host,
         cache,
         printer,
@@ -198,6 +202,7 @@ pub(super) async fn do_safe_lock(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
@@ -231,6 +236,7 @@ pub(super) async fn do_safe_lock(
                 connectivity,
                 concurrency,
                 native_tls,
+                client_options,
                 allow_insecure_host,
                 cache,
                 printer,
@@ -271,6 +277,7 @@ pub(super) async fn do_safe_lock(
                 connectivity,
                 concurrency,
                 native_tls,
+                client_options,
                 allow_insecure_host,
                 cache,
                 printer,
@@ -302,6 +309,7 @@ async fn do_lock(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
@@ -443,6 +451,10 @@ async fn do_lock(
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
@@ -977,6 +989,12 @@ impl ValidatedLock {
                 );
                 Ok(Self::Preferable(lock))
             }
+            SatisfiesResult::MismatchedIndex(name, version, index) => {
+                debug!(
+                    "Ignoring existing lockfile due to `{name}=={version}` not being resolved from its pinned index: `{index}`"
+                );
+                Ok(Self::Preferable(lock))
+            }
             SatisfiesResult::MismatchedPackageRequirements(name, version, expected, actual) => {
                 debug!(
                     "Ignoring existing lockfile due to mismatched `requires-dist` for: `{name}=={version}`\n  Requested: {:?}\n  Existing: {:?}",
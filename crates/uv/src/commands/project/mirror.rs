@@ -0,0 +1,252 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::io::Write as _;
+use std::path::Path;
+
+use anyhow::{anyhow, bail, Result};
+use futures::{StreamExt, TryStreamExt};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_auth::store_credentials;
+use uv_cache::Cache;
+use uv_client::{Connectivity, RegistryClient, RegistryClientBuilder};
+use uv_configuration::{Concurrency, KeyringProviderType, TrustedHost};
+use uv_distribution_types::{File, IndexLocations};
+use uv_extract::hash::Hasher;
+use uv_fs::Simplified;
+use uv_normalize::PackageName;
+use uv_pypi_types::{HashAlgorithm, HashDigest};
+use uv_workspace::{DiscoveryOptions, Workspace};
+
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::ProjectError;
+use crate::commands::simple_index::{self, IndexFile};
+use crate::commands::{elapsed, ExitStatus};
+use crate::printer::Printer;
+use crate::settings::ClientOptions;
+
+/// The name of the directory, within the mirror, in which distributions are stored.
+const FILES_DIR: &str = "files";
+
+/// Materialize a local package index from the project's `uv.lock`.
+pub(crate) async fn mirror(
+    project_dir: &Path,
+    out_dir: &Path,
+    index_locations: &IndexLocations,
+    keyring_provider: KeyringProviderType,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    client_options: &ClientOptions,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let start = std::time::Instant::now();
+
+    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+    let lock = LockTarget::from(&workspace)
+        .read()
+        .await?
+        .ok_or(ProjectError::MissingLockfile)?;
+
+    // Collect the distributions for every package, across all platforms. Packages that aren't
+    // from a registry (e.g., Git or path dependencies) have no distributions to mirror.
+    let mut projects: BTreeMap<PackageName, BTreeMap<String, File>> = BTreeMap::new();
+    for package in lock.packages() {
+        let files = package.registry_files(workspace.install_path())?;
+        if files.is_empty() {
+            debug!("Skipping non-registry package: `{}`", package.name());
+            continue;
+        }
+        let entry = projects.entry(package.name().clone()).or_default();
+        for file in files {
+            entry.insert(file.filename.clone(), file);
+        }
+    }
+
+    // Add all authenticated sources to the cache.
+    for index in index_locations.allowed_indexes() {
+        if let Some(credentials) = index.credentials() {
+            store_credentials(index.raw_url(), credentials);
+        }
+    }
+
+    let client = RegistryClientBuilder::new(cache.clone())
+        .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
+        .connectivity(connectivity)
+        .index_urls(index_locations.index_urls())
+        .keyring(keyring_provider)
+        .allow_insecure_host(allow_insecure_host.to_vec())
+        .build();
+
+    let files_dir = out_dir.join(FILES_DIR);
+    fs_err::tokio::create_dir_all(&files_dir).await?;
+
+    // Download and verify every distribution.
+    let total = projects.values().map(BTreeMap::len).sum::<usize>();
+    let downloaded = futures::stream::iter(projects.values_mut().flat_map(BTreeMap::values_mut))
+        .map(|file| fetch(&client, file, &files_dir))
+        .buffer_unordered(concurrency.downloads)
+        .try_fold(0usize, |count, downloaded| async move {
+            Ok(count + usize::from(downloaded))
+        })
+        .await?;
+
+    // Write the index pages.
+    for (name, files) in &projects {
+        write_project(out_dir, name, files.values()).await?;
+    }
+    write_root(out_dir, projects.keys()).await?;
+
+    let s = if total == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "{}",
+        format!(
+            "Mirrored {} {}",
+            format!("{total} distribution{s}").bold(),
+            format!("({downloaded} downloaded) in {}", elapsed(start.elapsed())).dimmed()
+        )
+        .dimmed()
+    )?;
+    writeln!(
+        printer.stderr(),
+        "Wrote index for {} to: `{}`",
+        format!(
+            "{} package{}",
+            projects.len(),
+            if projects.len() == 1 { "" } else { "s" }
+        )
+        .bold(),
+        out_dir.simplified_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// Download a distribution into the given directory, verifying it against its hashes.
+///
+/// If the distribution has no hashes, the computed SHA-256 hash is added to it.
+///
+/// Returns `false` if the distribution already existed with a matching hash.
+async fn fetch(client: &RegistryClient, file: &mut File, files_dir: &Path) -> Result<bool> {
+    let path = files_dir.join(&file.filename);
+
+    // Verify against the strongest of the expected hashes.
+    let algorithm = file
+        .hashes
+        .iter()
+        .map(HashDigest::algorithm)
+        .max()
+        .unwrap_or(HashAlgorithm::Sha256);
+
+    if path.is_file() {
+        let mut hasher = Hasher::from(algorithm);
+        hasher.update(&fs_err::tokio::read(&path).await?);
+        let digest = HashDigest::from(hasher);
+        if file.hashes.is_empty() || file.hashes.contains(&digest) {
+            debug!("Using existing distribution: `{}`", path.user_display());
+            if file.hashes.is_empty() {
+                file.hashes.push(digest);
+            }
+            return Ok(false);
+        }
+        debug!(
+            "Existing distribution has a mismatched hash: `{}`",
+            path.user_display()
+        );
+    }
+
+    let url = file.url.to_url()?;
+    debug!("Downloading: {url}");
+
+    // Stream the distribution to a temporary file, hashing it along the way.
+    let mut temp = tempfile::NamedTempFile::new_in(files_dir)?;
+    let mut hasher = Hasher::from(algorithm);
+    if url.scheme() == "file" {
+        let source = url
+            .to_file_path()
+            .map_err(|()| anyhow!("Invalid file URL: {url}"))?;
+        let contents = fs_err::tokio::read(&source).await?;
+        hasher.update(&contents);
+        temp.write_all(&contents)?;
+    } else {
+        let response = client
+            .uncached_client(&url)
+            .get(url.clone())
+            .send()
+            .await?
+            .error_for_status()?;
+        let mut stream = response.bytes_stream();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            hasher.update(&chunk);
+            temp.write_all(&chunk)?;
+        }
+    }
+
+    let digest = HashDigest::from(hasher);
+    if file.hashes.is_empty() {
+        file.hashes.push(digest);
+    } else if !file.hashes.contains(&digest) {
+        bail!(
+            "Hash mismatch for `{}`\n\nExpected:\n{}\n\nComputed:\n  {digest}",
+            file.filename,
+            file.hashes
+                .iter()
+                .filter(|hash| hash.algorithm == algorithm)
+                .map(|hash| format!("  {hash}"))
+                .join("\n"),
+        );
+    }
+
+    temp.persist(&path)?;
+    Ok(true)
+}
+
+/// Write the HTML (PEP 503) and JSON (PEP 691) pages for a project.
+async fn write_project(
+    out_dir: &Path,
+    name: &PackageName,
+    files: impl Iterator<Item = &File>,
+) -> Result<()> {
+    let files = files
+        .map(|file| IndexFile {
+            filename: &file.filename,
+            url: format!("../{FILES_DIR}/{}", file.filename),
+            hashes: &file.hashes,
+        })
+        .collect::<Vec<_>>();
+
+    let project_dir = out_dir.join(name.as_str());
+    fs_err::tokio::create_dir_all(&project_dir).await?;
+    fs_err::tokio::write(
+        project_dir.join("index.html"),
+        simple_index::project_html(name, &files),
+    )
+    .await?;
+    fs_err::tokio::write(
+        project_dir.join("index.json"),
+        simple_index::project_json(name, &files),
+    )
+    .await?;
+    Ok(())
+}
+
+/// Write the HTML (PEP 503) and JSON (PEP 691) root pages, listing every project.
+async fn write_root<'a>(
+    out_dir: &Path,
+    names: impl Iterator<Item = &'a PackageName>,
+) -> Result<()> {
+    let names = names.collect::<Vec<_>>();
+    fs_err::tokio::write(out_dir.join("index.html"), simple_index::root_html(&names)).await?;
+    fs_err::tokio::write(out_dir.join("index.json"), simple_index::root_json(&names)).await?;
+    Ok(())
+}
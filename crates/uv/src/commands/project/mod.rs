@@ -40,7 +40,7 @@ use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildIsolation, EmptyInstalledPackages, HashStrategy};
 use uv_warnings::{warn_user, warn_user_once};
 use uv_workspace::dependency_groups::DependencyGroupError;
-use uv_workspace::pyproject::PyProjectToml;
+use uv_workspace::pyproject::{PyProjectToml, WorkspaceVenv};
 use uv_workspace::{ProjectWorkspace, Workspace};
 
 use crate::commands::pip::loggers::{InstallLogger, ResolveLogger};
@@ -48,7 +48,9 @@ use crate::commands::pip::operations::{Changelog, Modifications};
 use crate::commands::reporters::{PythonDownloadReporter, ResolverReporter};
 use crate::commands::{capitalize, conjunction, pip};
 use crate::printer::Printer;
-use crate::settings::{InstallerSettingsRef, ResolverInstallerSettings, ResolverSettingsRef};
+use crate::settings::{
+    ClientOptions, InstallerSettingsRef, ResolverInstallerSettings, ResolverSettingsRef,
+};
 
 pub(crate) mod add;
 pub(crate) mod environment;
@@ -56,11 +58,17 @@ pub(crate) mod export;
 pub(crate) mod init;
 mod install_target;
 pub(crate) mod lock;
-mod lock_target;
+pub(crate) mod lock_target;
+pub(crate) mod mirror;
 pub(crate) mod remove;
 pub(crate) mod run;
 pub(crate) mod sync;
+mod task;
+mod template;
 pub(crate) mod tree;
+pub(crate) mod upgrade;
+pub(crate) mod version;
+pub(crate) mod workspace;
 
 #[derive(thiserror::Error, Debug)]
 pub(crate) enum ProjectError {
@@ -145,6 +153,9 @@ pub(crate) enum ProjectError {
     #[error("Default group `{0}` (from `tool.uv.default-groups`) is not defined in the project's `dependency-group` table")]
     MissingDefaultGroup(GroupName),
 
+    #[error("`--all-packages` is not supported when workspace members use their own environments (`tool.uv.workspace.venv = \"per-member\"`); use `--package` to target each member instead")]
+    AllPackagesPerMember,
+
     #[error("Supported environments must be disjoint, but the following markers overlap: `{0}` and `{1}`.\n\n{hint}{colon} replace `{1}` with `{2}`.", hint = "hint".bold().cyan(), colon = ":".bold())]
     OverlappingMarkers(String, String, String),
 
@@ -450,6 +461,7 @@ impl ScriptInterpreter {
         python_downloads: PythonDownloads,
         connectivity: Connectivity,
         native_tls: bool,
+        client_options: &ClientOptions,
         allow_insecure_host: &[TrustedHost],
         install_mirrors: &PythonInstallMirrors,
         no_config: bool,
@@ -468,6 +480,10 @@ impl ScriptInterpreter {
         let client_builder = BaseClientBuilder::new()
             .connectivity(connectivity)
             .native_tls(native_tls)
+            .cache_stats(client_options.cache_stats.clone())
+            .retry_backoff(client_options.retry_backoff)
+            .concurrency_per_host(client_options.concurrency_per_host)
+            .proxies(client_options.proxies.clone())
             .allow_insecure_host(allow_insecure_host.to_vec());
 
         let reporter = PythonDownloadReporter::single(printer);
@@ -519,14 +535,18 @@ pub(crate) enum ProjectInterpreter {
 
 impl ProjectInterpreter {
     /// Discover the interpreter to use in the current [`Workspace`].
+    ///
+    /// If a workspace member is provided, its project environment is considered.
     pub(crate) async fn discover(
         workspace: &Workspace,
+        member: Option<&PackageName>,
         project_dir: &Path,
         python_request: Option<PythonRequest>,
         python_preference: PythonPreference,
         python_downloads: PythonDownloads,
         connectivity: Connectivity,
         native_tls: bool,
+        client_options: &ClientOptions,
         allow_insecure_host: &[TrustedHost],
         install_mirrors: &PythonInstallMirrors,
         no_config: bool,
@@ -542,7 +562,7 @@ impl ProjectInterpreter {
             .await?;
 
         // Read from the virtual environment first.
-        let venv = workspace.venv();
+        let venv = workspace.venv(member);
         match PythonEnvironment::from_root(&venv, cache) {
             Ok(venv) => {
                 if python_request.as_ref().map_or(true, |request| {
@@ -612,6 +632,10 @@ impl ProjectInterpreter {
         let client_builder = BaseClientBuilder::default()
             .connectivity(connectivity)
             .native_tls(native_tls)
+            .cache_stats(client_options.cache_stats.clone())
+            .retry_backoff(client_options.retry_backoff)
+            .concurrency_per_host(client_options.concurrency_per_host)
+            .proxies(client_options.proxies.clone())
             .allow_insecure_host(allow_insecure_host.to_vec());
 
         let reporter = PythonDownloadReporter::single(printer);
@@ -842,14 +866,18 @@ impl ScriptPython {
 }
 
 /// Initialize a virtual environment for the current project.
+///
+/// If a workspace member is provided, its project environment is used.
 pub(crate) async fn get_or_init_environment(
     workspace: &Workspace,
+    member: Option<&PackageName>,
     python: Option<PythonRequest>,
     install_mirrors: &PythonInstallMirrors,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     connectivity: Connectivity,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
     cache: &Cache,
@@ -857,12 +885,14 @@ pub(crate) async fn get_or_init_environment(
 ) -> Result<PythonEnvironment, ProjectError> {
     match ProjectInterpreter::discover(
         workspace,
+        member,
         workspace.install_path().as_ref(),
         python,
         python_preference,
         python_downloads,
         connectivity,
         native_tls,
+        client_options,
         allow_insecure_host,
         install_mirrors,
         no_config,
@@ -876,7 +906,7 @@ pub(crate) async fn get_or_init_environment(
 
         // Otherwise, create a virtual environment with the discovered interpreter.
         ProjectInterpreter::Interpreter(interpreter) => {
-            let venv = workspace.venv();
+            let venv = workspace.venv(member);
 
             // Avoid removing things that are not virtual environments
             let should_remove = match (venv.try_exists(), venv.join("pyvenv.cfg").try_exists()) {
@@ -928,14 +958,20 @@ pub(crate) async fn get_or_init_environment(
 
             // Determine a prompt for the environment, in order of preference:
             //
-            // 1) The name of the project
-            // 2) The name of the directory at the root of the workspace
-            // 3) No prompt
-            let prompt = workspace
-                .pyproject_toml()
-                .project
-                .as_ref()
-                .map(|p| p.name.to_string())
+            // 1) The name of the member, if it has its own environment
+            // 2) The name of the project
+            // 3) The name of the directory at the root of the workspace
+            // 4) No prompt
+            let prompt = member
+                .filter(|_| workspace.venv_layout() == WorkspaceVenv::PerMember)
+                .map(ToString::to_string)
+                .or_else(|| {
+                    workspace
+                        .pyproject_toml()
+                        .project
+                        .as_ref()
+                        .map(|p| p.name.to_string())
+                })
                 .or_else(|| {
                     workspace
                         .install_path()
@@ -967,6 +1003,7 @@ pub(crate) async fn resolve_names(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
@@ -1019,6 +1056,10 @@ pub(crate) async fn resolve_names(
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(*index_strategy)
@@ -1119,6 +1160,7 @@ pub(crate) async fn resolve_environment<'a>(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
@@ -1169,6 +1211,10 @@ pub(crate) async fn resolve_environment<'a>(
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
@@ -1297,6 +1343,7 @@ pub(crate) async fn sync_environment(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
@@ -1334,6 +1381,10 @@ pub(crate) async fn sync_environment(
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
@@ -1451,6 +1502,7 @@ pub(crate) async fn update_environment(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
@@ -1529,6 +1581,10 @@ pub(crate) async fn update_environment(
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(*index_strategy)
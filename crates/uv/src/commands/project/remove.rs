@@ -1,8 +1,13 @@
+use std::collections::BTreeSet;
 use std::fmt::Write;
 use std::path::Path;
 
 use anyhow::{Context, Result};
+use itertools::Itertools;
 use owo_colors::OwoColorize;
+use rustc_hash::FxHashSet;
+use tracing::debug;
+use walkdir::WalkDir;
 
 use uv_cache::Cache;
 use uv_client::Connectivity;
@@ -11,10 +16,12 @@ use uv_configuration::{
     PreviewMode, TrustedHost,
 };
 use uv_dispatch::SharedState;
+use uv_distribution_types::InstalledDist;
 use uv_fs::Simplified;
+use uv_installer::SitePackages;
 use uv_normalize::DEV_DEPENDENCIES;
 use uv_pep508::PackageName;
-use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_python::{PythonDownloads, PythonEnvironment, PythonPreference, PythonRequest};
 use uv_scripts::Pep723Script;
 use uv_settings::PythonInstallMirrors;
 use uv_warnings::warn_user_once;
@@ -29,7 +36,7 @@ use crate::commands::project::lock::LockMode;
 use crate::commands::project::{default_dependency_groups, ProjectError};
 use crate::commands::{diagnostics, project, ExitStatus};
 use crate::printer::Printer;
-use crate::settings::ResolverInstallerSettings;
+use crate::settings::{ClientOptions, ResolverInstallerSettings};
 
 /// Remove one or more packages from the project requirements.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -39,6 +46,8 @@ pub(crate) async fn remove(
     frozen: bool,
     no_sync: bool,
     packages: Vec<PackageName>,
+    unused: bool,
+    dry_run: bool,
     dependency_type: DependencyType,
     package: Option<PackageName>,
     python: Option<String>,
@@ -51,6 +60,7 @@ pub(crate) async fn remove(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
     cache: &Cache,
@@ -106,6 +116,42 @@ pub(crate) async fn remove(
         ),
     }?;
 
+    // If `--unused`, determine the dependencies that aren't imported by the project.
+    let packages = if unused {
+        let Target::Project(project) = &target else {
+            anyhow::bail!("`--unused` is not supported for Python scripts with inline metadata");
+        };
+
+        let unused = find_unused(project, &toml, &dependency_type, cache)?;
+        if unused.is_empty() {
+            writeln!(printer.stderr(), "No unused dependencies found")?;
+            return Ok(ExitStatus::Success);
+        }
+
+        for name in &unused {
+            writeln!(printer.stdout(), "{name}")?;
+        }
+
+        let s = if unused.len() == 1 { "y" } else { "ies" };
+        if dry_run {
+            writeln!(
+                printer.stderr(),
+                "Would remove {} unused dependenc{s}",
+                unused.len()
+            )?;
+            return Ok(ExitStatus::Success);
+        }
+        writeln!(
+            printer.stderr(),
+            "Removing {} unused dependenc{s}",
+            unused.len()
+        )?;
+
+        unused
+    } else {
+        packages
+    };
+
     for package in packages {
         match dependency_type {
             DependencyType::Production => {
@@ -194,12 +240,14 @@ pub(crate) async fn remove(
     // Discover or create the virtual environment.
     let venv = project::get_or_init_environment(
         project.workspace(),
+        project.project_name(),
         python.as_deref().map(PythonRequest::parse),
         &install_mirrors,
         python_preference,
         python_downloads,
         connectivity,
         native_tls,
+        client_options,
         allow_insecure_host,
         no_config,
         cache,
@@ -230,6 +278,7 @@ pub(crate) async fn remove(
         connectivity,
         concurrency,
         native_tls,
+        client_options,
         allow_insecure_host,
         cache,
         printer,
@@ -285,6 +334,7 @@ pub(crate) async fn remove(
         connectivity,
         concurrency,
         native_tls,
+        client_options,
         allow_insecure_host,
         cache,
         printer,
@@ -358,3 +408,230 @@ fn show_other_dependency_type_hint(
 
     Ok(())
 }
+
+/// Find the dependencies of the given type that aren't imported by the project's Python sources.
+///
+/// Each dependency is mapped to the top-level modules it provides, as recorded by its installed
+/// distribution in the project environment.
+fn find_unused(
+    project: &VirtualProject,
+    pyproject: &PyProjectTomlMut,
+    dependency_type: &DependencyType,
+    cache: &Cache,
+) -> Result<Vec<PackageName>> {
+    // Collect the dependencies of the requested type.
+    let dependencies = pyproject
+        .dependencies()
+        .into_iter()
+        .filter(|(ty, ..)| match (dependency_type, ty) {
+            (DependencyType::Dev, DependencyType::Group(group))
+            | (DependencyType::Group(group), DependencyType::Dev) => *group == *DEV_DEPENDENCIES,
+            (expected, ty) => expected == ty,
+        })
+        .map(|(.., requirement)| requirement.name)
+        .collect::<BTreeSet<_>>();
+
+    // Retain any dependencies that are explicitly allowed to go unused.
+    let allowed = project
+        .pyproject_toml()
+        .tool
+        .as_ref()
+        .and_then(|tool| tool.uv.as_ref())
+        .and_then(|uv| uv.allow_unused.as_deref())
+        .unwrap_or_default();
+
+    // Read the installed distributions from the project environment.
+    let root = project.workspace().venv(project.project_name());
+    if !root.is_dir() {
+        anyhow::bail!(
+            "No project environment found at `{}`; run `{}` to create it",
+            root.user_display().cyan(),
+            "uv sync".green()
+        );
+    }
+    let environment = PythonEnvironment::from_root(&root, cache)?;
+    let site_packages = SitePackages::from_environment(&environment)?;
+
+    let imports = imported_modules(project.root())?;
+
+    let mut unused = Vec::new();
+    let mut missing = Vec::new();
+    for name in dependencies {
+        if allowed.contains(&name) {
+            debug!("Skipping allowed dependency: `{name}`");
+            continue;
+        }
+
+        let distributions = site_packages.get_packages(&name);
+        if distributions.is_empty() {
+            missing.push(name);
+            continue;
+        }
+
+        let mut modules = BTreeSet::new();
+        for dist in distributions {
+            modules.extend(top_level_modules(dist)?);
+        }
+        if modules.is_empty() {
+            modules.insert(name.as_str().replace('-', "_"));
+        }
+
+        if modules.iter().any(|module| imports.contains(module)) {
+            debug!("Found import of `{name}`");
+        } else {
+            debug!(
+                "No imports found for `{name}` (provides: {})",
+                modules
+                    .iter()
+                    .map(|module| format!("`{module}`"))
+                    .join(", ")
+            );
+            unused.push(name);
+        }
+    }
+
+    if !missing.is_empty() {
+        let s = if missing.len() == 1 { "y" } else { "ies" };
+        warn_user_once!(
+            "Skipping dependenc{s} that aren't installed in the project environment: {}",
+            missing.iter().map(|name| format!("`{name}`")).join(", ")
+        );
+    }
+
+    Ok(unused)
+}
+
+/// Determine the top-level modules provided by an installed distribution.
+///
+/// Reads `top_level.txt` if present, falling back to the entries in the `RECORD` file.
+fn top_level_modules(dist: &InstalledDist) -> Result<BTreeSet<String>> {
+    let mut modules = BTreeSet::new();
+
+    if let Ok(contents) = fs_err::read_to_string(dist.path().join("top_level.txt")) {
+        for line in contents.lines() {
+            if let Some(module) = line
+                .trim()
+                .split('/')
+                .next()
+                .filter(|module| !module.is_empty())
+            {
+                modules.insert(module.to_string());
+            }
+        }
+        return Ok(modules);
+    }
+
+    let Ok(mut record) = fs_err::File::open(dist.path().join("RECORD")) else {
+        return Ok(modules);
+    };
+    for entry in uv_install_wheel::read_record_file(&mut record)? {
+        let mut components = entry.path.split('/');
+        let Some(first) = components.next() else {
+            continue;
+        };
+        let is_dir = components.next().is_some();
+
+        // Skip metadata, scripts and data files, and editable hooks.
+        if first == ".."
+            || first == "__pycache__"
+            || first.starts_with("__editable__")
+            || first.ends_with(".dist-info")
+            || first.ends_with(".data")
+        {
+            continue;
+        }
+
+        let module = if is_dir {
+            Some(first)
+        } else if let Some(module) = first.strip_suffix(".py") {
+            Some(module)
+        } else if first.ends_with(".so") || first.ends_with(".pyd") {
+            // Extension modules carry an ABI tag, as in `_cffi_backend.cpython-312-darwin.so`.
+            first.split('.').next()
+        } else {
+            None
+        };
+        if let Some(module) = module.filter(|module| is_identifier(module)) {
+            modules.insert(module.to_string());
+        }
+    }
+
+    Ok(modules)
+}
+
+/// Collect the top-level modules imported by the Python sources in the given directory.
+///
+/// Hidden directories, virtual environments, and nested projects are skipped.
+fn imported_modules(root: &Path) -> Result<FxHashSet<String>> {
+    let mut modules = FxHashSet::default();
+
+    let walker = WalkDir::new(root).into_iter().filter_entry(|entry| {
+        if entry.depth() == 0 || !entry.file_type().is_dir() {
+            return true;
+        }
+        let hidden = entry.file_name().to_string_lossy().starts_with('.');
+        !(hidden
+            || entry.file_name() == "__pycache__"
+            || entry.path().join("pyvenv.cfg").is_file()
+            || entry.path().join("pyproject.toml").is_file())
+    });
+
+    for entry in walker {
+        let entry = entry?;
+        if !entry.file_type().is_file()
+            || entry
+                .path()
+                .extension()
+                .is_none_or(|extension| extension != "py")
+        {
+            continue;
+        }
+
+        let Ok(contents) = fs_err::read_to_string(entry.path()) else {
+            debug!(
+                "Skipping unreadable file: `{}`",
+                entry.path().user_display()
+            );
+            continue;
+        };
+
+        for line in contents.lines() {
+            let line = line.split('#').next().unwrap_or_default();
+            for statement in line.split(';') {
+                let Some((keyword, rest)) = statement.trim().split_once(char::is_whitespace) else {
+                    continue;
+                };
+                let names = match keyword {
+                    // For example: `import foo.bar as baz, qux`.
+                    "import" => rest.split(',').collect::<Vec<_>>(),
+                    // For example: `from foo.bar import baz`. Relative imports are ignored.
+                    "from" => vec![rest],
+                    _ => continue,
+                };
+                for name in names {
+                    let Some(module) = name
+                        .split_whitespace()
+                        .next()
+                        .and_then(|name| name.split('.').next())
+                    else {
+                        continue;
+                    };
+                    if is_identifier(module) {
+                        modules.insert(module.to_string());
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(modules)
+}
+
+/// Returns `true` if the given string is a valid Python identifier.
+fn is_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_alphabetic() || first == '_')
+        && chars.all(|char| char.is_alphanumeric() || char == '_')
+}
@@ -1,11 +1,12 @@
 use std::borrow::Cow;
 use std::collections::BTreeMap;
-use std::ffi::OsString;
+use std::ffi::{OsStr, OsString};
 use std::fmt::Write;
 use std::io::Read;
 use std::path::{Path, PathBuf};
 
 use anyhow::{anyhow, bail, Context};
+use futures::stream::FuturesUnordered;
 use futures::StreamExt;
 use itertools::Itertools;
 use owo_colors::OwoColorize;
@@ -36,6 +37,7 @@ use uv_scripts::Pep723Item;
 use uv_settings::PythonInstallMirrors;
 use uv_static::EnvVars;
 use uv_warnings::warn_user;
+use uv_workspace::pyproject::WorkspaceVenv;
 use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace, WorkspaceError};
 
 use crate::commands::pip::loggers::{
@@ -46,6 +48,7 @@ use crate::commands::project::environment::CachedEnvironment;
 use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::LockMode;
 use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::task::TaskPlan;
 use crate::commands::project::{
     default_dependency_groups, validate_project_requires_python, DependencyGroupsTarget,
     EnvironmentSpecification, ProjectError, ScriptInterpreter, WorkspacePython,
@@ -53,7 +56,7 @@ use crate::commands::project::{
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{diagnostics, project, ExitStatus};
 use crate::printer::Printer;
-use crate::settings::ResolverInstallerSettings;
+use crate::settings::{ClientOptions, ResolverInstallerSettings};
 
 /// Run a command.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -68,6 +71,8 @@ pub(crate) async fn run(
     no_sync: bool,
     isolated: bool,
     all_packages: bool,
+    jobs: usize,
+    keep_going: bool,
     package: Option<PackageName>,
     no_project: bool,
     no_config: bool,
@@ -84,6 +89,7 @@ pub(crate) async fn run(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
@@ -192,6 +198,7 @@ pub(crate) async fn run(
             python_downloads,
             connectivity,
             native_tls,
+            client_options,
             allow_insecure_host,
             &install_mirrors,
             no_config,
@@ -313,6 +320,7 @@ pub(crate) async fn run(
                 connectivity,
                 concurrency,
                 native_tls,
+                client_options,
                 allow_insecure_host,
                 cache,
                 printer,
@@ -353,6 +361,12 @@ pub(crate) async fn run(
     // The lockfile used for the base environment.
     let mut lock: Option<(Lock, PathBuf)> = None;
 
+    // If the command refers to a task in `tool.uv.scripts`, the resolved task and its dependencies.
+    let mut tasks: Option<TaskPlan> = None;
+
+    // If the command should be run in every workspace member, the name and root of each member.
+    let mut members: Vec<(PackageName, PathBuf)> = Vec::new();
+
     // Discover and sync the base environment.
     let temp_dir;
     let base_interpreter = if let Some(script_interpreter) = script_interpreter {
@@ -503,6 +517,41 @@ pub(crate) async fn run(
                 );
             }
 
+            if all_packages {
+                // Each member has its own environment, so commands can't share a single one.
+                if project.workspace().venv_layout() == WorkspaceVenv::PerMember {
+                    return Err(ProjectError::AllPackagesPerMember.into());
+                }
+
+                members = project
+                    .workspace()
+                    .packages()
+                    .iter()
+                    .map(|(name, member)| (name.clone(), member.root().clone()))
+                    .collect();
+            }
+
+            // Determine whether the command refers to a task in `tool.uv.scripts`.
+            if let Some(RunCommand::External(name, args)) = command.as_ref() {
+                tasks = TaskPlan::from_project(&project, name, args)?;
+
+                // Tasks are defined by a single project, and so can't be run in every member.
+                if all_packages && tasks.is_some() {
+                    bail!(
+                        "`--all-packages` is not supported for tasks, but `{}` is defined in `tool.uv.scripts`",
+                        name.to_string_lossy()
+                    );
+                }
+            }
+
+            // Include any dependency groups required by the task.
+            let dev = if let Some(tasks) = tasks.as_ref() {
+                tasks.validate_groups(&project)?;
+                dev.with_groups(tasks.groups())
+            } else {
+                dev
+            };
+
             let venv = if isolated {
                 debug!("Creating isolated virtual environment");
 
@@ -511,6 +560,10 @@ pub(crate) async fn run(
                 let client_builder = BaseClientBuilder::new()
                     .connectivity(connectivity)
                     .native_tls(native_tls)
+                    .cache_stats(client_options.cache_stats.clone())
+                    .retry_backoff(client_options.retry_backoff)
+                    .concurrency_per_host(client_options.concurrency_per_host)
+                    .proxies(client_options.proxies.clone())
                     .allow_insecure_host(allow_insecure_host.to_vec());
 
                 // Resolve the Python request and requirement for the workspace.
@@ -565,12 +618,14 @@ pub(crate) async fn run(
                 // project.
                 project::get_or_init_environment(
                     project.workspace(),
+                    project.project_name(),
                     python.as_deref().map(PythonRequest::parse),
                     &install_mirrors,
                     python_preference,
                     python_downloads,
                     connectivity,
                     native_tls,
+                    client_options,
                     allow_insecure_host,
                     no_config,
                     cache,
@@ -636,6 +691,7 @@ pub(crate) async fn run(
                     connectivity,
                     concurrency,
                     native_tls,
+                    client_options,
                     allow_insecure_host,
                     cache,
                     printer,
@@ -717,6 +773,7 @@ pub(crate) async fn run(
                     connectivity,
                     concurrency,
                     native_tls,
+                    client_options,
                     allow_insecure_host,
                     cache,
                     printer,
@@ -747,6 +804,10 @@ pub(crate) async fn run(
                 let client_builder = BaseClientBuilder::new()
                     .connectivity(connectivity)
                     .native_tls(native_tls)
+                    .cache_stats(client_options.cache_stats.clone())
+                    .retry_backoff(client_options.retry_backoff)
+                    .concurrency_per_host(client_options.concurrency_per_host)
+                    .proxies(client_options.proxies.clone())
                     .allow_insecure_host(allow_insecure_host.to_vec());
 
                 // (1) Explicit request from user
@@ -815,6 +876,10 @@ pub(crate) async fn run(
         let client_builder = BaseClientBuilder::new()
             .connectivity(connectivity)
             .native_tls(native_tls)
+            .cache_stats(client_options.cache_stats.clone())
+            .retry_backoff(client_options.retry_backoff)
+            .concurrency_per_host(client_options.concurrency_per_host)
+            .proxies(client_options.proxies.clone())
             .allow_insecure_host(allow_insecure_host.to_vec());
 
         let spec =
@@ -869,6 +934,7 @@ pub(crate) async fn run(
                     connectivity,
                     concurrency,
                     native_tls,
+                    client_options,
                     allow_insecure_host,
                     cache,
                     printer,
@@ -991,9 +1057,6 @@ pub(crate) async fn run(
         return Ok(ExitStatus::Error);
     };
 
-    debug!("Running `{command}`");
-    let mut process = command.as_command(interpreter);
-
     // Construct the `PATH` environment variable.
     let new_path = std::env::join_paths(
         ephemeral_env
@@ -1018,6 +1081,28 @@ pub(crate) async fn run(
                     .flat_map(std::env::split_paths),
             ),
     )?;
+
+    // If the command refers to a task, run it (along with its dependencies) instead.
+    if let Some(tasks) = tasks {
+        return tasks.run(&new_path, interpreter, printer).await;
+    }
+
+    // If requested, run the command in each workspace member.
+    if !members.is_empty() {
+        return run_members(
+            command.absolute()?,
+            &members,
+            interpreter,
+            &new_path,
+            jobs,
+            keep_going,
+            printer,
+        )
+        .await;
+    }
+
+    debug!("Running `{command}`");
+    let mut process = command.as_command(interpreter);
     process.env(EnvVars::PATH, new_path);
 
     // Ensure `VIRTUAL_ENV` is set.
@@ -1038,6 +1123,132 @@ pub(crate) async fn run(
     let _handler = tokio::spawn(async { while tokio::signal::ctrl_c().await.is_ok() {} });
 
     // Exit based on the result of the command.
+    let status = wait_for_child(&mut handle).await?;
+
+    Ok(exit_status(status))
+}
+
+/// Run a command once in each workspace member, using the member's root as the working directory.
+///
+/// Up to `jobs` members are run concurrently. Unless `keep_going` is set, no further members are
+/// started once the command fails in any member. If the command can't be run in a member, any
+/// members that are already running are awaited before the error is returned.
+async fn run_members(
+    command: RunCommand,
+    members: &[(PackageName, PathBuf)],
+    interpreter: &Interpreter,
+    path: &OsStr,
+    jobs: usize,
+    keep_going: bool,
+    printer: Printer,
+) -> anyhow::Result<ExitStatus> {
+    // Ignore signals in the parent process, deferring them to the children.
+    let _handler = tokio::spawn(async { while tokio::signal::ctrl_c().await.is_ok() {} });
+
+    let mut pending = members.iter().enumerate();
+    let mut running = FuturesUnordered::new();
+    let mut statuses: Vec<Option<std::process::ExitStatus>> = vec![None; members.len()];
+    let mut failed = false;
+    let mut error = None;
+
+    loop {
+        // Start as many members as allowed, unless we're stopping due to a failure or an error.
+        while running.len() < jobs && error.is_none() && (keep_going || !failed) {
+            let Some((index, (name, root))) = pending.next() else {
+                break;
+            };
+
+            match spawn_member(&command, name, root, interpreter, path, printer) {
+                Ok(mut handle) => running.push(async move {
+                    let status = wait_for_child(&mut handle).await;
+                    if status.is_err() {
+                        let _ = handle.start_kill();
+                    }
+                    (index, status)
+                }),
+                Err(err) => error = Some(err),
+            }
+        }
+
+        // On error, wait for any members that are already running before returning.
+        let Some((index, status)) = running.next().await else {
+            break;
+        };
+        match status {
+            Ok(status) => {
+                if !status.success() {
+                    failed = true;
+                }
+                statuses[index] = Some(status);
+            }
+            Err(err) => {
+                error.get_or_insert(err);
+            }
+        }
+    }
+
+    if let Some(err) = error {
+        return Err(err);
+    }
+
+    // Summarize the outcome in each member.
+    writeln!(printer.stderr())?;
+    for ((name, _), status) in members.iter().zip(&statuses) {
+        let outcome = match status {
+            Some(status) if status.success() => "success".green().to_string(),
+            Some(status) => match status.code() {
+                Some(code) => format!("exit code {code}").red().to_string(),
+                None => "terminated by signal".red().to_string(),
+            },
+            None => "skipped".dimmed().to_string(),
+        };
+        writeln!(printer.stderr(), "{}: {outcome}", name.cyan())?;
+    }
+
+    if failed {
+        Ok(ExitStatus::Failure)
+    } else {
+        Ok(ExitStatus::Success)
+    }
+}
+
+/// Spawn the command in the given workspace member, using the member's root as the working
+/// directory.
+fn spawn_member(
+    command: &RunCommand,
+    name: &PackageName,
+    root: &Path,
+    interpreter: &Interpreter,
+    path: &OsStr,
+    printer: Printer,
+) -> anyhow::Result<tokio::process::Child> {
+    debug!("Running `{command}` in `{name}` at: {}", root.display());
+    writeln!(
+        printer.stderr(),
+        "{} `{}` in {}",
+        "Running".bold().green(),
+        command.display_executable(),
+        name.cyan()
+    )?;
+
+    let mut process = command.as_command(interpreter);
+    process.current_dir(root);
+    process.env(EnvVars::PATH, path);
+
+    // Ensure `VIRTUAL_ENV` is set.
+    if interpreter.is_virtualenv() {
+        process.env(EnvVars::VIRTUAL_ENV, interpreter.sys_prefix().as_os_str());
+    };
+
+    process
+        .spawn()
+        .with_context(|| format!("Failed to spawn: `{}`", command.display_executable()))
+}
+
+/// Wait for the child process to exit, forwarding `SIGTERM` to the child on Unix.
+pub(super) async fn wait_for_child(
+    handle: &mut tokio::process::Child,
+) -> anyhow::Result<std::process::ExitStatus> {
     #[cfg(unix)]
     let status = {
         use tokio::select;
@@ -1052,7 +1263,7 @@ pub(crate) async fn run(
 
                 // `SIGTERM`
                 _ = term_signal.recv() => {
-                    let _ = terminate_process(&mut handle);
+                    let _ = terminate_process(handle);
                 }
             };
         }
@@ -1061,10 +1272,15 @@ pub(crate) async fn run(
     #[cfg(not(unix))]
     let status = handle.wait().await?;
 
+    Ok(status)
+}
+
+/// Convert the exit status of a child process into an [`ExitStatus`].
+pub(super) fn exit_status(status: std::process::ExitStatus) -> ExitStatus {
     if let Some(code) = status.code() {
         debug!("Command exited with code: {code}");
         if let Ok(code) = u8::try_from(code) {
-            Ok(ExitStatus::External(code))
+            ExitStatus::External(code)
         } else {
             #[allow(clippy::exit)]
             std::process::exit(code);
@@ -1075,7 +1291,7 @@ pub(crate) async fn run(
             use std::os::unix::process::ExitStatusExt;
             debug!("Command exited with signal: {:?}", status.signal());
         }
-        Ok(ExitStatus::Failure)
+        ExitStatus::Failure
     }
 }
 
@@ -1168,6 +1384,26 @@ pub(crate) enum RunCommand {
 }
 
 impl RunCommand {
+    /// Resolve any relative script or package paths against the current working directory, such
+    /// that the command can be run from a different directory.
+    fn absolute(self) -> std::io::Result<Self> {
+        Ok(match self {
+            Self::PythonScript(target, args) => {
+                Self::PythonScript(std::path::absolute(target)?, args)
+            }
+            Self::PythonGuiScript(target, args) => {
+                Self::PythonGuiScript(std::path::absolute(target)?, args)
+            }
+            Self::PythonPackage(target, args) => {
+                Self::PythonPackage(std::path::absolute(target)?, args)
+            }
+            Self::PythonZipapp(target, args) => {
+                Self::PythonZipapp(std::path::absolute(target)?, args)
+            }
+            command => command,
+        })
+    }
+
     /// Return the name of the target executable, for display purposes.
     fn display_executable(&self) -> Cow<'_, str> {
         match self {
@@ -1373,6 +1609,7 @@ impl RunCommand {
         gui_script: bool,
         connectivity: Connectivity,
         native_tls: bool,
+        client_options: &ClientOptions,
         allow_insecure_host: &[TrustedHost],
     ) -> anyhow::Result<Self> {
         let (target, args) = command.split();
@@ -1417,6 +1654,10 @@ impl RunCommand {
                 let client = BaseClientBuilder::new()
                     .connectivity(connectivity)
                     .native_tls(native_tls)
+                    .cache_stats(client_options.cache_stats.clone())
+                    .retry_backoff(client_options.retry_backoff)
+                    .concurrency_per_host(client_options.concurrency_per_host)
+                    .proxies(client_options.proxies.clone())
                     .allow_insecure_host(allow_insecure_host.to_vec())
                     .build();
                 let response = client.for_host(&url).get(url.clone()).send().await?;
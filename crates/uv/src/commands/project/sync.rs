@@ -1,22 +1,27 @@
 use std::borrow::Cow;
+use std::fmt::Write;
 use std::path::Path;
 use std::str::FromStr;
 
 use anyhow::{Context, Result};
 use itertools::Itertools;
+use owo_colors::OwoColorize;
 
 use uv_auth::store_credentials;
 use uv_cache::Cache;
 use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
 use uv_configuration::{
-    Concurrency, Constraints, DevGroupsManifest, DevGroupsSpecification, EditableMode,
-    ExtrasSpecification, HashCheckingMode, InstallOptions, LowerBound, PreviewMode, TrustedHost,
+    BuildOptions, Concurrency, Constraints, DevGroupsManifest, DevGroupsSpecification,
+    EditableMode, ExtrasSpecification, HashCheckingMode, InstallOptions, LowerBound, PreviewMode,
+    TrustedHost,
 };
 use uv_dispatch::{BuildDispatch, SharedState};
 use uv_distribution_types::{
-    DirectorySourceDist, Dist, Index, Resolution, ResolvedDist, SourceDist,
+    DirectorySourceDist, Dist, DistributionMetadata, Index, InstalledMetadata, Name, Resolution,
+    ResolvedDist, SourceDist,
 };
-use uv_installer::SitePackages;
+use uv_fs::Simplified;
+use uv_installer::{Plan, Planner, SitePackages};
 use uv_normalize::PackageName;
 use uv_pep508::{MarkerTree, Requirement, VersionOrUrl};
 use uv_pypi_types::{
@@ -27,7 +32,9 @@ use uv_resolver::{FlatIndex, Installable};
 use uv_settings::PythonInstallMirrors;
 use uv_types::{BuildIsolation, HashStrategy};
 use uv_warnings::warn_user;
-use uv_workspace::pyproject::{DependencyGroupSpecifier, Source, Sources, ToolUvSources};
+use uv_workspace::pyproject::{
+    DependencyGroupSpecifier, Source, Sources, ToolUvSources, WorkspaceVenv,
+};
 use uv_workspace::{DiscoveryOptions, MemberDiscovery, VirtualProject, Workspace};
 
 use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger, InstallLogger};
@@ -37,10 +44,11 @@ use crate::commands::project::install_target::InstallTarget;
 use crate::commands::project::lock::{do_safe_lock, LockMode};
 use crate::commands::project::{
     default_dependency_groups, detect_conflicts, DependencyGroupsTarget, ProjectError,
+    ProjectInterpreter,
 };
-use crate::commands::{diagnostics, project, ExitStatus};
+use crate::commands::{diagnostics, project, ChangeEventKind, DryRunEvent, ExitStatus};
 use crate::printer::Printer;
-use crate::settings::{InstallerSettingsRef, ResolverInstallerSettings};
+use crate::settings::{ClientOptions, InstallerSettingsRef, ResolverInstallerSettings};
 
 /// Sync the project environment.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -48,6 +56,7 @@ pub(crate) async fn sync(
     project_dir: &Path,
     locked: bool,
     frozen: bool,
+    check: bool,
     all_packages: bool,
     package: Option<PackageName>,
     extras: ExtrasSpecification,
@@ -64,6 +73,7 @@ pub(crate) async fn sync(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
     cache: &Cache,
@@ -91,6 +101,11 @@ pub(crate) async fn sync(
         VirtualProject::discover(project_dir, &DiscoveryOptions::default()).await?
     };
 
+    // Each member has its own environment, so the workspace can't be synced into a single one.
+    if all_packages && project.workspace().venv_layout() == WorkspaceVenv::PerMember {
+        return Err(ProjectError::AllPackagesPerMember.into());
+    }
+
     // Validate that any referenced dependency groups are defined in the workspace.
     if !frozen {
         let target = match &project {
@@ -117,21 +132,60 @@ pub(crate) async fn sync(
         warn_user!("Skipping installation of entry points (`project.scripts`) because this project is not packaged; to install entry points, set `tool.uv.package = true` or define a `build-system`");
     }
 
-    // Discover or create the virtual environment.
-    let venv = project::get_or_init_environment(
-        project.workspace(),
-        python.as_deref().map(PythonRequest::parse),
-        &install_mirrors,
-        python_preference,
-        python_downloads,
-        connectivity,
-        native_tls,
-        allow_insecure_host,
-        no_config,
-        cache,
-        printer,
-    )
-    .await?;
+    // Discover or create the virtual environment. With `--check`, the environment must already
+    // exist, as it's never created or replaced.
+    let venv = if check {
+        match ProjectInterpreter::discover(
+            project.workspace(),
+            project.project_name(),
+            project.workspace().install_path().as_ref(),
+            python.as_deref().map(PythonRequest::parse),
+            python_preference,
+            python_downloads,
+            connectivity,
+            native_tls,
+            client_options,
+            allow_insecure_host,
+            &install_mirrors,
+            no_config,
+            cache,
+            printer,
+        )
+        .await?
+        {
+            ProjectInterpreter::Environment(environment) => environment,
+            ProjectInterpreter::Interpreter(_) => {
+                writeln!(
+                    printer.stderr(),
+                    "No compatible project environment found at `{}`; run `{}` to create it",
+                    project
+                        .workspace()
+                        .venv(project.project_name())
+                        .user_display()
+                        .cyan(),
+                    "uv sync".green()
+                )?;
+                return Ok(ExitStatus::Failure);
+            }
+        }
+    } else {
+        project::get_or_init_environment(
+            project.workspace(),
+            project.project_name(),
+            python.as_deref().map(PythonRequest::parse),
+            &install_mirrors,
+            python_preference,
+            python_downloads,
+            connectivity,
+            native_tls,
+            client_options,
+            allow_insecure_host,
+            no_config,
+            cache,
+            printer,
+        )
+        .await?
+    };
 
     // Initialize any shared state.
     let state = SharedState::default();
@@ -139,7 +193,7 @@ pub(crate) async fn sync(
     // Determine the lock mode.
     let mode = if frozen {
         LockMode::Frozen
-    } else if locked {
+    } else if locked || check {
         LockMode::Locked(venv.interpreter())
     } else {
         LockMode::Write(venv.interpreter())
@@ -155,6 +209,7 @@ pub(crate) async fn sync(
         connectivity,
         concurrency,
         native_tls,
+        client_options,
         allow_insecure_host,
         cache,
         printer,
@@ -216,6 +271,29 @@ pub(crate) async fn sync(
         }
     };
 
+    // With `--check`, compare the environment to the lockfile without modifying it.
+    if check {
+        return match do_check(
+            target,
+            &venv,
+            &extras,
+            &dev.with_defaults(defaults),
+            editable,
+            &install_options,
+            modifications,
+            settings.as_ref().into(),
+            cache,
+            printer,
+        ) {
+            Ok(true) => Ok(ExitStatus::Success),
+            Ok(false) => Ok(ExitStatus::Failure),
+            Err(ProjectError::Operation(err)) => diagnostics::OperationDiagnostic::default()
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into())),
+            Err(err) => Err(err.into()),
+        };
+    }
+
     // Perform the sync operation.
     match do_sync(
         target,
@@ -231,6 +309,7 @@ pub(crate) async fn sync(
         connectivity,
         concurrency,
         native_tls,
+        client_options,
         allow_insecure_host,
         cache,
         printer,
@@ -266,6 +345,7 @@ pub(super) async fn do_sync(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
@@ -299,66 +379,19 @@ pub(super) async fn do_sync(
         sources,
     } = settings;
 
-    // Validate that the Python version is supported by the lockfile.
-    if !target
-        .lock()
-        .requires_python()
-        .contains(venv.interpreter().python_version())
-    {
-        return Err(ProjectError::LockedPythonIncompatibility(
-            venv.interpreter().python_version().clone(),
-            target.lock().requires_python().clone(),
-        ));
-    }
-
-    // Validate that the set of requested extras and development groups are compatible.
-    detect_conflicts(target.lock(), extras, dev)?;
-
-    // Determine the markers to use for resolution.
-    let marker_env = venv.interpreter().resolver_marker_environment();
-
-    // Validate that the platform is supported by the lockfile.
-    let environments = target.lock().supported_environments();
-    if !environments.is_empty() {
-        if !environments
-            .iter()
-            .any(|env| env.evaluate(&marker_env, &[]))
-        {
-            return Err(ProjectError::LockedPlatformIncompatibility(
-                // For error reporting, we use the "simplified"
-                // supported environments, because these correspond to
-                // what the end user actually wrote. The non-simplified
-                // environments, by contrast, are explicitly
-                // constrained by `requires-python`.
-                target
-                    .lock()
-                    .simplified_supported_environments()
-                    .into_iter()
-                    .filter_map(MarkerTree::contents)
-                    .map(|env| format!("`{env}`"))
-                    .join(", "),
-            ));
-        }
-    }
-
-    // Determine the tags to use for resolution.
-    let tags = venv.interpreter().tags()?;
-
     // Read the lockfile.
-    let resolution = target.to_resolution(
-        &marker_env,
-        tags,
+    let resolution = lock_resolution(
+        target,
+        venv,
         extras,
         dev,
-        build_options,
+        editable,
         &install_options,
+        build_options,
     )?;
 
-    // Always skip virtual projects, which shouldn't be built or installed.
-    let resolution = apply_no_virtual_project(resolution);
-
-    // If necessary, convert editable to non-editable distributions.
-    let resolution = apply_editable_mode(resolution, editable);
+    // Determine the tags to use for installation.
+    let tags = venv.interpreter().tags()?;
 
     // Add all authenticated sources to the cache.
     for index in index_locations.allowed_indexes() {
@@ -373,6 +406,10 @@ pub(super) async fn do_sync(
     // Initialize the registry client.
     let client = RegistryClientBuilder::new(cache.clone())
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .connectivity(connectivity)
         .index_urls(index_locations.index_urls())
         .index_strategy(index_strategy)
@@ -464,6 +501,205 @@ pub(super) async fn do_sync(
     Ok(())
 }
 
+/// Determine the [`Resolution`] to install from the lockfile, validating that the lockfile is
+/// compatible with the environment.
+fn lock_resolution(
+    target: InstallTarget<'_>,
+    venv: &PythonEnvironment,
+    extras: &ExtrasSpecification,
+    dev: &DevGroupsManifest,
+    editable: EditableMode,
+    install_options: &InstallOptions,
+    build_options: &BuildOptions,
+) -> Result<Resolution, ProjectError> {
+    // Validate that the Python version is supported by the lockfile.
+    if !target
+        .lock()
+        .requires_python()
+        .contains(venv.interpreter().python_version())
+    {
+        return Err(ProjectError::LockedPythonIncompatibility(
+            venv.interpreter().python_version().clone(),
+            target.lock().requires_python().clone(),
+        ));
+    }
+
+    // Validate that the set of requested extras and development groups are compatible.
+    detect_conflicts(target.lock(), extras, dev)?;
+
+    // Determine the markers to use for resolution.
+    let marker_env = venv.interpreter().resolver_marker_environment();
+
+    // Validate that the platform is supported by the lockfile.
+    let environments = target.lock().supported_environments();
+    if !environments.is_empty() {
+        if !environments
+            .iter()
+            .any(|env| env.evaluate(&marker_env, &[]))
+        {
+            return Err(ProjectError::LockedPlatformIncompatibility(
+                // For error reporting, we use the "simplified"
+                // supported environments, because these correspond to
+                // what the end user actually wrote. The non-simplified
+                // environments, by contrast, are explicitly
+                // constrained by `requires-python`.
+                target
+                    .lock()
+                    .simplified_supported_environments()
+                    .into_iter()
+                    .filter_map(MarkerTree::contents)
+                    .map(|env| format!("`{env}`"))
+                    .join(", "),
+            ));
+        }
+    }
+
+    // Determine the tags to use for resolution.
+    let tags = venv.interpreter().tags()?;
+
+    // Read the lockfile.
+    let resolution = target.to_resolution(
+        &marker_env,
+        tags,
+        extras,
+        dev,
+        build_options,
+        install_options,
+    )?;
+
+    // Always skip virtual projects, which shouldn't be built or installed.
+    let resolution = apply_no_virtual_project(resolution);
+
+    // If necessary, convert editable to non-editable distributions.
+    let resolution = apply_editable_mode(resolution, editable);
+
+    Ok(resolution)
+}
+
+/// Check that an environment is in sync with a lockfile, without modifying the environment.
+///
+/// Reports the packages that would be installed, removed, or reinstalled by a sync, and returns
+/// `true` if the environment is in sync.
+fn do_check(
+    target: InstallTarget<'_>,
+    venv: &PythonEnvironment,
+    extras: &ExtrasSpecification,
+    dev: &DevGroupsManifest,
+    editable: EditableMode,
+    install_options: &InstallOptions,
+    modifications: Modifications,
+    settings: InstallerSettingsRef<'_>,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<bool, ProjectError> {
+    let start = std::time::Instant::now();
+
+    // Read the lockfile.
+    let resolution = lock_resolution(
+        target,
+        venv,
+        extras,
+        dev,
+        editable,
+        install_options,
+        settings.build_options,
+    )?;
+
+    // Extract the hashes from the lockfile.
+    let hasher = HashStrategy::from_resolution(&resolution, HashCheckingMode::Verify)?;
+
+    // Determine the changes that a sync would make to the environment.
+    let site_packages = SitePackages::from_environment(venv)?;
+    let Plan {
+        cached,
+        remote,
+        reinstalls,
+        extraneous,
+    } = Planner::new(&resolution)
+        .build(
+            site_packages,
+            settings.reinstall,
+            settings.build_options,
+            &hasher,
+            settings.index_locations,
+            settings.config_setting,
+            cache,
+            venv,
+            venv.interpreter().tags()?,
+        )
+        .context("Failed to determine installation plan")?;
+
+    // In `--inexact` mode, extraneous packages are retained.
+    let extraneous = match modifications {
+        Modifications::Sufficient => vec![],
+        Modifications::Exact => extraneous,
+    };
+
+    let mut installs = remote
+        .iter()
+        .map(|dist| (dist.name().clone(), dist.version_or_url().to_string()))
+        .chain(
+            cached
+                .iter()
+                .map(|dist| (dist.name().clone(), dist.installed_version().to_string())),
+        )
+        .collect::<Vec<_>>();
+
+    // A package that would be removed and installed at the same version is a reinstall.
+    let mut events = Vec::new();
+    for dist in reinstalls.iter().chain(extraneous.iter()) {
+        let version = dist.installed_version().to_string();
+        let kind = if let Some(index) = installs
+            .iter()
+            .position(|(name, install)| name == dist.name() && *install == version)
+        {
+            installs.remove(index);
+            ChangeEventKind::Reinstalled
+        } else {
+            ChangeEventKind::Removed
+        };
+        events.push(DryRunEvent {
+            name: dist.name().clone(),
+            version,
+            kind,
+        });
+    }
+    events.extend(installs.into_iter().map(|(name, version)| DryRunEvent {
+        name,
+        version,
+        kind: ChangeEventKind::Added,
+    }));
+
+    if events.is_empty() {
+        DefaultInstallLogger.on_audit(resolution.len(), start, printer)?;
+        return Ok(true);
+    }
+
+    writeln!(
+        printer.stderr(),
+        "The project environment at `{}` is out of sync with the lockfile:",
+        venv.root().user_display().cyan()
+    )?;
+    for event in events
+        .into_iter()
+        .sorted_unstable_by(|a, b| a.name.cmp(&b.name).then_with(|| a.kind.cmp(&b.kind)))
+    {
+        let symbol = match event.kind {
+            ChangeEventKind::Added => "+".green().to_string(),
+            ChangeEventKind::Removed => "-".red().to_string(),
+            ChangeEventKind::Reinstalled => "~".yellow().to_string(),
+        };
+        writeln!(
+            printer.stderr(),
+            " {symbol} {}{}",
+            event.name.bold(),
+            event.version.dimmed()
+        )?;
+    }
+
+    Ok(false)
+}
+
 /// Filter out any virtual workspace members.
 fn apply_no_virtual_project(resolution: Resolution) -> Resolution {
     resolution.filter(|dist| {
@@ -0,0 +1,308 @@
+use std::collections::BTreeMap;
+use std::ffi::{OsStr, OsString};
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anyhow::{bail, Context};
+use owo_colors::OwoColorize;
+use tokio::process::Command;
+use tracing::debug;
+
+use uv_fs::Simplified;
+use uv_normalize::GroupName;
+use uv_python::Interpreter;
+use uv_static::EnvVars;
+use uv_warnings::warn_user;
+use uv_workspace::pyproject::{ScriptCommand, ToolUvScript};
+use uv_workspace::VirtualProject;
+
+use crate::commands::project::run::{exit_status, wait_for_child};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// A task from `tool.uv.scripts`.
+#[derive(Debug)]
+struct Task {
+    /// The name of the task.
+    name: String,
+    /// The task definition.
+    script: ToolUvScript,
+}
+
+/// A task from `tool.uv.scripts`, along with its dependencies, in the order in which they should
+/// be run.
+#[derive(Debug)]
+pub(crate) struct TaskPlan {
+    /// The tasks to run; the requested task is always last.
+    tasks: Vec<Task>,
+    /// The directory containing the `pyproject.toml` in which the tasks are declared.
+    root: PathBuf,
+    /// The additional arguments to pass to the requested task.
+    args: Vec<OsString>,
+}
+
+impl TaskPlan {
+    /// Resolve the task with the given name, along with its dependencies.
+    ///
+    /// Tasks are read from the `tool.uv.scripts` table of the current project, falling back to
+    /// that of the workspace root. Returns `None` if no such task is declared.
+    pub(crate) fn from_project(
+        project: &VirtualProject,
+        name: &OsStr,
+        args: &[OsString],
+    ) -> anyhow::Result<Option<Self>> {
+        let Some(name) = name.to_str() else {
+            return Ok(None);
+        };
+
+        let candidates = [
+            (project.pyproject_toml(), project.root()),
+            (
+                project.workspace().pyproject_toml(),
+                project.workspace().install_path().as_path(),
+            ),
+        ];
+
+        for (pyproject_toml, root) in candidates {
+            let Some(scripts) = pyproject_toml
+                .tool
+                .as_ref()
+                .and_then(|tool| tool.uv.as_ref())
+                .and_then(|uv| uv.scripts.as_ref())
+            else {
+                continue;
+            };
+            let Some((name, _)) = scripts.get_key_value(name) else {
+                continue;
+            };
+
+            debug!("Found task `{name}` in: `{}`", root.display());
+
+            // Order the tasks such that each task runs after its dependencies.
+            let mut tasks = Vec::new();
+            let mut stack = Vec::new();
+            visit(name, scripts, &mut stack, &mut tasks)?;
+
+            return Ok(Some(Self {
+                tasks,
+                root: root.to_path_buf(),
+                args: args.to_vec(),
+            }));
+        }
+
+        Ok(None)
+    }
+
+    /// The dependency groups required by the tasks.
+    pub(crate) fn groups(&self) -> Vec<GroupName> {
+        let mut groups = Vec::new();
+        for task in &self.tasks {
+            for group in task.script.groups() {
+                if !groups.contains(group) {
+                    groups.push(group.clone());
+                }
+            }
+        }
+        groups
+    }
+
+    /// Validate that the dependency groups required by the tasks are defined in the given project.
+    ///
+    /// Tasks declared in the workspace root can be run from any member, but their groups are
+    /// synced in the member, so each group must be defined there too.
+    pub(crate) fn validate_groups(&self, project: &VirtualProject) -> anyhow::Result<()> {
+        for task in &self.tasks {
+            for group in task.script.groups() {
+                let defined = match project {
+                    VirtualProject::Project(project) => project
+                        .current_project()
+                        .pyproject_toml()
+                        .dependency_groups
+                        .as_ref()
+                        .is_some_and(|groups| groups.contains_key(group)),
+                    VirtualProject::NonProject(workspace) => workspace.groups().contains(group),
+                };
+                if !defined {
+                    bail!(
+                        "Task `{}` requires the dependency group `{group}`, which is not defined in: `{}`",
+                        task.name,
+                        project.root().join("pyproject.toml").user_display()
+                    );
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Warn if the requested task shadows a command of the same name on the given `PATH`.
+    ///
+    /// Tasks that wrap the command they shadow (e.g., `pytest = "pytest -x"`) are expected, and so
+    /// don't trigger a warning.
+    fn warn_shadowed(&self, path: &OsStr) {
+        let Some(task) = self.tasks.last() else {
+            return;
+        };
+        let wraps = match task.script.command() {
+            Some(ScriptCommand::Shell(script)) => script.split_whitespace().next(),
+            Some(ScriptCommand::Args(command)) => command.first().map(String::as_str),
+            None => None,
+        };
+        if wraps == Some(task.name.as_str()) {
+            return;
+        }
+        let Ok(cwd) = std::env::current_dir() else {
+            return;
+        };
+        if let Ok(executable) = which::which_in(&task.name, Some(path), cwd) {
+            warn_user!(
+                "Task `{}` in `tool.uv.scripts` shadows the command at `{}`; the task will be run instead",
+                task.name,
+                executable.user_display()
+            );
+        }
+    }
+
+    /// Run the tasks in the given environment, stopping at the first failure.
+    pub(crate) async fn run(
+        &self,
+        path: &OsStr,
+        interpreter: &Interpreter,
+        printer: Printer,
+    ) -> anyhow::Result<ExitStatus> {
+        // Ignore signals in the parent process, deferring them to the child. This is safe as
+        // long as the tasks are the last thing that runs in this process.
+        let _handler = tokio::spawn(async { while tokio::signal::ctrl_c().await.is_ok() {} });
+
+        self.warn_shadowed(path);
+
+        for (index, task) in self.tasks.iter().enumerate() {
+            let Some(command) = task.script.command() else {
+                continue;
+            };
+
+            // Only the requested task receives the additional arguments.
+            let args = if index == self.tasks.len() - 1 {
+                self.args.as_slice()
+            } else {
+                &[]
+            };
+
+            let mut process = match command {
+                ScriptCommand::Shell(script) => shell_command(script, args),
+                ScriptCommand::Args(command) => {
+                    let Some((executable, rest)) = command.split_first() else {
+                        bail!("Task `{}` has an empty command", task.name);
+                    };
+                    let mut process = Command::new(executable);
+                    process.args(rest);
+                    process.args(args);
+                    process
+                }
+            };
+
+            process.env(EnvVars::PATH, path);
+            if interpreter.is_virtualenv() {
+                process.env(EnvVars::VIRTUAL_ENV, interpreter.sys_prefix().as_os_str());
+            }
+            process.envs(task.script.env());
+            if let Some(cwd) = task.script.cwd() {
+                process.current_dir(self.root.join(cwd));
+            }
+
+            writeln!(
+                printer.stderr(),
+                "{}",
+                format!("Running task `{}`", task.name.cyan()).dimmed()
+            )?;
+
+            debug!("Running task `{}`: {command:?}", task.name);
+            let mut handle = process
+                .spawn()
+                .with_context(|| format!("Failed to spawn task: `{}`", task.name))?;
+            let status = wait_for_child(&mut handle).await?;
+
+            if !status.success() {
+                debug!("Task `{}` failed", task.name);
+                return Ok(exit_status(status));
+            }
+        }
+
+        Ok(ExitStatus::Success)
+    }
+}
+
+/// Visit a task and its dependencies in depth-first order, appending each task to `tasks` after
+/// its dependencies.
+fn visit<'a>(
+    name: &'a str,
+    scripts: &'a BTreeMap<String, ToolUvScript>,
+    stack: &mut Vec<&'a str>,
+    tasks: &mut Vec<Task>,
+) -> anyhow::Result<()> {
+    if tasks.iter().any(|task| task.name == name) {
+        return Ok(());
+    }
+    if let Some(position) = stack.iter().position(|task| *task == name) {
+        let cycle = stack[position..]
+            .iter()
+            .chain(std::iter::once(&name))
+            .map(|task| format!("`{task}`"))
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        bail!("Found a cycle in the dependencies of task `{name}`: {cycle}");
+    }
+
+    let Some((name, script)) = scripts.get_key_value(name) else {
+        bail!(
+            "Task `{}` depends on `{name}`, which is not defined in `tool.uv.scripts`",
+            stack.last().copied().unwrap_or_default()
+        );
+    };
+
+    stack.push(name);
+    for dependency in script.depends_on() {
+        visit(dependency, scripts, stack, tasks)?;
+    }
+    stack.pop();
+
+    tasks.push(Task {
+        name: name.clone(),
+        script: script.clone(),
+    });
+    Ok(())
+}
+
+/// Create a [`Command`] that runs the given script in the system shell, with the given arguments
+/// appended.
+fn shell_command(script: &str, args: &[OsString]) -> Command {
+    let mut script = script.to_string();
+    for arg in args {
+        script.push(' ');
+        script.push_str(&quote(&arg.to_string_lossy()));
+    }
+
+    if cfg!(windows) {
+        let mut process = Command::new("cmd");
+        process.arg("/C");
+        #[cfg(windows)]
+        process.raw_arg(&script);
+        process
+    } else {
+        let mut process = Command::new("sh");
+        process.arg("-c").arg(script);
+        process
+    }
+}
+
+/// Quote an argument for inclusion in a shell command, if necessary.
+fn quote(arg: &str) -> String {
+    let safe = |c: char| c.is_ascii_alphanumeric() || "-_./=:,+@%".contains(c);
+    if !arg.is_empty() && arg.chars().all(safe) {
+        return arg.to_string();
+    }
+    if cfg!(windows) {
+        format!("\"{}\"", arg.replace('"', "\"\""))
+    } else {
+        format!("'{}'", arg.replace('\'', r#"'"'"'"#))
+    }
+}
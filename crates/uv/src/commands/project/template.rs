@@ -0,0 +1,279 @@
+use std::borrow::Cow;
+use std::ops::Bound;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+use url::Url;
+use walkdir::{DirEntry, WalkDir};
+
+use uv_cache::{Cache, CacheBucket};
+use uv_cache_key::{cache_digest, RepositoryUrl};
+use uv_client::BaseClientBuilder;
+use uv_fs::{LockedFile, Simplified, CWD};
+use uv_git::{GitProxyEnv, GitSource};
+use uv_normalize::PackageName;
+use uv_pypi_types::ParsedGitUrl;
+use uv_resolver::RequiresPython;
+
+/// A project template for `uv init`, i.e., a directory of files to copy into a new project.
+#[derive(Debug)]
+pub(crate) struct ProjectTemplate {
+    /// The root directory of the template.
+    root: PathBuf,
+}
+
+impl ProjectTemplate {
+    /// Resolve a template from a local directory or a Git repository.
+    ///
+    /// Git repositories are fetched into the cache; a `#subdirectory=` fragment may be used to
+    /// select a template within the repository.
+    pub(crate) async fn fetch(
+        source: &str,
+        client_builder: &BaseClientBuilder<'_>,
+        cache: &Cache,
+    ) -> Result<Self> {
+        let Some(git) = parse_git_url(source)? else {
+            let root = CWD.join(source);
+            if !root.is_dir() {
+                anyhow::bail!(
+                    "Template directory does not exist: `{}`",
+                    root.user_display().cyan()
+                );
+            }
+            debug!("Using template from: `{}`", root.user_display());
+            return Self::from_root(root);
+        };
+
+        debug!("Fetching template from Git: {}", git.url.repository());
+
+        // Avoid races with other processes fetching the same repository.
+        let git_cache = cache.bucket(CacheBucket::Git);
+        let lock_dir = git_cache.join("locks");
+        fs_err::tokio::create_dir_all(&lock_dir).await?;
+        let repository_url = RepositoryUrl::new(git.url.repository());
+        let _lock = LockedFile::acquire(
+            lock_dir.join(cache_digest(&repository_url)),
+            &repository_url,
+        )
+        .await?;
+
+        let client = client_builder.build();
+        let proxy_env = client
+            .proxies()
+            .map(|proxies| {
+                GitProxyEnv::new(
+                    proxies.http.as_ref(),
+                    proxies.https.as_ref(),
+                    &proxies.no_proxy,
+                )
+            })
+            .unwrap_or_default();
+        let git_source = GitSource::new(
+            git.url.clone(),
+            client.for_host(git.url.repository()).clone(),
+            git_cache,
+        )
+        .with_proxy_env(proxy_env);
+        let fetch = tokio::task::spawn_blocking(move || git_source.fetch())
+            .await?
+            .with_context(|| format!("Failed to fetch template: `{source}`"))?;
+
+        let root = match git.subdirectory {
+            Some(subdirectory) => fetch.path().join(subdirectory),
+            None => fetch.into_path(),
+        };
+        if !root.is_dir() {
+            anyhow::bail!(
+                "Template subdirectory does not exist in `{}`",
+                source.cyan()
+            );
+        }
+
+        Self::from_root(root)
+    }
+
+    /// Create a template from its root directory.
+    ///
+    /// Rejects any symlinks that point outside of the template (e.g., to `~/.ssh/id_rsa`), such
+    /// that rendering the template can't copy arbitrary local files into the project.
+    fn from_root(root: PathBuf) -> Result<Self> {
+        let canonical_root = fs_err::canonicalize(&root)?;
+        for entry in walk(&root) {
+            let entry = entry?;
+            if !entry.path_is_symlink() {
+                continue;
+            }
+            let destination = fs_err::read_link(entry.path())?;
+            let contained = destination.is_relative()
+                && fs_err::canonicalize(entry.path())
+                    .is_ok_and(|resolved| resolved.starts_with(&canonical_root));
+            if !contained {
+                anyhow::bail!(
+                    "Template contains a symlink that points outside of the template: `{}`",
+                    entry.path().strip_prefix(&root)?.portable_display().cyan()
+                );
+            }
+        }
+        Ok(Self { root })
+    }
+
+    /// Copy the template into the given directory, substituting any variables in file paths
+    /// and contents.
+    ///
+    /// Files that already exist in the target directory are left untouched.
+    pub(crate) fn render(&self, path: &Path, variables: &TemplateVariables) -> Result<()> {
+        for entry in walk(&self.root) {
+            let entry = entry?;
+            let relative = entry.path().strip_prefix(&self.root)?;
+            let target = path.join(variables.render_path(relative));
+
+            if entry.file_type().is_dir() {
+                fs_err::create_dir_all(&target)?;
+                continue;
+            }
+
+            if target.try_exists()? {
+                debug!("Skipping existing file: `{}`", target.user_display());
+                continue;
+            }
+            if let Some(parent) = target.parent() {
+                fs_err::create_dir_all(parent)?;
+            }
+
+            // Recreate symlinks, which point within the template, rather than copying their
+            // targets.
+            if entry.path_is_symlink() {
+                let destination = variables.render_path(&fs_err::read_link(entry.path())?);
+                create_symlink(&destination, entry.path(), &target)?;
+                continue;
+            }
+
+            // Only substitute variables in text files; copy anything else verbatim.
+            match String::from_utf8(fs_err::read(entry.path())?) {
+                Ok(contents) => fs_err::write(&target, variables.render(&contents))?,
+                Err(err) => fs_err::write(&target, err.into_bytes())?,
+            }
+
+            // Preserve the permissions of the template file (e.g., for executable scripts).
+            fs_err::set_permissions(&target, fs_err::metadata(entry.path())?.permissions())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Walk the entries of a template, excluding the root itself and any `.git` directory.
+///
+/// Symlinks are yielded as-is, rather than followed.
+fn walk(root: &Path) -> impl Iterator<Item = walkdir::Result<DirEntry>> {
+    WalkDir::new(root)
+        .min_depth(1)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|entry| entry.file_name() != ".git")
+}
+
+/// Create a symlink at `target` pointing to the relative `destination`.
+#[cfg(unix)]
+fn create_symlink(destination: &Path, _source: &Path, target: &Path) -> std::io::Result<()> {
+    fs_err::os::unix::fs::symlink(destination, target)
+}
+
+/// Copy the `source` of a symlink to `target`, since creating symlinks on Windows requires
+/// elevated privileges.
+#[cfg(windows)]
+fn create_symlink(_destination: &Path, source: &Path, target: &Path) -> std::io::Result<()> {
+    if source.is_dir() {
+        uv_fs::copy_dir_all(source, target)
+    } else {
+        fs_err::copy(source, target).map(drop)
+    }
+}
+
+/// The variables that can be substituted into a [`ProjectTemplate`], as `{{ variable }}`.
+#[derive(Debug)]
+pub(crate) struct TemplateVariables<'a> {
+    /// The name of the project (`{{ name }}`).
+    name: &'a PackageName,
+    /// The minimum supported Python version of the project (`{{ python_version }}`).
+    python_version: String,
+}
+
+impl<'a> TemplateVariables<'a> {
+    pub(crate) fn new(name: &'a PackageName, requires_python: &RequiresPython) -> Self {
+        let python_version = match &**requires_python.range().lower() {
+            Bound::Included(version) | Bound::Excluded(version) => version.to_string(),
+            Bound::Unbounded => String::new(),
+        };
+        Self {
+            name,
+            python_version,
+        }
+    }
+
+    /// Returns the value of the given variable, if it exists.
+    fn get(&self, key: &str) -> Option<Cow<'_, str>> {
+        match key {
+            "name" => Some(Cow::Borrowed(self.name.as_str())),
+            "module_name" => Some(self.name.as_dist_info_name()),
+            "python_version" => Some(Cow::Borrowed(self.python_version.as_str())),
+            _ => None,
+        }
+    }
+
+    /// Substitute any `{{ variable }}` placeholders in the given text.
+    ///
+    /// Unknown placeholders (e.g., GitHub Actions expressions like `${{ matrix.os }}`) are left
+    /// as-is.
+    fn render(&self, text: &str) -> String {
+        let mut output = String::with_capacity(text.len());
+        let mut rest = text;
+        while let Some(start) = rest.find("{{") {
+            let Some(end) = rest[start..].find("}}").map(|end| start + end + 2) else {
+                break;
+            };
+            output.push_str(&rest[..start]);
+            match self.get(rest[start + 2..end - 2].trim()) {
+                Some(value) => output.push_str(&value),
+                None => output.push_str(&rest[start..end]),
+            }
+            rest = &rest[end..];
+        }
+        output.push_str(rest);
+        output
+    }
+
+    /// Substitute any `{{ variable }}` placeholders in the components of the given path.
+    fn render_path(&self, path: &Path) -> PathBuf {
+        path.components()
+            .map(|component| {
+                let component = component.as_os_str();
+                match component.to_str() {
+                    Some(component) => PathBuf::from(self.render(component)),
+                    None => PathBuf::from(component),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Parse a template source as a Git URL, returning `None` if it refers to a local directory.
+///
+/// Both bare URLs (e.g., `https://github.com/org/template`) and URLs with a `git+` prefix are
+/// supported, along with a trailing `@<rev>`.
+fn parse_git_url(source: &str) -> Result<Option<ParsedGitUrl>> {
+    let Ok(url) = Url::parse(source) else {
+        return Ok(None);
+    };
+
+    // Windows paths with drive letters (e.g., `C:\template`) parse as single-letter schemes.
+    if url.scheme().len() == 1 {
+        return Ok(None);
+    }
+
+    let git =
+        ParsedGitUrl::try_from(url).with_context(|| format!("Invalid template URL: `{source}`"))?;
+    Ok(Some(git))
+}
@@ -28,7 +28,7 @@ use crate::commands::project::{
 use crate::commands::reporters::LatestVersionReporter;
 use crate::commands::{diagnostics, ExitStatus};
 use crate::printer::Printer;
-use crate::settings::ResolverSettings;
+use crate::settings::{ClientOptions, ResolverSettings};
 
 /// Run a command.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -54,6 +54,7 @@ pub(crate) async fn tree(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
     cache: &Cache,
@@ -79,12 +80,14 @@ pub(crate) async fn tree(
         Some(
             ProjectInterpreter::discover(
                 &workspace,
+                None,
                 project_dir,
                 python.as_deref().map(PythonRequest::parse),
                 python_preference,
                 python_downloads,
                 connectivity,
                 native_tls,
+                client_options,
                 allow_insecure_host,
                 &install_mirrors,
                 no_config,
@@ -119,6 +122,7 @@ pub(crate) async fn tree(
         connectivity,
         concurrency,
         native_tls,
+        client_options,
         allow_insecure_host,
         cache,
         printer,
@@ -188,6 +192,10 @@ pub(crate) async fn tree(
                 cache.clone().with_refresh(Refresh::All(Timestamp::now())),
             )
             .native_tls(native_tls)
+            .cache_stats(client_options.cache_stats.clone())
+            .retry_backoff(client_options.retry_backoff)
+            .concurrency_per_host(client_options.concurrency_per_host)
+            .proxies(client_options.proxies.clone())
             .connectivity(connectivity)
             .keyring(*keyring_provider)
             .allow_insecure_host(allow_insecure_host.to_vec())
@@ -0,0 +1,550 @@
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+use anyhow::{Context, Error, Result};
+use futures::StreamExt;
+use itertools::Itertools;
+use tracing::debug;
+
+use uv_cache::{Cache, Refresh};
+use uv_cache_info::Timestamp;
+use uv_client::{Connectivity, RegistryClientBuilder};
+use uv_configuration::{
+    AddBoundsKind, Concurrency, DevGroupsManifest, EditableMode, ExtrasSpecification,
+    InstallOptions, LowerBound, PreviewMode, TrustedHost,
+};
+use uv_dispatch::SharedState;
+use uv_distribution_types::{IndexCapabilities, IndexUrl};
+use uv_normalize::PackageName;
+use uv_pep440::{Operator, Version, VersionSpecifiers};
+use uv_pep508::VersionOrUrl;
+use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_resolver::RequiresPython;
+use uv_settings::PythonInstallMirrors;
+use uv_warnings::warn_user;
+use uv_workspace::pyproject::{DependencyType, Source, Sources};
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
+use uv_workspace::{DiscoveryOptions, VirtualProject, Workspace};
+
+use crate::commands::pip::latest::LatestClient;
+use crate::commands::pip::list::{Column, MultiZip};
+use crate::commands::pip::loggers::{DefaultInstallLogger, DefaultResolveLogger};
+use crate::commands::pip::operations::Modifications;
+use crate::commands::project::install_target::InstallTarget;
+use crate::commands::project::lock::LockMode;
+use crate::commands::project::{default_dependency_groups, find_requires_python, ProjectError};
+use crate::commands::reporters::LatestVersionReporter;
+use crate::commands::{diagnostics, project, ExitStatus};
+use crate::printer::Printer;
+use crate::settings::{ClientOptions, ResolverInstallerSettings};
+
+/// Upgrade the version specifiers of the project's dependencies to the latest versions.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn upgrade(
+    project_dir: &Path,
+    packages: Vec<PackageName>,
+    bounds: Option<AddBoundsKind>,
+    dry_run: bool,
+    frozen: bool,
+    no_sync: bool,
+    package: Option<PackageName>,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverInstallerSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    client_options: &ClientOptions,
+    allow_insecure_host: &[TrustedHost],
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    // Find the project in the workspace.
+    let project = if let Some(package) = package {
+        VirtualProject::Project(
+            Workspace::discover(project_dir, &DiscoveryOptions::default())
+                .await?
+                .with_current_project(package.clone())
+                .with_context(|| format!("Package `{package}` not found in workspace"))?,
+        )
+    } else {
+        VirtualProject::discover(project_dir, &DiscoveryOptions::default()).await?
+    };
+
+    let existing_pyproject_toml = project.pyproject_toml().raw.clone();
+    let mut toml =
+        PyProjectTomlMut::from_toml(&existing_pyproject_toml, DependencyTarget::PyProjectToml)?;
+
+    // Collect the dependencies to upgrade.
+    let dependencies = toml
+        .dependencies()
+        .into_iter()
+        .filter(|(.., requirement)| packages.is_empty() || packages.contains(&requirement.name))
+        .collect::<Vec<_>>();
+
+    for package in &packages {
+        if !dependencies
+            .iter()
+            .any(|(.., requirement)| requirement.name == *package)
+        {
+            anyhow::bail!("The dependency `{package}` could not be found in the project");
+        }
+    }
+
+    // Determine the sources for the project's dependencies, preferring those declared in the
+    // project itself over those declared in the workspace root.
+    let sources = project
+        .pyproject_toml()
+        .tool
+        .as_ref()
+        .and_then(|tool| tool.uv.as_ref())
+        .and_then(|uv| uv.sources.as_ref())
+        .map(|sources| sources.inner());
+
+    // Filter to dependencies with a version specifier, and determine the index to query for each.
+    let mut candidates = Vec::new();
+    for (dependency_type, index, requirement) in dependencies {
+        let name = &requirement.name;
+
+        // Skip workspace members.
+        if project.workspace().packages().contains_key(name) {
+            debug!("Skipping workspace member: `{name}`");
+            continue;
+        }
+
+        // Skip dependencies without a version specifier.
+        let specifiers = match &requirement.version_or_url {
+            Some(VersionOrUrl::VersionSpecifier(specifiers)) if !specifiers.is_empty() => {
+                specifiers.clone()
+            }
+            Some(VersionOrUrl::Url(_)) => {
+                debug!("Skipping URL dependency: `{name}`");
+                continue;
+            }
+            _ => {
+                debug!("Skipping dependency without a version specifier: `{name}`");
+                continue;
+            }
+        };
+
+        // Skip dependencies with non-registry sources, and determine the index to use for any
+        // that are pinned to a specific index.
+        let source = sources
+            .and_then(|sources| sources.get(name))
+            .or_else(|| project.workspace().sources().get(name));
+        if source.is_some_and(|sources| {
+            sources
+                .iter()
+                .any(|source| !matches!(source, Source::Registry { .. }))
+        }) {
+            debug!("Skipping dependency with a non-registry source: `{name}`");
+            continue;
+        }
+        let index_url = source.and_then(|sources| pinned_index(sources, &settings));
+
+        // Determine the style of the bounds to write.
+        let Some(kind) = bounds.or_else(|| AddBoundsKind::from_specifiers(&specifiers)) else {
+            warn_user!(
+                "Skipping `{name}`, since the version specifier `{specifiers}` can't be upgraded automatically (use `--bounds` to replace it)"
+            );
+            continue;
+        };
+
+        candidates.push(Candidate {
+            dependency_type,
+            index,
+            name: name.clone(),
+            index_url,
+            specifiers,
+            kind,
+        });
+    }
+
+    if candidates.is_empty() {
+        writeln!(printer.stderr(), "No dependencies to upgrade")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Fetch the latest version of each dependency.
+    let latest = {
+        let capabilities = IndexCapabilities::default();
+
+        // Initialize the registry client.
+        let client =
+            RegistryClientBuilder::new(cache.clone().with_refresh(Refresh::All(Timestamp::now())))
+                .native_tls(native_tls)
+                .cache_stats(client_options.cache_stats.clone())
+                .retry_backoff(client_options.retry_backoff)
+                .concurrency_per_host(client_options.concurrency_per_host)
+                .proxies(client_options.proxies.clone())
+                .connectivity(connectivity)
+                .index_urls(settings.index_locations.index_urls())
+                .index_strategy(settings.index_strategy)
+                .keyring(settings.keyring_provider)
+                .allow_insecure_host(allow_insecure_host.to_vec())
+                .build();
+
+        // Only consider versions that are compatible with the project's Python requirement.
+        let requires_python = find_requires_python(project.workspace())
+            .unwrap_or_else(|| RequiresPython::from_specifiers(&VersionSpecifiers::empty()));
+
+        // Initialize the client to fetch the latest version of each package.
+        let client = LatestClient {
+            client: &client,
+            capabilities: &capabilities,
+            prerelease: settings.prerelease,
+            exclude_newer: settings.exclude_newer,
+            tags: None,
+            requires_python: &requires_python,
+        };
+
+        let queries = candidates
+            .iter()
+            .map(|candidate| (&candidate.name, candidate.index_url.as_ref()))
+            .unique()
+            .collect::<Vec<_>>();
+
+        let reporter = LatestVersionReporter::from(printer).with_length(queries.len() as u64);
+
+        let mut fetches = futures::stream::iter(queries)
+            .map(|(name, index)| async move {
+                let filename = client.find_latest(name, index).await?;
+                Ok::<_, Error>((
+                    name,
+                    index,
+                    filename.map(|filename| filename.into_version()),
+                ))
+            })
+            .buffer_unordered(concurrency.downloads);
+
+        let mut latest = BTreeMap::new();
+        while let Some((name, index, version)) = fetches.next().await.transpose()? {
+            match version {
+                Some(version) => {
+                    reporter.on_fetch_version(name, &version);
+                    latest.insert((name.clone(), index.cloned()), version);
+                }
+                None => reporter.on_fetch_progress(),
+            }
+        }
+        reporter.on_fetch_complete();
+        latest
+    };
+
+    // Determine the upgraded version specifiers.
+    let mut upgrades = Vec::new();
+    for candidate in candidates {
+        let Some(version) = latest.get(&(candidate.name.clone(), candidate.index_url.clone()))
+        else {
+            debug!("No versions found for: `{}`", candidate.name);
+            continue;
+        };
+
+        // Avoid downgrading (or re-writing) dependencies that already require the latest version.
+        if !is_newer(version, &candidate.specifiers) {
+            debug!(
+                "Dependency `{}` already requires the latest version ({version})",
+                candidate.name
+            );
+            continue;
+        }
+
+        let specifiers = candidate.kind.specifiers(version.clone());
+        upgrades.push(Upgrade {
+            candidate,
+            version: version.clone(),
+            specifiers,
+        });
+    }
+
+    if upgrades.is_empty() {
+        writeln!(printer.stderr(), "All dependencies are up-to-date")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Display the proposed changes.
+    let columns = [
+        Column {
+            header: String::from("Package"),
+            rows: upgrades
+                .iter()
+                .map(|upgrade| upgrade.candidate.name.to_string())
+                .collect(),
+        },
+        Column {
+            header: String::from("Table"),
+            rows: upgrades
+                .iter()
+                .map(|upgrade| table_name(&upgrade.candidate.dependency_type))
+                .collect(),
+        },
+        Column {
+            header: String::from("Current"),
+            rows: upgrades
+                .iter()
+                .map(|upgrade| upgrade.candidate.specifiers.to_string())
+                .collect(),
+        },
+        Column {
+            header: String::from("Latest"),
+            rows: upgrades
+                .iter()
+                .map(|upgrade| upgrade.version.to_string())
+                .collect(),
+        },
+        Column {
+            header: String::from("Upgraded"),
+            rows: upgrades
+                .iter()
+                .map(|upgrade| upgrade.specifiers.to_string())
+                .collect(),
+        },
+    ];
+    for elems in MultiZip(columns.iter().map(Column::fmt).collect_vec()) {
+        writeln!(printer.stdout(), "{}", elems.join(" ").trim_end())?;
+    }
+
+    if dry_run {
+        let s = if upgrades.len() == 1 { "y" } else { "ies" };
+        writeln!(
+            printer.stderr(),
+            "Would upgrade {} dependenc{s}",
+            upgrades.len()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Discover or create the virtual environment before modifying the `pyproject.toml`, such that
+    // it's left untouched if the environment can't be created. There's no need for an environment
+    // with `--frozen`, since we won't lock or sync.
+    let venv = if frozen {
+        None
+    } else {
+        Some(
+            project::get_or_init_environment(
+                project.workspace(),
+                project.project_name(),
+                python.as_deref().map(PythonRequest::parse),
+                &install_mirrors,
+                python_preference,
+                python_downloads,
+                connectivity,
+                native_tls,
+                client_options,
+                allow_insecure_host,
+                no_config,
+                cache,
+                printer,
+            )
+            .await?,
+        )
+    };
+
+    // Update the version specifiers in the `pyproject.toml`.
+    for upgrade in upgrades {
+        let Upgrade {
+            candidate,
+            specifiers,
+            ..
+        } = upgrade;
+        match &candidate.dependency_type {
+            DependencyType::Production => {
+                toml.set_dependency_bounds(candidate.index, specifiers)?;
+            }
+            DependencyType::Dev => {
+                toml.set_dev_dependency_bounds(candidate.index, specifiers)?;
+            }
+            DependencyType::Optional(extra) => {
+                toml.set_optional_dependency_bounds(extra, candidate.index, specifiers)?;
+            }
+            DependencyType::Group(group) => {
+                toml.set_dependency_group_requirement_bounds(group, candidate.index, specifiers)?;
+            }
+        }
+    }
+
+    let pyproject_path = project.root().join("pyproject.toml");
+    let content = toml.to_string();
+    fs_err::write(&pyproject_path, &content)?;
+
+    // If `--frozen`, exit early. There's no reason to lock and sync.
+    let Some(venv) = venv else {
+        return Ok(ExitStatus::Success);
+    };
+
+    // Update the `pyproject.toml` in-memory, reverting the changes if it can't be parsed.
+    let project = match toml::from_str(&content)
+        .map_err(ProjectError::PyprojectTomlParse)
+        .and_then(|pyproject_toml| {
+            project
+                .with_pyproject_toml(pyproject_toml)
+                .ok_or(ProjectError::PyprojectTomlUpdate)
+        }) {
+        Ok(project) => project,
+        Err(err) => {
+            fs_err::write(&pyproject_path, &existing_pyproject_toml)?;
+            return Err(err.into());
+        }
+    };
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Lock the project, reverting the changes to the `pyproject.toml` if locking fails.
+    let lock = match project::lock::do_safe_lock(
+        LockMode::Write(venv.interpreter()),
+        project.workspace().into(),
+        settings.as_ref().into(),
+        LowerBound::Allow,
+        &state,
+        Box::new(DefaultResolveLogger),
+        connectivity,
+        concurrency,
+        native_tls,
+        client_options,
+        allow_insecure_host,
+        cache,
+        printer,
+        preview,
+    )
+    .await
+    {
+        Ok(result) => result.into_lock(),
+        Err(ProjectError::Operation(err)) => {
+            fs_err::write(&pyproject_path, &existing_pyproject_toml)?;
+            return diagnostics::OperationDiagnostic::default()
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+        }
+        Err(err) => {
+            fs_err::write(&pyproject_path, &existing_pyproject_toml)?;
+            return Err(err.into());
+        }
+    };
+
+    if no_sync {
+        return Ok(ExitStatus::Success);
+    }
+
+    // Perform a full sync, since any of the project's dependencies may have been upgraded.
+    let extras = ExtrasSpecification::All;
+    let install_options = InstallOptions::default();
+
+    // Determine the default groups to include.
+    let defaults = default_dependency_groups(project.pyproject_toml())?;
+
+    // Identify the installation target.
+    let target = match &project {
+        VirtualProject::Project(project) => InstallTarget::Project {
+            workspace: project.workspace(),
+            name: project.project_name(),
+            lock: &lock,
+        },
+        VirtualProject::NonProject(workspace) => InstallTarget::NonProjectWorkspace {
+            workspace,
+            lock: &lock,
+        },
+    };
+
+    match project::sync::do_sync(
+        target,
+        &venv,
+        &extras,
+        &DevGroupsManifest::from_defaults(defaults),
+        EditableMode::Editable,
+        install_options,
+        Modifications::Exact,
+        settings.as_ref().into(),
+        Box::new(DefaultInstallLogger),
+        installer_metadata,
+        connectivity,
+        concurrency,
+        native_tls,
+        client_options,
+        allow_insecure_host,
+        cache,
+        printer,
+        preview,
+    )
+    .await
+    {
+        Ok(()) => {}
+        Err(ProjectError::Operation(err)) => {
+            return diagnostics::OperationDiagnostic::default()
+                .report(err)
+                .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()))
+        }
+        Err(err) => return Err(err.into()),
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// A dependency in the `pyproject.toml` that's eligible for an upgrade.
+#[derive(Debug)]
+struct Candidate {
+    /// The table that contains the dependency.
+    dependency_type: DependencyType,
+    /// The position of the dependency in the table.
+    index: usize,
+    /// The name of the dependency.
+    name: PackageName,
+    /// The index to which the dependency is pinned, if any.
+    index_url: Option<IndexUrl>,
+    /// The existing version specifiers.
+    specifiers: VersionSpecifiers,
+    /// The kind of bounds to write for the upgraded dependency.
+    kind: AddBoundsKind,
+}
+
+/// A proposed upgrade to a dependency.
+#[derive(Debug)]
+struct Upgrade {
+    candidate: Candidate,
+    /// The latest version of the dependency.
+    version: Version,
+    /// The upgraded version specifiers.
+    specifiers: VersionSpecifiers,
+}
+
+/// Returns the URL of the index to which a dependency with the given registry sources is pinned.
+fn pinned_index(sources: &Sources, settings: &ResolverInstallerSettings) -> Option<IndexUrl> {
+    let Source::Registry { index, .. } = sources.iter().next()? else {
+        return None;
+    };
+    settings
+        .index_locations
+        .indexes()
+        .find(|candidate| candidate.name.as_ref() == Some(index))
+        .map(|index| index.url.clone())
+}
+
+/// Returns `true` if the given version is newer than any version required by the specifiers.
+fn is_newer(version: &Version, specifiers: &VersionSpecifiers) -> bool {
+    specifiers
+        .iter()
+        .all(|specifier| match specifier.operator() {
+            Operator::Equal
+            | Operator::ExactEqual
+            | Operator::GreaterThanEqual
+            | Operator::GreaterThan
+            | Operator::TildeEqual => version > specifier.version(),
+            _ => true,
+        })
+}
+
+/// Returns the name of the `pyproject.toml` table for the given dependency type.
+fn table_name(dependency_type: &DependencyType) -> String {
+    match dependency_type {
+        DependencyType::Production => "project.dependencies".to_string(),
+        DependencyType::Dev => "tool.uv.dev-dependencies".to_string(),
+        DependencyType::Optional(extra) => format!("project.optional-dependencies.{extra}"),
+        DependencyType::Group(group) => format!("dependency-groups.{group}"),
+    }
+}
@@ -0,0 +1,380 @@
+use std::fmt::Write;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::{bail, Context, Result};
+use owo_colors::OwoColorize;
+use serde::Serialize;
+
+use uv_cache::Cache;
+use uv_cli::{VersionBump, VersionFormat};
+use uv_client::Connectivity;
+use uv_configuration::{Concurrency, LowerBound, PreviewMode, TrustedHost};
+use uv_dispatch::SharedState;
+use uv_normalize::PackageName;
+use uv_pep440::{Prerelease, PrereleaseKind, Version};
+use uv_python::{PythonDownloads, PythonPreference, PythonRequest};
+use uv_settings::PythonInstallMirrors;
+use uv_workspace::pyproject_mut::{DependencyTarget, PyProjectTomlMut};
+use uv_workspace::{DiscoveryOptions, ProjectWorkspace, Workspace, WorkspaceError};
+
+use crate::commands::pip::loggers::DefaultResolveLogger;
+use crate::commands::project::lock::{do_safe_lock, LockMode};
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::{ProjectError, ProjectInterpreter};
+use crate::commands::{self, diagnostics, ExitStatus};
+use crate::printer::Printer;
+use crate::settings::{ClientOptions, ResolverSettings};
+
+/// Read or update the version of the current project.
+#[allow(clippy::fn_params_excessive_bools)]
+pub(crate) async fn project_version(
+    project_dir: &Path,
+    value: Option<String>,
+    bump: Vec<VersionBump>,
+    dry_run: bool,
+    short: bool,
+    output_format: VersionFormat,
+    frozen: bool,
+    package: Option<PackageName>,
+    python: Option<String>,
+    install_mirrors: PythonInstallMirrors,
+    settings: ResolverSettings,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    connectivity: Connectivity,
+    concurrency: Concurrency,
+    native_tls: bool,
+    client_options: &ClientOptions,
+    allow_insecure_host: &[TrustedHost],
+    no_config: bool,
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    // Find the project in the workspace.
+    let project = if let Some(package) = package {
+        Workspace::discover(project_dir, &DiscoveryOptions::default())
+            .await?
+            .with_current_project(package.clone())
+            .with_context(|| format!("Package `{package}` not found in workspace"))?
+    } else {
+        match ProjectWorkspace::discover(project_dir, &DiscoveryOptions::default()).await {
+            Ok(project) => project,
+            Err(WorkspaceError::MissingPyprojectToml | WorkspaceError::MissingProject(_))
+                if value.is_none() && bump.is_empty() =>
+            {
+                // Outside of a project, display uv's own version.
+                commands::version(output_format, &mut std::io::stdout())?;
+                return Ok(ExitStatus::Success);
+            }
+            Err(err) => return Err(err.into()),
+        }
+    };
+
+    let name = project.project_name().clone();
+    let pyproject_toml = project.current_project().pyproject_toml();
+    let Some(current) = pyproject_toml
+        .project
+        .as_ref()
+        .and_then(|project| project.version.clone())
+    else {
+        let dynamic = pyproject_toml
+            .project
+            .as_ref()
+            .and_then(|project| project.dynamic.as_ref())
+            .is_some_and(|dynamic| dynamic.iter().any(|field| field == "version"));
+        if dynamic {
+            bail!(
+                "The version of `{}` is declared as dynamic in `project.dynamic`, so it can't be read or updated by uv",
+                name.cyan()
+            );
+        }
+        bail!(
+            "The version of `{}` is missing; set it in `project.version`",
+            name.cyan()
+        );
+    };
+
+    // Determine the new version, if any.
+    let version = if let Some(value) = value {
+        Version::from_str(&value).with_context(|| format!("Invalid version: `{value}`"))?
+    } else if !bump.is_empty() {
+        bump_version(&current, &bump)?
+    } else {
+        report(&name, None, &current, short, output_format, printer)?;
+        return Ok(ExitStatus::Success);
+    };
+
+    if dry_run {
+        report(
+            &name,
+            Some(&current),
+            &version,
+            short,
+            output_format,
+            printer,
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    // Update the `pyproject.toml`, preserving its formatting.
+    let existing_pyproject_toml = pyproject_toml.raw.clone();
+    let pyproject_path = project.project_root().join("pyproject.toml");
+    let mut pyproject =
+        PyProjectTomlMut::from_toml(&existing_pyproject_toml, DependencyTarget::PyProjectToml)?;
+    pyproject.set_version(&version)?;
+    let content = pyproject.to_string();
+    fs_err::write(&pyproject_path, &content)?;
+
+    // Update the lockfile, if it exists, such that the locked version of the project matches the
+    // `pyproject.toml`.
+    if !frozen && LockTarget::from(project.workspace()).lock_path().is_file() {
+        let project = project
+            .with_pyproject_toml(
+                toml::from_str(&content).map_err(ProjectError::PyprojectTomlParse)?,
+            )
+            .ok_or(ProjectError::PyprojectTomlUpdate)?;
+
+        let interpreter = ProjectInterpreter::discover(
+            project.workspace(),
+            Some(project.project_name()),
+            project_dir,
+            python.as_deref().map(PythonRequest::parse),
+            python_preference,
+            python_downloads,
+            connectivity,
+            native_tls,
+            client_options,
+            allow_insecure_host,
+            &install_mirrors,
+            no_config,
+            cache,
+            printer,
+        )
+        .await?
+        .into_interpreter();
+
+        // Initialize any shared state.
+        let state = SharedState::default();
+
+        let result = do_safe_lock(
+            LockMode::Write(&interpreter),
+            project.workspace().into(),
+            settings.as_ref(),
+            LowerBound::Allow,
+            &state,
+            Box::new(DefaultResolveLogger),
+            connectivity,
+            concurrency,
+            native_tls,
+            client_options,
+            allow_insecure_host,
+            cache,
+            printer,
+            preview,
+        )
+        .await;
+
+        // If locking fails, revert the changes to the `pyproject.toml`.
+        match result {
+            Ok(_) => {}
+            Err(ProjectError::Operation(err)) => {
+                fs_err::write(&pyproject_path, &existing_pyproject_toml)?;
+                return diagnostics::OperationDiagnostic::default()
+                    .report(err)
+                    .map_or(Ok(ExitStatus::Failure), |err| Err(err.into()));
+            }
+            Err(err) => {
+                fs_err::write(&pyproject_path, &existing_pyproject_toml)?;
+                return Err(err.into());
+            }
+        }
+    }
+
+    report(
+        &name,
+        Some(&current),
+        &version,
+        short,
+        output_format,
+        printer,
+    )?;
+
+    Ok(ExitStatus::Success)
+}
+
+/// The version of a project, as displayed with `--output-format json`.
+#[derive(Serialize)]
+struct ProjectVersion {
+    /// The name of the project.
+    package_name: String,
+    /// The (new) version of the project.
+    version: String,
+    /// The version of the project prior to the update, if it was updated.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous_version: Option<String>,
+}
+
+/// Display the project version, along with the previous version, if it was updated.
+fn report(
+    name: &PackageName,
+    previous: Option<&Version>,
+    version: &Version,
+    short: bool,
+    output_format: VersionFormat,
+    printer: Printer,
+) -> Result<()> {
+    match output_format {
+        VersionFormat::Text => {
+            if short {
+                writeln!(printer.stdout(), "{version}")?;
+            } else if let Some(previous) = previous {
+                writeln!(
+                    printer.stdout(),
+                    "{name} {previous} => {}",
+                    version.to_string().bold()
+                )?;
+            } else {
+                writeln!(printer.stdout(), "{name} {version}")?;
+            }
+        }
+        VersionFormat::Json => {
+            let version = ProjectVersion {
+                package_name: name.to_string(),
+                version: version.to_string(),
+                previous_version: previous.map(ToString::to_string),
+            };
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&version)?
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Apply the given bumps to the current version.
+///
+/// Release segments are bumped first, followed by the pre-release, post-release, and development
+/// segments, regardless of the order in which the bumps were provided. Bumping a segment resets
+/// all less significant segments, and any local version label is dropped.
+fn bump_version(current: &Version, bumps: &[VersionBump]) -> Result<Version> {
+    let mut bumps = bumps.to_vec();
+    bumps.sort_unstable();
+    bumps.dedup();
+
+    let releases = bumps
+        .iter()
+        .filter(|bump| {
+            matches!(
+                bump,
+                VersionBump::Major | VersionBump::Minor | VersionBump::Patch
+            )
+        })
+        .count();
+    if releases > 1 {
+        bail!("Only one of `--bump major`, `--bump minor`, or `--bump patch` can be provided");
+    }
+
+    let prereleases = bumps
+        .iter()
+        .filter(|bump| {
+            matches!(
+                bump,
+                VersionBump::Alpha | VersionBump::Beta | VersionBump::Rc | VersionBump::Pre
+            )
+        })
+        .count();
+    if prereleases > 1 {
+        bail!(
+            "Only one of `--bump alpha`, `--bump beta`, `--bump rc`, or `--bump pre` can be provided"
+        );
+    }
+
+    // Whether any segment other than the development segment is bumped.
+    let bumped = bumps.iter().any(|bump| *bump != VersionBump::Dev);
+
+    let mut version = current.clone().without_local();
+    for bump in bumps {
+        version = match bump {
+            VersionBump::Major => bump_release(&version, 0),
+            VersionBump::Minor => bump_release(&version, 1),
+            VersionBump::Patch => bump_release(&version, 2),
+            VersionBump::Stable => {
+                Version::new(version.release().iter().copied()).with_epoch(version.epoch())
+            }
+            VersionBump::Alpha => bump_prerelease(version, PrereleaseKind::Alpha),
+            VersionBump::Beta => bump_prerelease(version, PrereleaseKind::Beta),
+            VersionBump::Rc => bump_prerelease(version, PrereleaseKind::Rc),
+            VersionBump::Pre => {
+                let Some(pre) = version.pre() else {
+                    bail!(
+                        "`--bump pre` requires a pre-release version, but the current version is `{current}`; use `--bump alpha`, `--bump beta`, or `--bump rc` to start a pre-release"
+                    );
+                };
+                bump_prerelease(version, pre.kind)
+            }
+            VersionBump::Post => {
+                let post = version.post().map_or(1, |post| post + 1);
+                version.with_post(Some(post)).with_dev(None)
+            }
+            VersionBump::Dev => match version.dev() {
+                Some(dev) => version.with_dev(Some(dev + 1)),
+                // A development release sorts before the corresponding release, so start the
+                // development of the next version, unless another bump already did.
+                None if !bumped => next_version(version).with_dev(Some(1)),
+                None => version.with_dev(Some(1)),
+            },
+        };
+    }
+
+    if version <= *current {
+        bail!(
+            "The new version `{version}` is not greater than the current version `{current}`; to set the version anyway, provide it explicitly, e.g., `uv version {version}`"
+        );
+    }
+
+    Ok(version)
+}
+
+/// Increment the release segment at the given index, resetting all subsequent release segments
+/// and dropping any pre-release, post-release, or development segments.
+fn bump_release(version: &Version, index: usize) -> Version {
+    let mut release = version.release().to_vec();
+    if release.len() <= index {
+        release.resize(index + 1, 0);
+    }
+    release[index] += 1;
+    for segment in &mut release[index + 1..] {
+        *segment = 0;
+    }
+    Version::new(release).with_epoch(version.epoch())
+}
+
+/// Increment the least significant segment of a version without a development segment: the
+/// post-release number, the pre-release number, or the patch version, in that order.
+fn next_version(version: Version) -> Version {
+    if let Some(post) = version.post() {
+        version.with_post(Some(post + 1))
+    } else if let Some(pre) = version.pre() {
+        bump_prerelease(version, pre.kind)
+    } else {
+        bump_release(&version, 2)
+    }
+}
+
+/// Increment the pre-release number if the version is already a pre-release of the given kind;
+/// otherwise, start a new pre-release of that kind. Drops any post-release or development
+/// segments.
+fn bump_prerelease(version: Version, kind: PrereleaseKind) -> Version {
+    let number = match version.pre() {
+        Some(pre) if pre.kind == kind => pre.number + 1,
+        _ => 1,
+    };
+    version
+        .with_pre(Some(Prerelease { kind, number }))
+        .with_post(None)
+        .with_dev(None)
+}
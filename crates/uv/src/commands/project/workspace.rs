@@ -0,0 +1,203 @@
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use anyhow::Result;
+use serde::Serialize;
+
+use uv_cli::WorkspaceListFormat;
+use uv_fs::{normalize_path, PortablePath, Simplified};
+use uv_normalize::PackageName;
+use uv_pep508::VerbatimUrl;
+use uv_resolver::Lock;
+use uv_warnings::warn_user;
+use uv_workspace::pyproject::DependencyGroupSpecifier;
+use uv_workspace::{DiscoveryOptions, Workspace, WorkspaceMember};
+
+use crate::commands::project::lock_target::LockTarget;
+use crate::commands::project::ProjectError;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+
+/// List the members of the workspace, optionally limited to those affected by changes to the
+/// given paths.
+pub(crate) async fn list(
+    project_dir: &Path,
+    affected_by: Option<Vec<PathBuf>>,
+    format: WorkspaceListFormat,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+
+    let members = if let Some(paths) = affected_by {
+        // Attribute each path to the innermost member that contains it. Relative paths are
+        // resolved against the project directory.
+        let mut changed = BTreeSet::new();
+        for path in paths {
+            let path = normalize_path(&project_dir.join(path)).into_owned();
+            if let Some((name, _)) = workspace
+                .packages()
+                .iter()
+                .filter(|(_, member)| path.starts_with(member.root()))
+                .max_by_key(|(_, member)| member.root().components().count())
+            {
+                changed.insert(name.clone());
+            }
+        }
+
+        // Include any members that depend on the changed members.
+        let affected = if changed.is_empty() {
+            BTreeSet::new()
+        } else {
+            let lock = LockTarget::from(&workspace)
+                .read()
+                .await?
+                .ok_or(ProjectError::MissingLockfile)?;
+            if !is_current(&workspace, &lock) {
+                warn_user!(
+                    "The lockfile at `{}` is outdated, so the affected members may be incomplete (run `uv lock` to update it)",
+                    workspace.install_path().join("uv.lock").user_display()
+                );
+            }
+            lock.dependents(&changed)
+        };
+
+        workspace
+            .packages()
+            .iter()
+            .filter(|(name, _)| affected.contains(*name))
+            .collect::<Vec<_>>()
+    } else {
+        workspace.packages().iter().collect::<Vec<_>>()
+    };
+
+    match format {
+        WorkspaceListFormat::Text => {
+            for (name, _) in members {
+                writeln!(printer.stdout(), "{name}")?;
+            }
+        }
+        WorkspaceListFormat::Json => {
+            let members = members
+                .into_iter()
+                .map(|(name, member)| Entry::new(&workspace, name, member))
+                .collect::<Vec<_>>();
+            writeln!(
+                printer.stdout(),
+                "{}",
+                serde_json::to_string_pretty(&members)?
+            )?;
+        }
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// Returns `true` if the dependencies between workspace members recorded in the [`Lock`] match
+/// those declared by the members.
+///
+/// Members with dynamic dependencies can't be checked without building them, and so are assumed
+/// to be current.
+fn is_current(workspace: &Workspace, lock: &Lock) -> bool {
+    workspace.packages().iter().all(|(name, member)| {
+        let Ok(Some(package)) = lock.find_by_name(name) else {
+            return false;
+        };
+        let Some(declared) = declared_dependencies(member) else {
+            return true;
+        };
+
+        // Only the dependencies on other members are relevant to `--affected-by`.
+        let is_other_member = |dependency: &PackageName| {
+            dependency != name && workspace.packages().contains_key(dependency)
+        };
+        let declared = declared
+            .into_iter()
+            .filter(|dependency| is_other_member(dependency))
+            .collect::<BTreeSet<_>>();
+        let locked = lock
+            .direct_dependencies(package)
+            .map(|package| package.name().clone())
+            .filter(|dependency| is_other_member(dependency))
+            .collect::<BTreeSet<_>>();
+        declared == locked
+    })
+}
+
+/// Returns the names of the dependencies declared by the given workspace member, including its
+/// optional dependencies and dependency groups.
+///
+/// Returns `None` if the member's dependencies are dynamic.
+fn declared_dependencies(member: &WorkspaceMember) -> Option<BTreeSet<PackageName>> {
+    let pyproject_toml = member.pyproject_toml();
+    let project = pyproject_toml.project.as_ref()?;
+    if project.dynamic.as_ref().is_some_and(|dynamic| {
+        dynamic
+            .iter()
+            .any(|field| field == "dependencies" || field == "optional-dependencies")
+    }) {
+        return None;
+    }
+
+    let groups = pyproject_toml.dependency_groups.as_ref();
+    let requirements = project
+        .dependencies
+        .iter()
+        .flatten()
+        .chain(
+            project
+                .optional_dependencies
+                .iter()
+                .flat_map(BTreeMap::values)
+                .flatten(),
+        )
+        .chain(
+            groups
+                .into_iter()
+                .flat_map(|groups| groups.keys().filter_map(|group| groups.get(group)))
+                .flatten()
+                .filter_map(|specifier| match specifier {
+                    DependencyGroupSpecifier::Requirement(requirement) => Some(requirement),
+                    _ => None,
+                }),
+        );
+
+    let mut names = requirements
+        .filter_map(|requirement| uv_pep508::Requirement::<VerbatimUrl>::from_str(requirement).ok())
+        .map(|requirement| requirement.name)
+        .collect::<BTreeSet<_>>();
+    names.extend(
+        pyproject_toml
+            .tool
+            .as_ref()
+            .and_then(|tool| tool.uv.as_ref())
+            .and_then(|uv| uv.dev_dependencies.as_ref())
+            .into_iter()
+            .flatten()
+            .map(|requirement| requirement.name.clone()),
+    );
+    Some(names)
+}
+
+/// A workspace member, as displayed with `--format json`.
+#[derive(Serialize)]
+struct Entry {
+    /// The name of the member.
+    name: PackageName,
+    /// The path to the member, relative to the workspace root.
+    path: String,
+}
+
+impl Entry {
+    fn new(workspace: &Workspace, name: &PackageName, member: &WorkspaceMember) -> Self {
+        let path = member
+            .root()
+            .strip_prefix(workspace.install_path())
+            .unwrap_or(member.root());
+        Self {
+            name: name.clone(),
+            path: PortablePath::from(path).to_string(),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::commands::reporters::PublishReporter;
 use crate::commands::{human_readable_bytes, ExitStatus};
 use crate::printer::Printer;
+use crate::settings::ClientOptions;
 use anyhow::{bail, Context, Result};
 use console::Term;
 use owo_colors::OwoColorize;
@@ -31,6 +32,7 @@ pub(crate) async fn publish(
     cache: &Cache,
     connectivity: Connectivity,
     native_tls: bool,
+    client_options: &ClientOptions,
     printer: Printer,
 ) -> Result<ExitStatus> {
     if connectivity.is_offline() {
@@ -57,6 +59,10 @@ pub(crate) async fn publish(
         .retries(0)
         .keyring(keyring_provider)
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .allow_insecure_host(allow_insecure_host.to_vec())
         // Don't try cloning the request to make an unauthenticated request first.
         .auth_integration(AuthIntegration::OnlyAuthenticated)
@@ -78,6 +84,10 @@ pub(crate) async fn publish(
         .index_urls();
         let registry_client_builder = RegistryClientBuilder::new(cache.clone())
             .native_tls(native_tls)
+            .cache_stats(client_options.cache_stats.clone())
+            .retry_backoff(client_options.retry_backoff)
+            .concurrency_per_host(client_options.concurrency_per_host)
+            .proxies(client_options.proxies.clone())
             .connectivity(connectivity)
             .index_urls(index_urls)
             .keyring(keyring_provider)
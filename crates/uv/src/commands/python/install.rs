@@ -31,6 +31,7 @@ use crate::commands::python::{ChangeEvent, ChangeEventKind};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::{elapsed, ExitStatus};
 use crate::printer::Printer;
+use crate::settings::ClientOptions;
 
 #[derive(Debug, Clone)]
 struct InstallRequest {
@@ -133,6 +134,7 @@ pub(crate) async fn install(
     default: bool,
     python_downloads: PythonDownloads,
     native_tls: bool,
+    client_options: &ClientOptions,
     connectivity: Connectivity,
     allow_insecure_host: &[TrustedHost],
     no_config: bool,
@@ -251,6 +253,10 @@ pub(crate) async fn install(
     let client = uv_client::BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .allow_insecure_host(allow_insecure_host.to_vec())
         .build();
     let reporter = PythonDownloadReporter::new(printer, downloads.len() as u64);
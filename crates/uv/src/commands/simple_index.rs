@@ -0,0 +1,147 @@
+//! Rendering of static simple repository pages, in both HTML (PEP 503) and JSON (PEP 691) formats.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use uv_normalize::PackageName;
+use uv_pypi_types::HashDigest;
+
+/// The content type of a PEP 691 JSON response.
+pub(crate) const JSON_CONTENT_TYPE: &str = "application/vnd.pypi.simple.v1+json";
+
+/// The content type of a PEP 503 HTML response.
+pub(crate) const HTML_CONTENT_TYPE: &str = "text/html";
+
+/// A file listed on a project page.
+#[derive(Debug)]
+pub(crate) struct IndexFile<'a> {
+    /// The filename of the distribution.
+    pub(crate) filename: &'a str,
+    /// The URL of the distribution, relative to the project page.
+    pub(crate) url: String,
+    /// The hashes of the distribution.
+    pub(crate) hashes: &'a [HashDigest],
+}
+
+/// The `meta` key of a PEP 691 JSON response.
+#[derive(Debug, Serialize)]
+struct Meta {
+    #[serde(rename = "api-version")]
+    api_version: &'static str,
+}
+
+impl Default for Meta {
+    fn default() -> Self {
+        Self { api_version: "1.0" }
+    }
+}
+
+/// A PEP 691 JSON project list.
+#[derive(Debug, Serialize)]
+struct ProjectList<'a> {
+    meta: Meta,
+    projects: Vec<ProjectListEntry<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct ProjectListEntry<'a> {
+    name: &'a PackageName,
+}
+
+/// A PEP 691 JSON project detail page.
+#[derive(Debug, Serialize)]
+struct ProjectDetail<'a> {
+    meta: Meta,
+    name: &'a PackageName,
+    files: Vec<ProjectFile<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct ProjectFile<'a> {
+    filename: &'a str,
+    url: &'a str,
+    hashes: BTreeMap<String, &'a str>,
+}
+
+/// Render the PEP 503 HTML page for a project.
+pub(crate) fn project_html(name: &PackageName, files: &[IndexFile]) -> String {
+    let mut html = String::new();
+    html.push_str(&header(&format!("Links for {name}")));
+    writeln!(html, "    <h1>Links for {name}</h1>").unwrap();
+    for file in files {
+        // Include the strongest hash in the URL fragment.
+        let fragment = file
+            .hashes
+            .iter()
+            .max_by_key(|hash| hash.algorithm)
+            .map(|hash| format!("#{}={}", hash.algorithm, hash.digest))
+            .unwrap_or_default();
+        writeln!(
+            html,
+            "    <a href=\"{}{fragment}\">{}</a><br/>",
+            file.url, file.filename
+        )
+        .unwrap();
+    }
+    html.push_str(FOOTER);
+    html
+}
+
+/// Render the PEP 691 JSON page for a project.
+pub(crate) fn project_json(name: &PackageName, files: &[IndexFile]) -> String {
+    let detail = ProjectDetail {
+        meta: Meta::default(),
+        name,
+        files: files
+            .iter()
+            .map(|file| ProjectFile {
+                filename: file.filename,
+                url: &file.url,
+                hashes: file
+                    .hashes
+                    .iter()
+                    .map(|hash| (hash.algorithm.to_string(), hash.digest.as_ref()))
+                    .collect(),
+            })
+            .collect(),
+    };
+    serde_json::to_string_pretty(&detail).expect("the project page is always serializable") + "\n"
+}
+
+/// Render the PEP 503 HTML root page, listing every project.
+pub(crate) fn root_html(names: &[&PackageName]) -> String {
+    let mut html = String::new();
+    html.push_str(&header("Simple index"));
+    for name in names {
+        writeln!(html, "    <a href=\"{name}/\">{name}</a><br/>").unwrap();
+    }
+    html.push_str(FOOTER);
+    html
+}
+
+/// Render the PEP 691 JSON root page, listing every project.
+pub(crate) fn root_json(names: &[&PackageName]) -> String {
+    let list = ProjectList {
+        meta: Meta::default(),
+        projects: names.iter().map(|name| ProjectListEntry { name }).collect(),
+    };
+    serde_json::to_string_pretty(&list).expect("the project list is always serializable") + "\n"
+}
+
+fn header(title: &str) -> String {
+    format!(
+        "\
+<!DOCTYPE html>
+<html>
+  <head>
+    <meta name=\"pypi:repository-version\" content=\"1.0\">
+    <title>{title}</title>
+  </head>
+  <body>
+"
+    )
+}
+
+const FOOTER: &str = "  </body>\n</html>\n";
@@ -0,0 +1,506 @@
+use std::fmt::Write;
+use std::io;
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::{BaseClientBuilder, Connectivity};
+use uv_configuration::{Concurrency, PreviewMode, TrustedHost};
+use uv_dispatch::SharedState;
+use uv_distribution_types::{Diagnostic, InstalledDist, Name};
+use uv_fs::Simplified;
+use uv_install_wheel::read_record_file;
+use uv_installer::SitePackages;
+use uv_normalize::PackageName;
+use uv_python::{
+    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest,
+};
+use uv_requirements::RequirementsSpecification;
+use uv_settings::{Combine, PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
+use uv_tool::{InstalledTools, Tool};
+
+use crate::commands::pip::loggers::{DefaultInstallLogger, SummaryResolveLogger};
+use crate::commands::project::{resolve_environment, sync_environment};
+use crate::commands::reporters::PythonDownloadReporter;
+use crate::commands::tool::common::{install_executables, remove_entrypoints};
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::{ClientOptions, ResolverInstallerSettings};
+
+/// Check installed tools for problems, and repair any broken tools.
+pub(crate) async fn doctor(
+    names: Vec<PackageName>,
+    check: bool,
+    install_mirrors: PythonInstallMirrors,
+    args: ResolverInstallerOptions,
+    filesystem: ResolverInstallerOptions,
+    connectivity: Connectivity,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    native_tls: bool,
+    client_options: &ClientOptions,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    let installed_tools = InstalledTools::from_settings()?;
+    let _lock = match installed_tools.lock().await {
+        Ok(lock) => lock,
+        Err(uv_tool::Error::Io(err)) if err.kind() == io::ErrorKind::NotFound => {
+            writeln!(printer.stderr(), "No tools installed")?;
+            return Ok(ExitStatus::Success);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    let mut tools = installed_tools.tools()?;
+    tools.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    // Filter to the requested tools, if any.
+    if !names.is_empty() {
+        for name in &names {
+            if !tools.iter().any(|(tool, _)| tool == name) {
+                let install_command = format!("uv tool install {name}");
+                bail!(
+                    "`{}` is not installed; run `{}` to install",
+                    name.cyan(),
+                    install_command.green()
+                );
+            }
+        }
+        tools.retain(|(name, _)| names.contains(name));
+    }
+
+    if tools.is_empty() {
+        writeln!(printer.stderr(), "No tools installed")?;
+        return Ok(ExitStatus::Success);
+    }
+
+    let mut broken = 0usize;
+    let mut failed = 0usize;
+    for (name, receipt) in &tools {
+        // Without a valid receipt, there's nothing to rebuild the tool from.
+        let Ok(receipt) = receipt else {
+            let install_command = format!("uv tool install --force {name}");
+            writeln!(
+                printer.stderr(),
+                "`{}` is missing a valid receipt; run `{}` to reinstall",
+                name.cyan(),
+                install_command.green()
+            )?;
+            broken += 1;
+            failed += 1;
+            continue;
+        };
+
+        let issues = check_tool(name, receipt, &installed_tools, cache)?;
+        if issues.is_empty() {
+            debug!("Tool `{name}` is healthy");
+            continue;
+        }
+
+        broken += 1;
+        writeln!(printer.stderr(), "`{}` is broken:", name.cyan())?;
+        for issue in &issues {
+            writeln!(printer.stderr(), "  - {issue}")?;
+        }
+
+        if check {
+            continue;
+        }
+
+        debug!("Rebuilding tool `{name}` from its receipt");
+        let result = rebuild_tool(
+            name,
+            receipt,
+            &installed_tools,
+            &args,
+            &filesystem,
+            &install_mirrors,
+            connectivity,
+            python_preference,
+            python_downloads,
+            installer_metadata,
+            concurrency,
+            native_tls,
+            client_options,
+            allow_insecure_host,
+            cache,
+            printer,
+            preview,
+        )
+        .await;
+
+        match result {
+            Ok(()) => {
+                writeln!(
+                    printer.stderr(),
+                    "Rebuilt `{}` from its receipt",
+                    name.cyan()
+                )?;
+            }
+            Err(err) => {
+                failed += 1;
+                writeln!(
+                    printer.stderr(),
+                    "{}: Failed to rebuild {}",
+                    "error".red().bold(),
+                    name.green()
+                )?;
+                for err in err.chain() {
+                    writeln!(
+                        printer.stderr(),
+                        "  {}: {}",
+                        "Caused by".red().bold(),
+                        err.to_string().trim()
+                    )?;
+                }
+            }
+        }
+    }
+
+    if broken == 0 {
+        let s = if tools.len() == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "Checked {} tool{s}; no problems found",
+            tools.len()
+        )?;
+        return Ok(ExitStatus::Success);
+    }
+
+    if check {
+        let s = if broken == 1 { "" } else { "s" };
+        writeln!(
+            printer.stderr(),
+            "Found {broken} broken tool{s}; run `{}` to repair",
+            "uv tool doctor".green()
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    if failed > 0 {
+        return Ok(ExitStatus::Failure);
+    }
+
+    Ok(ExitStatus::Success)
+}
+
+/// A problem with an installed tool.
+#[derive(Debug)]
+enum ToolIssue {
+    /// The tool environment doesn't exist.
+    MissingEnvironment,
+    /// The tool environment's Python interpreter doesn't exist.
+    MissingInterpreter,
+    /// The tool environment couldn't be read.
+    InvalidEnvironment(String),
+    /// The tool environment's Python interpreter doesn't satisfy the receipt's request.
+    IncompatibleInterpreter(String, PathBuf),
+    /// The tool package isn't installed in the environment.
+    MissingPackage(PackageName),
+    /// An installed package is missing its `RECORD`.
+    MissingRecord(PackageName),
+    /// A file listed in a package's `RECORD` is missing.
+    MissingFile(PackageName, PathBuf),
+    /// A file listed in a package's `RECORD` doesn't match its recorded size.
+    ModifiedFile(PackageName, PathBuf),
+    /// A diagnostic reported for the environment's installed packages (e.g., a missing
+    /// dependency).
+    Diagnostic(String),
+    /// An executable listed in the receipt is missing.
+    MissingExecutable(String, PathBuf),
+}
+
+impl std::fmt::Display for ToolIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingEnvironment => write!(f, "The tool environment is missing"),
+            Self::MissingInterpreter => {
+                write!(
+                    f,
+                    "The Python interpreter for the tool environment is missing"
+                )
+            }
+            Self::InvalidEnvironment(err) => write!(f, "The tool environment is invalid: {err}"),
+            Self::IncompatibleInterpreter(request, path) => write!(
+                f,
+                "The Python interpreter at `{}` does not satisfy the request `{request}`",
+                path.user_display()
+            ),
+            Self::MissingPackage(name) => {
+                write!(
+                    f,
+                    "The package `{name}` is not installed in the tool environment"
+                )
+            }
+            Self::MissingRecord(name) => write!(f, "The package `{name}` is missing its `RECORD`"),
+            Self::MissingFile(name, path) => write!(
+                f,
+                "The package `{name}` is missing a file: `{}`",
+                path.user_display()
+            ),
+            Self::ModifiedFile(name, path) => write!(
+                f,
+                "The package `{name}` has a modified file: `{}`",
+                path.user_display()
+            ),
+            Self::Diagnostic(message) => write!(f, "{message}"),
+            Self::MissingExecutable(name, path) => write!(
+                f,
+                "The executable `{name}` is missing: `{}`",
+                path.user_display()
+            ),
+        }
+    }
+}
+
+/// Check an installed tool for problems, returning any issues that were found.
+fn check_tool(
+    name: &PackageName,
+    receipt: &Tool,
+    installed_tools: &InstalledTools,
+    cache: &Cache,
+) -> Result<Vec<ToolIssue>> {
+    let mut issues = Vec::new();
+
+    // Verify that the tool's executables exist (and, if they're symlinks, that they aren't
+    // dangling).
+    for entrypoint in receipt.entrypoints() {
+        if !entrypoint.install_path.exists() {
+            issues.push(ToolIssue::MissingExecutable(
+                entrypoint.name.clone(),
+                entrypoint.install_path.clone(),
+            ));
+        }
+    }
+
+    // Verify that the environment exists, along with its interpreter.
+    let environment = match installed_tools.get_environment(name, cache) {
+        Ok(Some(environment)) => environment,
+        Ok(None) => {
+            if installed_tools.tool_dir(name).join("pyvenv.cfg").is_file() {
+                issues.push(ToolIssue::MissingInterpreter);
+            } else {
+                issues.push(ToolIssue::MissingEnvironment);
+            }
+            return Ok(issues);
+        }
+        Err(err) => {
+            issues.push(ToolIssue::InvalidEnvironment(err.to_string()));
+            return Ok(issues);
+        }
+    };
+
+    if let Some(request) = receipt.python() {
+        if !PythonRequest::parse(request).satisfied(environment.interpreter(), cache) {
+            issues.push(ToolIssue::IncompatibleInterpreter(
+                request.clone(),
+                environment.interpreter().sys_executable().to_path_buf(),
+            ));
+        }
+    }
+
+    issues.extend(check_environment(name, &environment)?);
+
+    Ok(issues)
+}
+
+/// Check the packages installed in a tool environment for problems.
+fn check_environment(
+    name: &PackageName,
+    environment: &PythonEnvironment,
+) -> Result<Vec<ToolIssue>> {
+    let mut issues = Vec::new();
+
+    let site_packages = SitePackages::from_environment(environment)?;
+    if site_packages.get_packages(name).is_empty() {
+        issues.push(ToolIssue::MissingPackage(name.clone()));
+    }
+
+    // Verify that every file in each package's `RECORD` is present and unmodified.
+    for dist in site_packages.iter() {
+        issues.extend(check_record(dist)?);
+    }
+
+    // Verify that the installed packages are consistent (e.g., that no dependencies are missing).
+    let markers = environment.interpreter().resolver_marker_environment();
+    for diagnostic in site_packages.diagnostics(&markers)? {
+        issues.push(ToolIssue::Diagnostic(diagnostic.message()));
+    }
+
+    Ok(issues)
+}
+
+/// Verify the files listed in the `RECORD` of an installed distribution.
+fn check_record(dist: &InstalledDist) -> Result<Vec<ToolIssue>> {
+    let mut issues = Vec::new();
+
+    // Only `.dist-info` directories include a `RECORD`.
+    let dist_info = dist.path();
+    if dist_info
+        .extension()
+        .is_none_or(|extension| extension != "dist-info")
+    {
+        return Ok(issues);
+    }
+    let Some(site_packages) = dist_info.parent() else {
+        return Ok(issues);
+    };
+
+    let record = match fs_err::File::open(dist_info.join("RECORD")) {
+        Ok(mut file) => read_record_file(&mut file)?,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            issues.push(ToolIssue::MissingRecord(dist.name().clone()));
+            return Ok(issues);
+        }
+        Err(err) => return Err(err.into()),
+    };
+
+    for entry in record {
+        let path = site_packages.join(&entry.path);
+        match fs_err::metadata(&path) {
+            Ok(metadata) => {
+                if entry.size.is_some_and(|size| size != metadata.len()) {
+                    issues.push(ToolIssue::ModifiedFile(dist.name().clone(), path));
+                }
+            }
+            Err(err) if err.kind() == io::ErrorKind::NotFound => {
+                issues.push(ToolIssue::MissingFile(dist.name().clone(), path));
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+
+    Ok(issues)
+}
+
+/// Rebuild a tool's environment and executables from its receipt.
+async fn rebuild_tool(
+    name: &PackageName,
+    receipt: &Tool,
+    installed_tools: &InstalledTools,
+    args: &ResolverInstallerOptions,
+    filesystem: &ResolverInstallerOptions,
+    install_mirrors: &PythonInstallMirrors,
+    connectivity: Connectivity,
+    python_preference: PythonPreference,
+    python_downloads: PythonDownloads,
+    installer_metadata: bool,
+    concurrency: Concurrency,
+    native_tls: bool,
+    client_options: &ClientOptions,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<()> {
+    // Resolve the appropriate settings, preferring: CLI > receipt > user.
+    let options = args.clone().combine(
+        ResolverInstallerOptions::from(receipt.options().clone()).combine(filesystem.clone()),
+    );
+    let settings = ResolverInstallerSettings::from(options.clone());
+
+    let client_builder = BaseClientBuilder::new()
+        .connectivity(connectivity)
+        .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
+        .allow_insecure_host(allow_insecure_host.to_vec());
+
+    // Find (or download) the interpreter requested at installation time.
+    let reporter = PythonDownloadReporter::single(printer);
+    let python_request = receipt.python().as_deref().map(PythonRequest::parse);
+    let interpreter = PythonInstallation::find_or_download(
+        python_request.as_ref(),
+        EnvironmentPreference::OnlySystem,
+        python_preference,
+        python_downloads,
+        &client_builder,
+        cache,
+        Some(&reporter),
+        install_mirrors.python_install_mirror.as_deref(),
+        install_mirrors.pypy_install_mirror.as_deref(),
+    )
+    .await?
+    .into_interpreter();
+
+    // Resolve the requirements from the receipt.
+    let spec = RequirementsSpecification::from_overrides(
+        receipt.requirements().to_vec(),
+        receipt.constraints().to_vec(),
+        receipt.overrides().to_vec(),
+    );
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    let resolution = resolve_environment(
+        spec.into(),
+        &interpreter,
+        settings.as_ref().into(),
+        &state,
+        Box::new(SummaryResolveLogger),
+        connectivity,
+        concurrency,
+        native_tls,
+        client_options,
+        allow_insecure_host,
+        cache,
+        printer,
+        preview,
+    )
+    .await?;
+
+    // Re-create the environment, removing the broken one.
+    let environment = installed_tools.create_environment(name, interpreter)?;
+
+    let environment = sync_environment(
+        environment,
+        &resolution.into(),
+        settings.as_ref().into(),
+        &state,
+        Box::new(DefaultInstallLogger),
+        installer_metadata,
+        connectivity,
+        concurrency,
+        native_tls,
+        client_options,
+        allow_insecure_host,
+        cache,
+        printer,
+        preview,
+    )
+    .await?;
+
+    // Replace the existing executables, which may be missing or point into the old environment.
+    remove_entrypoints(receipt);
+
+    let status = install_executables(
+        &environment,
+        name,
+        installed_tools,
+        ToolOptions::from(options),
+        true,
+        receipt.python().to_owned(),
+        receipt.requirements().to_vec(),
+        receipt.constraints().to_vec(),
+        receipt.overrides().to_vec(),
+        printer,
+    )?;
+
+    if !matches!(status, ExitStatus::Success) {
+        bail!("No executables are provided by `{name}`");
+    }
+
+    Ok(())
+}
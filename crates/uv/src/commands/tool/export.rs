@@ -0,0 +1,291 @@
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anyhow::{Context, Result};
+use itertools::Itertools;
+use owo_colors::OwoColorize;
+use tracing::debug;
+
+use uv_cache::Cache;
+use uv_client::{Connectivity, FlatIndexClient, RegistryClientBuilder};
+use uv_configuration::{Concurrency, Constraints, LowerBound, PreviewMode, TrustedHost};
+use uv_dispatch::{BuildDispatch, SharedState};
+use uv_distribution::DistributionDatabase;
+use uv_distribution_types::{Dist, Index, Name, Resolution, ResolvedDist, SourceDist};
+use uv_fs::Simplified;
+use uv_installer::{Preparer, SitePackages};
+use uv_normalize::PackageName;
+use uv_pep440::{VersionSpecifier, VersionSpecifiers};
+use uv_pep508::MarkerTree;
+use uv_pypi_types::{Requirement, RequirementSource};
+use uv_requirements::RequirementsSpecification;
+use uv_resolver::FlatIndex;
+use uv_settings::{Combine, ResolverInstallerOptions};
+use uv_tool::InstalledTools;
+use uv_types::{BuildIsolation, HashStrategy};
+use uv_warnings::warn_user;
+
+use crate::commands::pip::loggers::SummaryResolveLogger;
+use crate::commands::project::resolve_environment;
+use crate::commands::reporters::PrepareReporter;
+use crate::commands::wheel_archive::write_wheel;
+use crate::commands::ExitStatus;
+use crate::printer::Printer;
+use crate::settings::{ClientOptions, InstallerSettingsRef, ResolverInstallerSettings};
+
+/// Export the wheels required to reproduce a tool's environment.
+pub(crate) async fn export(
+    name: PackageName,
+    out_dir: PathBuf,
+    connectivity: Connectivity,
+    args: ResolverInstallerOptions,
+    filesystem: ResolverInstallerOptions,
+    concurrency: Concurrency,
+    native_tls: bool,
+    client_options: &ClientOptions,
+    allow_insecure_host: &[TrustedHost],
+    cache: &Cache,
+    printer: Printer,
+    preview: PreviewMode,
+) -> Result<ExitStatus> {
+    let installed_tools = InstalledTools::from_settings()?.init()?;
+    let _lock = installed_tools.lock().await?;
+
+    // Ensure the tool is installed.
+    let receipt = match installed_tools.get_tool_receipt(&name) {
+        Ok(Some(receipt)) => receipt,
+        Ok(None) => {
+            let install_command = format!("uv tool install {name}");
+            return Err(anyhow::anyhow!(
+                "`{}` is not installed; run `{}` to install",
+                name.cyan(),
+                install_command.green()
+            ));
+        }
+        Err(_) => {
+            let install_command = format!("uv tool install --force {name}");
+            return Err(anyhow::anyhow!(
+                "`{}` is missing a valid receipt; run `{}` to reinstall",
+                name.cyan(),
+                install_command.green()
+            ));
+        }
+    };
+
+    let environment = match installed_tools.get_environment(&name, cache) {
+        Ok(Some(environment)) => environment,
+        Ok(None) => {
+            let install_command = format!("uv tool install {name}");
+            return Err(anyhow::anyhow!(
+                "`{}` is not installed; run `{}` to install",
+                name.cyan(),
+                install_command.green()
+            ));
+        }
+        Err(_) => {
+            let install_command = format!("uv tool install --force {name}");
+            return Err(anyhow::anyhow!(
+                "`{}` is missing a valid environment; run `{}` to reinstall",
+                name.cyan(),
+                install_command.green()
+            ));
+        }
+    };
+
+    // Resolve the appropriate settings, preferring: CLI > receipt > user.
+    let options =
+        args.combine(ResolverInstallerOptions::from(receipt.options().clone()).combine(filesystem));
+    let settings = ResolverInstallerSettings::from(options);
+
+    // Pin each package to the version that's currently installed in the tool environment, such
+    // that the exported wheels reproduce the environment rather than upgrading it.
+    let site_packages = SitePackages::from_environment(&environment)?;
+    let pins = site_packages.iter().map(|dist| Requirement {
+        name: dist.name().clone(),
+        extras: vec![],
+        groups: vec![],
+        marker: MarkerTree::default(),
+        source: RequirementSource::Registry {
+            specifier: VersionSpecifiers::from(VersionSpecifier::equals_version(
+                dist.version().clone(),
+            )),
+            index: None,
+            conflict: None,
+        },
+        origin: None,
+    });
+
+    let spec = RequirementsSpecification::from_overrides(
+        receipt.requirements().to_vec(),
+        receipt.constraints().iter().cloned().chain(pins).collect(),
+        receipt.overrides().to_vec(),
+    );
+
+    // Initialize any shared state.
+    let state = SharedState::default();
+
+    // Resolve the requirements against the tool's interpreter.
+    let interpreter = environment.interpreter();
+    let resolution = Resolution::from(
+        resolve_environment(
+            spec.into(),
+            interpreter,
+            settings.as_ref().into(),
+            &state,
+            Box::new(SummaryResolveLogger),
+            connectivity,
+            concurrency,
+            native_tls,
+            client_options,
+            allow_insecure_host,
+            cache,
+            printer,
+            preview,
+        )
+        .await?,
+    );
+
+    let InstallerSettingsRef {
+        index_locations,
+        index_strategy,
+        keyring_provider,
+        dependency_metadata,
+        config_setting,
+        no_build_isolation,
+        no_build_isolation_package,
+        exclude_newer,
+        link_mode,
+        compile_bytecode: _,
+        reinstall: _,
+        build_options,
+        sources,
+    } = settings.as_ref().into();
+
+    let tags = interpreter.tags()?;
+
+    // Add all authenticated sources to the cache.
+    for index in index_locations.allowed_indexes() {
+        if let Some(credentials) = index.credentials() {
+            uv_auth::store_credentials(index.raw_url(), credentials);
+        }
+    }
+
+    // Initialize the registry client.
+    let client = RegistryClientBuilder::new(cache.clone())
+        .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
+        .connectivity(connectivity)
+        .index_urls(index_locations.index_urls())
+        .index_strategy(index_strategy)
+        .keyring(keyring_provider)
+        .allow_insecure_host(allow_insecure_host.to_vec())
+        .markers(interpreter.markers())
+        .platform(interpreter.platform())
+        .build();
+
+    // Determine whether to enable build isolation.
+    let build_isolation = if no_build_isolation {
+        BuildIsolation::Shared(&environment)
+    } else if no_build_isolation_package.is_empty() {
+        BuildIsolation::Isolated
+    } else {
+        BuildIsolation::SharedPackage(&environment, no_build_isolation_package)
+    };
+
+    // Use the default build constraints and hash strategy, as in `sync_environment`.
+    let build_constraints = Constraints::default();
+    let build_hasher = HashStrategy::default();
+    let hasher = HashStrategy::default();
+
+    // Resolve the flat indexes from `--find-links`.
+    let flat_index = {
+        let client = FlatIndexClient::new(&client, cache);
+        let entries = client
+            .fetch(index_locations.flat_indexes().map(Index::url))
+            .await?;
+        FlatIndex::from_entries(entries, Some(tags), &hasher, build_options)
+    };
+
+    // Create a build dispatch.
+    let build_dispatch = BuildDispatch::new(
+        &client,
+        cache,
+        build_constraints,
+        interpreter,
+        index_locations,
+        &flat_index,
+        dependency_metadata,
+        state.clone(),
+        index_strategy,
+        config_setting,
+        build_isolation,
+        link_mode,
+        build_options,
+        &build_hasher,
+        exclude_newer,
+        LowerBound::Allow,
+        sources,
+        concurrency,
+        preview,
+    );
+
+    // Collect the distributions to export. Editable installs refer to a local source tree, so
+    // there's no wheel to export for them.
+    let mut distributions = Vec::new();
+    for dist in resolution.distributions() {
+        let ResolvedDist::Installable { dist, .. } = dist else {
+            continue;
+        };
+        if let Dist::Source(SourceDist::Directory(directory)) = dist {
+            if directory.editable {
+                warn_user!(
+                    "Skipping editable distribution `{}`, which can't be exported as a wheel",
+                    directory.name
+                );
+                continue;
+            }
+        }
+        distributions.push(dist.clone());
+    }
+
+    // Download (or build) a wheel for each distribution.
+    let preparer = Preparer::new(
+        cache,
+        tags,
+        &hasher,
+        build_options,
+        DistributionDatabase::new(&client, &build_dispatch, concurrency.downloads),
+    )
+    .with_reporter(PrepareReporter::from(printer).with_length(distributions.len() as u64));
+
+    let wheels = preparer
+        .prepare(distributions, state.in_flight(), &resolution)
+        .await?;
+
+    // Write the wheels to the output directory. Wheels are stored unzipped in the cache, so each
+    // archive is reconstructed from the extracted files.
+    fs_err::create_dir_all(&out_dir)?;
+    for wheel in wheels
+        .iter()
+        .sorted_unstable_by(|a, b| a.name().cmp(b.name()))
+    {
+        let target = out_dir.join(wheel.filename().to_string());
+        debug!("Writing wheel to: {}", target.user_display());
+        write_wheel(wheel.path(), &target)
+            .with_context(|| format!("Failed to write wheel: {}", target.user_display()))?;
+    }
+
+    let s = if wheels.len() == 1 { "" } else { "s" };
+    writeln!(
+        printer.stderr(),
+        "Exported {} wheel{s} for `{}` to: {}",
+        wheels.len(),
+        name.cyan(),
+        out_dir.user_display().cyan()
+    )?;
+
+    Ok(ExitStatus::Success)
+}
@@ -8,15 +8,17 @@ use tracing::{debug, trace};
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
 use uv_client::{BaseClientBuilder, Connectivity};
-use uv_configuration::{Concurrency, PreviewMode, Reinstall, TrustedHost, Upgrade};
+use uv_configuration::{AddBoundsKind, Concurrency, PreviewMode, Reinstall, TrustedHost, Upgrade};
 use uv_dispatch::SharedState;
 use uv_distribution_types::{NameRequirementSpecification, UnresolvedRequirementSpecification};
+use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
 use uv_pep508::MarkerTree;
 use uv_pypi_types::{Requirement, RequirementSource};
 use uv_python::{
-    EnvironmentPreference, PythonDownloads, PythonInstallation, PythonPreference, PythonRequest,
+    EnvironmentPreference, PythonDownloads, PythonEnvironment, PythonInstallation,
+    PythonPreference, PythonRequest,
 };
 use uv_requirements::{RequirementsSource, RequirementsSpecification};
 use uv_settings::{PythonInstallMirrors, ResolverInstallerOptions, ToolOptions};
@@ -36,7 +38,7 @@ use crate::commands::{
     diagnostics, reporters::PythonDownloadReporter, tool::common::install_executables,
 };
 use crate::printer::Printer;
-use crate::settings::ResolverInstallerSettings;
+use crate::settings::{ClientOptions, ResolverInstallerSettings};
 
 /// Install a tool.
 #[allow(clippy::fn_params_excessive_bools)]
@@ -50,6 +52,7 @@ pub(crate) async fn install(
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     force: bool,
+    bounds: Option<AddBoundsKind>,
     options: ResolverInstallerOptions,
     settings: ResolverInstallerSettings,
     python_preference: PythonPreference,
@@ -58,6 +61,7 @@ pub(crate) async fn install(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     cache: Cache,
     printer: Printer,
@@ -66,6 +70,10 @@ pub(crate) async fn install(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .allow_insecure_host(allow_insecure_host.to_vec());
 
     let reporter = PythonDownloadReporter::single(printer);
@@ -94,6 +102,10 @@ pub(crate) async fn install(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .allow_insecure_host(allow_insecure_host.to_vec());
 
     // Parse the input requirement.
@@ -126,6 +138,7 @@ pub(crate) async fn install(
                 connectivity,
                 concurrency,
                 native_tls,
+                client_options,
                 allow_insecure_host,
                 &cache,
                 printer,
@@ -201,6 +214,7 @@ pub(crate) async fn install(
                 connectivity,
                 concurrency,
                 native_tls,
+                client_options,
                 allow_insecure_host,
                 &cache,
                 printer,
@@ -266,6 +280,7 @@ pub(crate) async fn install(
                 connectivity,
                 concurrency,
                 native_tls,
+                client_options,
                 allow_insecure_host,
                 &cache,
                 printer,
@@ -292,6 +307,7 @@ pub(crate) async fn install(
         connectivity,
         concurrency,
         native_tls,
+        client_options,
         allow_insecure_host,
         &cache,
         printer,
@@ -424,6 +440,7 @@ pub(crate) async fn install(
             connectivity,
             concurrency,
             native_tls,
+            client_options,
             allow_insecure_host,
             &cache,
             printer,
@@ -459,6 +476,7 @@ pub(crate) async fn install(
             connectivity,
             concurrency,
             native_tls,
+            client_options,
             allow_insecure_host,
             &cache,
             printer,
@@ -494,6 +512,7 @@ pub(crate) async fn install(
             connectivity,
             concurrency,
             native_tls,
+            client_options,
             allow_insecure_host,
             &cache,
             printer,
@@ -515,6 +534,14 @@ pub(crate) async fn install(
         }
     };
 
+    // If requested, record bounds for any requirements without a version specifier, based on the
+    // installed versions.
+    let requirements = if let Some(bounds) = bounds {
+        apply_bounds(requirements, bounds, &environment)?
+    } else {
+        requirements
+    };
+
     install_executables(
         &environment,
         &from.name,
@@ -528,3 +555,26 @@ pub(crate) async fn install(
         printer,
     )
 }
+
+/// Add version bounds to any registry requirements without a version specifier, based on the
+/// versions installed in the environment.
+fn apply_bounds(
+    requirements: Vec<Requirement>,
+    bounds: AddBoundsKind,
+    environment: &PythonEnvironment,
+) -> Result<Vec<Requirement>> {
+    let site_packages = SitePackages::from_environment(environment)?;
+    Ok(requirements
+        .into_iter()
+        .map(|mut requirement| {
+            if let RequirementSource::Registry { specifier, .. } = &mut requirement.source {
+                if specifier.is_empty() {
+                    if let Some(installed) = site_packages.get_packages(&requirement.name).first() {
+                        *specifier = bounds.specifiers(installed.version().clone());
+                    }
+                }
+            }
+            requirement
+        })
+        .collect())
+}
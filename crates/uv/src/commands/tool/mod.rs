@@ -7,6 +7,8 @@ use uv_pep440::Version;
 
 mod common;
 pub(crate) mod dir;
+pub(crate) mod doctor;
+pub(crate) mod export;
 pub(crate) mod install;
 pub(crate) mod list;
 pub(crate) mod run;
@@ -1,6 +1,6 @@
 use std::fmt::Display;
 use std::fmt::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 
 use anstream::eprint;
@@ -16,7 +16,10 @@ use uv_cli::ExternalCommand;
 use uv_client::{BaseClientBuilder, Connectivity};
 use uv_configuration::{Concurrency, PreviewMode, TrustedHost};
 use uv_dispatch::SharedState;
-use uv_distribution_types::{Name, UnresolvedRequirementSpecification};
+use uv_distribution_types::{
+    Name, NameRequirementSpecification, UnresolvedRequirementSpecification,
+};
+use uv_fs::Simplified;
 use uv_installer::{SatisfiesResult, SitePackages};
 use uv_normalize::PackageName;
 use uv_pep440::{VersionSpecifier, VersionSpecifiers};
@@ -31,10 +34,12 @@ use uv_settings::PythonInstallMirrors;
 use uv_static::EnvVars;
 use uv_tool::{entrypoint_paths, InstalledTools};
 use uv_warnings::warn_user;
+use uv_workspace::{DiscoveryOptions, Workspace};
 
 use crate::commands::pip::loggers::{
     DefaultInstallLogger, DefaultResolveLogger, SummaryInstallLogger, SummaryResolveLogger,
 };
+use crate::commands::project::lock_target::LockTarget;
 use crate::commands::project::{resolve_names, EnvironmentSpecification, ProjectError};
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::tool::Target;
@@ -43,7 +48,7 @@ use crate::commands::{
     diagnostics, project::environment::CachedEnvironment, tool::common::matching_packages,
 };
 use crate::printer::Printer;
-use crate::settings::ResolverInstallerSettings;
+use crate::settings::{ClientOptions, ResolverInstallerSettings};
 
 /// The user-facing command used to invoke a tool run.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -70,17 +75,21 @@ pub(crate) async fn run(
     from: Option<String>,
     with: &[RequirementsSource],
     show_resolution: bool,
+    show_env: bool,
     python: Option<String>,
     install_mirrors: PythonInstallMirrors,
     settings: ResolverInstallerSettings,
     invocation_source: ToolRunCommand,
     isolated: bool,
+    from_project: bool,
+    project_dir: &Path,
     python_preference: PythonPreference,
     python_downloads: PythonDownloads,
     installer_metadata: bool,
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     cache: Cache,
     printer: Printer,
@@ -104,6 +113,13 @@ pub(crate) async fn run(
 
     let target = Target::parse(target, from.as_deref());
 
+    // If requested, pin the tool and its dependencies to the versions in the project's lockfile.
+    let (target, constraints) = if from_project {
+        locked_target(target, project_dir).await?
+    } else {
+        (target, Vec::new())
+    };
+
     // If the user passed, e.g., `ruff@latest`, refresh the cache.
     let cache = if target.is_latest() {
         cache.with_refresh(Refresh::All(Timestamp::now()))
@@ -115,17 +131,20 @@ pub(crate) async fn run(
     let result = get_or_create_environment(
         &target,
         with,
+        constraints,
         show_resolution,
+        show_env,
         python.as_deref(),
         install_mirrors,
         &settings,
-        isolated,
+        isolated || from_project,
         python_preference,
         python_downloads,
         installer_metadata,
         connectivity,
         concurrency,
         native_tls,
+        client_options,
         allow_insecure_host,
         &cache,
         printer,
@@ -312,6 +331,104 @@ fn get_entrypoints(
     )?)
 }
 
+/// Pin the [`Target`] to the version of its package in the lockfile of the project at
+/// `project_dir`.
+///
+/// Returns the pinned [`Target`], along with constraints that pin each of the package's
+/// dependencies to their locked versions.
+async fn locked_target<'a>(
+    target: Target<'a>,
+    project_dir: &Path,
+) -> anyhow::Result<(Target<'a>, Vec<Requirement>)> {
+    let (command, name) = match target {
+        // Ex) `ruff`
+        Target::Unspecified(name) => (None, name),
+        // Ex) `ruff --from ruff`
+        Target::From(command, from) if PackageName::from_str(from).is_ok() => (Some(command), from),
+        Target::From(..) => {
+            bail!("`--from-project` requires `--from` to be a package name, not a requirement")
+        }
+        Target::Version(..)
+        | Target::FromVersion(..)
+        | Target::Latest(..)
+        | Target::FromLatest(..) => {
+            bail!("`--from-project` cannot be combined with a version request")
+        }
+    };
+    let package_name = PackageName::from_str(name)?;
+
+    let workspace = Workspace::discover(project_dir, &DiscoveryOptions::default()).await?;
+    let Some(lock) = LockTarget::from(&workspace).read().await? else {
+        return Err(ProjectError::MissingLockfile.into());
+    };
+    let Some(package) = lock
+        .find_by_name(&package_name)
+        .map_err(|err| anyhow::anyhow!("Failed to find `{package_name}` in `uv.lock`: {err}"))?
+    else {
+        bail!(
+            "`{}` is not included in the lockfile at: {}",
+            package_name.cyan(),
+            workspace
+                .install_path()
+                .join("uv.lock")
+                .user_display()
+                .cyan()
+        );
+    };
+
+    // Pin every package in the tool's dependency tree to its locked version. Only registry
+    // packages can be pinned by version, so reject any other sources.
+    let mut constraints = Vec::new();
+    for dependency in lock.transitive_dependencies(package) {
+        if dependency.index(workspace.install_path())?.is_none() {
+            bail!(
+                "`--from-project` requires `{}` and its dependencies to be locked to a registry, but `{}` is locked to a non-registry source",
+                package_name.cyan(),
+                dependency.name().cyan()
+            );
+        }
+
+        // If the package is locked at multiple versions, pin each version within its forks.
+        let marker = if dependency.fork_markers().is_empty() {
+            MarkerTree::TRUE
+        } else {
+            let mut marker = MarkerTree::FALSE;
+            for fork in dependency.fork_markers() {
+                marker.or(fork.pep508());
+            }
+            marker
+        };
+
+        constraints.push(Requirement {
+            name: dependency.name().clone(),
+            extras: vec![],
+            groups: vec![],
+            marker,
+            source: RequirementSource::Registry {
+                specifier: VersionSpecifiers::from(VersionSpecifier::equals_version(
+                    dependency.version().clone(),
+                )),
+                index: None,
+                conflict: None,
+            },
+            origin: None,
+        });
+    }
+
+    debug!(
+        "Using `{}` v{} from the project lockfile",
+        package.name(),
+        package.version()
+    );
+
+    let version = package.version().clone();
+    let target = match command {
+        Some(command) => Target::FromVersion(command, name, version),
+        None => Target::Version(name, version),
+    };
+    Ok((target, constraints))
+}
+
 /// Display a list of tools that provide the executable.
 ///
 /// If there is no package providing the executable, we will display a message to how to install a package.
@@ -434,7 +551,9 @@ fn warn_executable_not_provided_by_package(
 async fn get_or_create_environment(
     target: &Target<'_>,
     with: &[RequirementsSource],
+    constraints: Vec<Requirement>,
     show_resolution: bool,
+    show_env: bool,
     python: Option<&str>,
     install_mirrors: PythonInstallMirrors,
     settings: &ResolverInstallerSettings,
@@ -445,6 +564,7 @@ async fn get_or_create_environment(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
@@ -453,6 +573,10 @@ async fn get_or_create_environment(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .allow_insecure_host(allow_insecure_host.to_vec());
 
     let reporter = PythonDownloadReporter::single(printer);
@@ -529,6 +653,7 @@ async fn get_or_create_environment(
             connectivity,
             concurrency,
             native_tls,
+            client_options,
             allow_insecure_host,
             cache,
             printer,
@@ -544,6 +669,10 @@ async fn get_or_create_environment(
         let client_builder = BaseClientBuilder::new()
             .connectivity(connectivity)
             .native_tls(native_tls)
+            .cache_stats(client_options.cache_stats.clone())
+            .retry_backoff(client_options.retry_backoff)
+            .concurrency_per_host(client_options.concurrency_per_host)
+            .proxies(client_options.proxies.clone())
             .allow_insecure_host(allow_insecure_host.to_vec());
         RequirementsSpecification::from_simple_sources(with, &client_builder).await?
     };
@@ -561,6 +690,7 @@ async fn get_or_create_environment(
                 connectivity,
                 concurrency,
                 native_tls,
+                client_options,
                 allow_insecure_host,
                 cache,
                 printer,
@@ -604,6 +734,13 @@ async fn get_or_create_environment(
                 Ok(SatisfiesResult::Fresh { .. })
             ) {
                 debug!("Using existing tool `{}`", from.name);
+                if show_env {
+                    writeln!(
+                        printer.stderr(),
+                        "Using installed tool environment at: {}",
+                        environment.root().user_display().cyan()
+                    )?;
+                }
                 return Ok((from, environment));
             }
         }
@@ -615,6 +752,15 @@ async fn get_or_create_environment(
             .into_iter()
             .map(UnresolvedRequirementSpecification::from)
             .collect(),
+        constraints: spec
+            .constraints
+            .into_iter()
+            .chain(
+                constraints
+                    .into_iter()
+                    .map(NameRequirementSpecification::from),
+            )
+            .collect(),
         ..spec
     };
 
@@ -640,6 +786,7 @@ async fn get_or_create_environment(
         connectivity,
         concurrency,
         native_tls,
+        client_options,
         allow_insecure_host,
         cache,
         printer,
@@ -647,5 +794,14 @@ async fn get_or_create_environment(
     )
     .await?;
 
+    if show_env {
+        writeln!(
+            printer.stderr(),
+            "Using cached environment at: {} ({})",
+            environment.root().user_display().cyan(),
+            environment.reuse()
+        )?;
+    }
+
     Ok((from, environment.into()))
 }
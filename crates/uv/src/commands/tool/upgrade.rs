@@ -30,7 +30,7 @@ use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::tool::common::remove_entrypoints;
 use crate::commands::{conjunction, tool::common::install_executables, ExitStatus};
 use crate::printer::Printer;
-use crate::settings::ResolverInstallerSettings;
+use crate::settings::{ClientOptions, ResolverInstallerSettings};
 
 /// Upgrade a tool.
 pub(crate) async fn upgrade(
@@ -45,6 +45,7 @@ pub(crate) async fn upgrade(
     installer_metadata: bool,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     cache: &Cache,
     printer: Printer,
@@ -83,6 +84,10 @@ pub(crate) async fn upgrade(
     let client_builder = BaseClientBuilder::new()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .allow_insecure_host(allow_insecure_host.to_vec());
 
     let python_request = python.as_deref().map(PythonRequest::parse);
@@ -129,6 +134,7 @@ pub(crate) async fn upgrade(
             connectivity,
             concurrency,
             native_tls,
+            client_options,
             allow_insecure_host,
             preview,
         )
@@ -222,6 +228,7 @@ async fn upgrade_tool(
     connectivity: Connectivity,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     allow_insecure_host: &[TrustedHost],
     preview: PreviewMode,
 ) -> Result<UpgradeOutcome> {
@@ -303,6 +310,7 @@ async fn upgrade_tool(
             connectivity,
             concurrency,
             native_tls,
+            client_options,
             allow_insecure_host,
             cache,
             printer,
@@ -322,6 +330,7 @@ async fn upgrade_tool(
             connectivity,
             concurrency,
             native_tls,
+            client_options,
             allow_insecure_host,
             cache,
             printer,
@@ -348,6 +357,7 @@ async fn upgrade_tool(
             connectivity,
             concurrency,
             native_tls,
+            client_options,
             allow_insecure_host,
             cache,
             printer,
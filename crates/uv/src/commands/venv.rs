@@ -36,6 +36,7 @@ use crate::commands::project::{validate_project_requires_python, WorkspacePython
 use crate::commands::reporters::PythonDownloadReporter;
 use crate::commands::ExitStatus;
 use crate::printer::Printer;
+use crate::settings::ClientOptions;
 
 /// Create a virtual environment.
 #[allow(clippy::unnecessary_wraps, clippy::fn_params_excessive_bools)]
@@ -60,6 +61,7 @@ pub(crate) async fn venv(
     exclude_newer: Option<ExcludeNewer>,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     no_config: bool,
     no_project: bool,
     cache: &Cache,
@@ -88,6 +90,7 @@ pub(crate) async fn venv(
         exclude_newer,
         concurrency,
         native_tls,
+        client_options,
         no_config,
         no_project,
         cache,
@@ -147,6 +150,7 @@ async fn venv_impl(
     exclude_newer: Option<ExcludeNewer>,
     concurrency: Concurrency,
     native_tls: bool,
+    client_options: &ClientOptions,
     no_config: bool,
     no_project: bool,
     cache: &Cache,
@@ -178,7 +182,7 @@ async fn venv_impl(
                 // This isn't strictly necessary and we may want to change it later, but this
                 // avoids a breaking change when adding project environment support to `uv venv`.
                 (project.workspace().install_path() == project_dir)
-                    .then(|| project.workspace().venv())
+                    .then(|| project.workspace().venv(project.project_name()))
             })
             .unwrap_or(PathBuf::from(".venv")),
     );
@@ -186,6 +190,10 @@ async fn venv_impl(
     let client_builder = BaseClientBuilder::default()
         .connectivity(connectivity)
         .native_tls(native_tls)
+        .cache_stats(client_options.cache_stats.clone())
+        .retry_backoff(client_options.retry_backoff)
+        .concurrency_per_host(client_options.concurrency_per_host)
+        .proxies(client_options.proxies.clone())
         .allow_insecure_host(allow_insecure_host.to_vec());
 
     let reporter = PythonDownloadReporter::single(printer);
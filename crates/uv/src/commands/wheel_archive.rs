@@ -0,0 +1,64 @@
+//! Re-packing of unzipped wheels from the cache into wheel archives.
+
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use anyhow::Result;
+use walkdir::WalkDir;
+use zip::write::FileOptions;
+use zip::{CompressionMethod, ZipWriter};
+
+/// Write the unzipped wheel at `source` to a wheel archive at `target`.
+///
+/// The `.dist-info` directory is written last, with the `RECORD` as its final entry, as
+/// recommended by the binary distribution format. Entries are otherwise sorted by name, such that
+/// packing the same wheel twice yields the same archive.
+pub(crate) fn write_wheel(source: &Path, target: &Path) -> Result<()> {
+    let mut files = Vec::new();
+    for entry in WalkDir::new(source).sort_by_file_name() {
+        let entry = entry?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry.path().strip_prefix(source)?;
+        let name = relative
+            .components()
+            .map(|component| component.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+
+        // Preserve the executable bit, e.g., for scripts.
+        #[cfg(unix)]
+        let permissions = {
+            use std::os::unix::fs::PermissionsExt;
+            if entry.metadata()?.permissions().mode() & 0o111 != 0 {
+                0o755
+            } else {
+                0o644
+            }
+        };
+        #[cfg(not(unix))]
+        let permissions = 0o644;
+
+        files.push((name, entry.into_path(), permissions));
+    }
+
+    // Move the `.dist-info` directory (and its `RECORD`) to the end of the archive.
+    files.sort_by_key(|(name, ..)| {
+        let (top_level, file) = name.split_once('/').unwrap_or((name, ""));
+        let dist_info = top_level.ends_with(".dist-info");
+        (dist_info, dist_info && file == "RECORD")
+    });
+
+    let mut writer = ZipWriter::new(BufWriter::new(fs_err::File::create(target)?));
+    for (name, path, permissions) in files {
+        let options = FileOptions::default()
+            .compression_method(CompressionMethod::Deflated)
+            .unix_permissions(permissions);
+        writer.start_file(name, options)?;
+        std::io::copy(&mut fs_err::File::open(path)?, &mut writer)?;
+    }
+    writer.finish()?.flush()?;
+
+    Ok(())
+}
@@ -10,6 +10,7 @@ use anstream::eprintln;
 use anyhow::{bail, Context, Result};
 use clap::error::{ContextKind, ContextValue};
 use clap::{CommandFactory, Parser};
+use clap_complete::env::CompleteEnv;
 use owo_colors::OwoColorize;
 use settings::PipTreeSettings;
 use tokio::task::spawn_blocking;
@@ -17,13 +18,17 @@ use tracing::{debug, instrument};
 use uv_cache::{Cache, Refresh};
 use uv_cache_info::Timestamp;
 use uv_cli::{
-    compat::CompatArgs, BuildBackendCommand, CacheCommand, CacheNamespace, Cli, Commands,
-    PipCommand, PipNamespace, ProjectCommand,
+    compat::CompatArgs, AuthCommand, AuthNamespace, BuildBackendCommand, CacheCommand,
+    CacheNamespace, Cli, Commands, PipCommand, PipNamespace, ProjectCommand,
+};
+use uv_cli::{
+    PythonCommand, PythonNamespace, ToolCommand, ToolNamespace, WorkspaceCommand,
+    WorkspaceNamespace,
 };
-use uv_cli::{PythonCommand, PythonNamespace, ToolCommand, ToolNamespace, TopLevelArgs};
 #[cfg(feature = "self-update")]
 use uv_cli::{SelfCommand, SelfNamespace, SelfUpdateArgs};
-use uv_fs::CWD;
+use uv_client::CacheStatsRecorder;
+use uv_fs::{Simplified, CWD};
 use uv_requirements::RequirementsSource;
 use uv_scripts::{Pep723Item, Pep723Metadata, Pep723Script};
 use uv_settings::{Combine, FilesystemOptions, Options};
@@ -34,9 +39,9 @@ use uv_workspace::{DiscoveryOptions, Workspace};
 use crate::commands::{ExitStatus, RunCommand, ToolRunCommand};
 use crate::printer::Printer;
 use crate::settings::{
-    CacheSettings, GlobalSettings, PipCheckSettings, PipCompileSettings, PipFreezeSettings,
-    PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings, PipUninstallSettings,
-    PublishSettings,
+    CacheSettings, ClientOptions, GlobalSettings, PipCheckSettings, PipCompileSettings,
+    PipFreezeSettings, PipInstallSettings, PipListSettings, PipShowSettings, PipSyncSettings,
+    PipUninstallSettings, PublishSettings,
 };
 
 pub(crate) mod commands;
@@ -51,6 +56,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         uv_warnings::enable();
     }
 
+    // Aggregate the HTTP cache statistics across every client.
+    let cache_stats = CacheStatsRecorder::default();
+
     // Switch directories as early as possible.
     if let Some(directory) = cli.top_level.global_args.directory.as_ref() {
         std::env::set_current_dir(directory)?;
@@ -153,6 +161,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                     *gui_script,
                     settings.connectivity,
                     settings.native_tls,
+                    &ClientOptions::new(&settings, cache_stats.clone()),
                     &settings.allow_insecure_host,
                 )
                 .await?,
@@ -200,6 +209,13 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         .and_then(|tool| tool.uv.as_ref())
         .map(|uv| Options::simple(uv.globals.clone(), uv.top_level.clone()))
         .map(FilesystemOptions::from)
+        .map(|options| {
+            let source = script
+                .as_ref()
+                .and_then(Pep723Item::path)
+                .map_or_else(|| "-".to_string(), |path| path.user_display().to_string());
+            options.without_credential_helpers(source)
+        })
         .combine(filesystem);
 
     // Resolve the global settings.
@@ -261,6 +277,9 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     // Don't initialize the rayon threadpool yet, this is too costly when we're doing a noop sync.
     uv_configuration::RAYON_PARALLELISM.store(globals.concurrency.installs, Ordering::SeqCst);
 
+    // Configure the options shared by every HTTP client, including the proxies.
+    let client_options = ClientOptions::new(&globals, cache_stats);
+
     debug!("uv {}", uv_cli::version::version());
 
     // Write out any resolved settings.
@@ -281,7 +300,10 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
     show_settings!(cache_settings, false);
 
     // Configure the cache.
-    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?;
+    let cache = Cache::from_settings(cache_settings.no_cache, cache_settings.cache_dir)?
+        .with_environment_retention(cache_settings.environment_retention);
+
+    let report_cache_stats = globals.report_cache_stats;
 
     let result = match *cli.command {
         Commands::Help(args) => commands::help(
@@ -375,6 +397,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.python_preference,
                 globals.concurrency,
                 globals.native_tls,
+                &client_options,
                 globals.quiet,
                 cache,
                 printer,
@@ -446,6 +469,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.python_preference,
                 globals.concurrency,
                 globals.native_tls,
+                &client_options,
                 &globals.allow_insecure_host,
                 cache,
                 args.dry_run,
@@ -537,6 +561,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.python_preference,
                 globals.concurrency,
                 globals.native_tls,
+                &client_options,
                 &globals.allow_insecure_host,
                 cache,
                 args.dry_run,
@@ -575,6 +600,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 cache,
                 globals.connectivity,
                 globals.native_tls,
+                &client_options,
                 args.settings.keyring_provider,
                 &globals.allow_insecure_host,
                 args.dry_run,
@@ -630,6 +656,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python.as_deref(),
                 args.settings.system,
                 globals.native_tls,
+                &client_options,
                 &cache,
                 printer,
             )
@@ -684,6 +711,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.python.as_deref(),
                 args.settings.system,
                 globals.native_tls,
+                &client_options,
                 &cache,
                 printer,
             )
@@ -725,6 +753,12 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
             commands::cache_dir(&cache);
             Ok(ExitStatus::Success)
         }
+        Commands::Cache(CacheNamespace {
+            command: CacheCommand::Serve(args),
+        }) => {
+            show_settings!(args);
+            commands::cache_serve(args.bind, &cache, printer).await
+        }
         Commands::Build(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::BuildSettings::resolve(args, filesystem);
@@ -765,6 +799,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.connectivity,
                 globals.concurrency,
                 globals.native_tls,
+                &client_options,
                 &globals.allow_insecure_host,
                 &cache,
                 printer,
@@ -820,6 +855,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.settings.exclude_newer,
                 globals.concurrency,
                 globals.native_tls,
+                &client_options,
                 cli.top_level.no_config,
                 args.no_project,
                 &cache,
@@ -836,6 +872,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 run_command,
                 script,
                 globals,
+                &client_options,
                 cli.top_level.no_config,
                 filesystem,
                 cache,
@@ -858,9 +895,42 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 is not available. Please use your package manager to update uv."
             );
         }
-        Commands::Version { output_format } => {
-            commands::version(output_format, &mut stdout())?;
-            Ok(ExitStatus::Success)
+        Commands::Version(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::VersionSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            commands::project_version(
+                &project_dir,
+                args.value,
+                args.bump,
+                args.dry_run,
+                args.short,
+                args.output_format,
+                args.frozen,
+                args.package,
+                args.python,
+                args.install_mirrors,
+                args.settings,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.connectivity,
+                globals.concurrency,
+                globals.native_tls,
+                &client_options,
+                &globals.allow_insecure_host,
+                cli.top_level.no_config,
+                &cache,
+                printer,
+                globals.preview,
+            )
+            .await
         }
         Commands::GenerateShellCompletion(args) => {
             args.shell.generate(&mut Cli::command(), &mut stdout());
@@ -878,25 +948,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
 
             if let Some(shell) = args.generate_shell_completion {
                 // uvx: combine `uv tool uvx` with the top-level arguments
-                let mut uvx = Cli::command()
-                    .find_subcommand("tool")
-                    .unwrap()
-                    .find_subcommand("uvx")
-                    .unwrap()
-                    .clone()
-                    // Avoid duplicating the `--help` and `--version` flags from the top-level arguments.
-                    .disable_help_flag(true)
-                    .disable_version_flag(true)
-                    .version(env!("CARGO_PKG_VERSION"));
-
-                // Copy the top-level arguments into the `uvx` command. (Like `Args::augment_args`, but
-                // expanded to skip collisions.)
-                for arg in TopLevelArgs::command().get_arguments() {
-                    if arg.get_id() != "isolated" {
-                        uvx = uvx.arg(arg);
-                    }
-                }
-                shell.generate(&mut uvx, &mut stdout());
+                shell.generate(&mut Cli::uvx_command(), &mut stdout());
                 return Ok(ExitStatus::Success);
             }
 
@@ -932,17 +984,21 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.from,
                 &requirements,
                 args.show_resolution || globals.verbose > 0,
+                args.show_env,
                 args.python,
                 args.install_mirrors,
                 args.settings,
                 invocation_source,
                 args.isolated,
+                args.from_project,
+                &project_dir,
                 globals.python_preference,
                 globals.python_downloads,
                 globals.installer_metadata,
                 globals.connectivity,
                 globals.concurrency,
                 globals.native_tls,
+                &client_options,
                 &globals.allow_insecure_host,
                 cache,
                 printer,
@@ -1000,6 +1056,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.python,
                 args.install_mirrors,
                 args.force,
+                args.bounds,
                 args.options,
                 args.settings,
                 globals.python_preference,
@@ -1008,6 +1065,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.connectivity,
                 globals.concurrency,
                 globals.native_tls,
+                &client_options,
                 &globals.allow_insecure_host,
                 cache,
                 printer,
@@ -1055,6 +1113,63 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 globals.installer_metadata,
                 globals.concurrency,
                 globals.native_tls,
+                &client_options,
+                &globals.allow_insecure_host,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Doctor(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolDoctorSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            Box::pin(commands::tool_doctor(
+                args.names,
+                args.check,
+                args.install_mirrors,
+                args.args,
+                args.filesystem,
+                globals.connectivity,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.installer_metadata,
+                globals.concurrency,
+                globals.native_tls,
+                &client_options,
+                &globals.allow_insecure_host,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        Commands::Tool(ToolNamespace {
+            command: ToolCommand::Export(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::ToolExportSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(args.refresh);
+
+            Box::pin(commands::tool_export(
+                args.name,
+                args.out_dir,
+                globals.connectivity,
+                args.args,
+                args.filesystem,
+                globals.concurrency,
+                globals.native_tls,
+                &client_options,
                 &globals.allow_insecure_host,
                 &cache,
                 printer,
@@ -1128,6 +1243,7 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 args.default,
                 globals.python_downloads,
                 globals.native_tls,
+                &client_options,
                 globals.connectivity,
                 &globals.allow_insecure_host,
                 cli.top_level.no_config,
@@ -1273,10 +1389,66 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
                 &cache,
                 globals.connectivity,
                 globals.native_tls,
+                &client_options,
                 printer,
             )
             .await
         }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Login(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuthLoginSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::auth_login(
+                &args.service,
+                args.username,
+                args.password,
+                args.store,
+                &args.indexes,
+                printer,
+            )
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Logout(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuthLogoutSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::auth_logout(
+                &args.service,
+                args.username.as_deref(),
+                args.store,
+                &args.indexes,
+                printer,
+            )
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::Token(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuthTokenSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::auth_token(
+                &args.service,
+                args.username.as_deref(),
+                args.store,
+                &args.indexes,
+                printer,
+            )
+        }
+        Commands::Auth(AuthNamespace {
+            command: AuthCommand::List(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::AuthListSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::auth_list(args.store, printer)
+        }
         Commands::BuildBackend { command } => spawn_blocking(move || match command {
             BuildBackendCommand::BuildSdist { sdist_directory } => {
                 commands::build_backend::build_sdist(&sdist_directory)
@@ -1314,6 +1486,14 @@ async fn run(mut cli: Cli) -> Result<ExitStatus> {
         .await
         .expect("tokio threadpool exited unexpectedly"),
     };
+
+    // Report the HTTP cache statistics, without masking the result of the command.
+    if let Err(err) =
+        commands::cache_stats(&client_options.cache_stats, report_cache_stats, printer)
+    {
+        warn_user!("Failed to report HTTP cache statistics: {err}");
+    }
+
     result
 }
 
@@ -1324,6 +1504,7 @@ async fn run_project(
     command: Option<RunCommand>,
     script: Option<Pep723Item>,
     globals: GlobalSettings,
+    client_options: &ClientOptions,
     // TODO(zanieb): Determine a better story for passing `no_config` in here
     no_config: bool,
     filesystem: Option<FilesystemOptions>,
@@ -1363,6 +1544,7 @@ async fn run_project(
                 args.description,
                 args.vcs,
                 args.build_backend,
+                args.template,
                 args.no_readme,
                 args.author_from,
                 args.no_pin_python,
@@ -1373,6 +1555,7 @@ async fn run_project(
                 globals.python_downloads,
                 globals.connectivity,
                 globals.native_tls,
+                client_options,
                 &globals.allow_insecure_host,
                 no_config,
                 &cache,
@@ -1420,6 +1603,8 @@ async fn run_project(
                 args.no_sync,
                 args.isolated,
                 args.all_packages,
+                args.jobs,
+                args.keep_going,
                 args.package,
                 args.no_project,
                 no_config,
@@ -1436,6 +1621,7 @@ async fn run_project(
                 globals.connectivity,
                 globals.concurrency,
                 globals.native_tls,
+                client_options,
                 &globals.allow_insecure_host,
                 &cache,
                 printer,
@@ -1461,6 +1647,7 @@ async fn run_project(
                 project_dir,
                 args.locked,
                 args.frozen,
+                args.check,
                 args.all_packages,
                 args.package,
                 args.extras,
@@ -1477,6 +1664,7 @@ async fn run_project(
                 globals.connectivity,
                 globals.concurrency,
                 globals.native_tls,
+                client_options,
                 &globals.allow_insecure_host,
                 no_config,
                 &cache,
@@ -1509,6 +1697,7 @@ async fn run_project(
                 globals.connectivity,
                 globals.concurrency,
                 globals.native_tls,
+                client_options,
                 &globals.allow_insecure_host,
                 no_config,
                 &cache,
@@ -1549,6 +1738,7 @@ async fn run_project(
                 args.editable,
                 args.dependency_type,
                 args.raw_sources,
+                args.bounds,
                 args.indexes,
                 args.rev,
                 args.tag,
@@ -1565,6 +1755,7 @@ async fn run_project(
                 globals.connectivity,
                 globals.concurrency,
                 globals.native_tls,
+                client_options,
                 &globals.allow_insecure_host,
                 no_config,
                 &cache,
@@ -1598,6 +1789,8 @@ async fn run_project(
                 args.frozen,
                 args.no_sync,
                 args.packages,
+                args.unused,
+                args.dry_run,
                 args.dependency_type,
                 args.package,
                 args.python,
@@ -1610,6 +1803,45 @@ async fn run_project(
                 globals.connectivity,
                 globals.concurrency,
                 globals.native_tls,
+                client_options,
+                &globals.allow_insecure_host,
+                no_config,
+                &cache,
+                printer,
+                globals.preview,
+            ))
+            .await
+        }
+        ProjectCommand::Upgrade(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::UpgradeSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?.with_refresh(
+                args.refresh
+                    .combine(Refresh::from(args.settings.reinstall.clone()))
+                    .combine(Refresh::from(args.settings.upgrade.clone())),
+            );
+
+            Box::pin(commands::upgrade(
+                project_dir,
+                args.packages,
+                args.bounds,
+                args.dry_run,
+                args.frozen,
+                args.no_sync,
+                args.package,
+                args.python,
+                args.install_mirrors,
+                args.settings,
+                globals.python_preference,
+                globals.python_downloads,
+                globals.installer_metadata,
+                globals.connectivity,
+                globals.concurrency,
+                globals.native_tls,
+                client_options,
                 &globals.allow_insecure_host,
                 no_config,
                 &cache,
@@ -1618,6 +1850,15 @@ async fn run_project(
             ))
             .await
         }
+        ProjectCommand::Workspace(WorkspaceNamespace {
+            command: WorkspaceCommand::List(args),
+        }) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::WorkspaceListSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            commands::workspace_list(project_dir, args.affected_by, args.format, printer).await
+        }
         ProjectCommand::Tree(args) => {
             // Resolve the settings from the command-line arguments and workspace configuration.
             let args = settings::TreeSettings::resolve(args, filesystem);
@@ -1648,6 +1889,7 @@ async fn run_project(
                 globals.connectivity,
                 globals.concurrency,
                 globals.native_tls,
+                client_options,
                 &globals.allow_insecure_host,
                 no_config,
                 &cache,
@@ -1687,6 +1929,7 @@ async fn run_project(
                 globals.connectivity,
                 globals.concurrency,
                 globals.native_tls,
+                client_options,
                 &globals.allow_insecure_host,
                 no_config,
                 globals.quiet,
@@ -1696,6 +1939,29 @@ async fn run_project(
             )
             .await
         }
+        ProjectCommand::Mirror(args) => {
+            // Resolve the settings from the command-line arguments and workspace configuration.
+            let args = settings::MirrorSettings::resolve(args, filesystem);
+            show_settings!(args);
+
+            // Initialize the cache.
+            let cache = cache.init()?;
+
+            commands::mirror(
+                project_dir,
+                &args.out_dir,
+                &args.index_locations,
+                args.keyring_provider,
+                globals.connectivity,
+                globals.concurrency,
+                globals.native_tls,
+                client_options,
+                &globals.allow_insecure_host,
+                &cache,
+                printer,
+            )
+            .await
+        }
     }
 }
 
@@ -1711,6 +1977,16 @@ where
     I: IntoIterator<Item = T>,
     T: Into<OsString> + Clone,
 {
+    // Respond to dynamic shell completion requests (e.g., `COMPLETE=bash uv`). If a request is
+    // present, the completions are written to stdout and the process exits.
+    if std::env::var_os(EnvVars::UV_INTERNAL__UVX_COMPLETE).is_some() {
+        CompleteEnv::with_factory(Cli::uvx_command)
+            .completer("uvx")
+            .complete();
+    } else {
+        CompleteEnv::with_factory(Cli::command).complete();
+    }
+
     // `std::env::args` is not `Send` so we parse before passing to our runtime
     // https://github.com/rust-lang/rust/pull/48005
     let cli = match Cli::try_parse_from(args) {
@@ -3,14 +3,17 @@ use std::num::NonZeroUsize;
 use std::path::PathBuf;
 use std::process;
 use std::str::FromStr;
+use std::time::Duration;
 
 use url::Url;
-use uv_cache::{CacheArgs, Refresh};
+use uv_cache::{CacheArgs, EnvironmentRetention, Refresh};
 use uv_cli::comma::CommaSeparatedRequirements;
 use uv_cli::{
     options::{flag, resolver_installer_options, resolver_options},
-    AuthorFrom, BuildArgs, ExportArgs, PublishArgs, PythonDirArgs, ResolverInstallerArgs,
-    ToolUpgradeArgs,
+    AuthListArgs, AuthLoginArgs, AuthLogoutArgs, AuthStore, AuthTokenArgs, AuthorFrom, BuildArgs,
+    ExportArgs, MirrorArgs, PublishArgs, PythonDirArgs, ResolverInstallerArgs, ToolDoctorArgs,
+    ToolExportArgs, ToolUpgradeArgs, UpgradeArgs, VersionArgs, VersionBump, VersionFormat,
+    WorkspaceListArgs, WorkspaceListFormat,
 };
 use uv_cli::{
     AddArgs, ColorChoice, ExternalCommand, GlobalArgs, InitArgs, ListFormat, LockArgs, Maybe,
@@ -19,12 +22,12 @@ use uv_cli::{
     PythonPinArgs, PythonUninstallArgs, RemoveArgs, RunArgs, SyncArgs, ToolDirArgs,
     ToolInstallArgs, ToolListArgs, ToolRunArgs, ToolUninstallArgs, TreeArgs, VenvArgs,
 };
-use uv_client::Connectivity;
+use uv_client::{CacheStatsRecorder, Connectivity, ProxySettings, RetryBackoff};
 use uv_configuration::{
-    BuildOptions, Concurrency, ConfigSettings, DevGroupsSpecification, EditableMode, ExportFormat,
-    ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions, KeyringProviderType,
-    NoBinary, NoBuild, PreviewMode, ProjectBuildBackend, Reinstall, SourceStrategy, TargetTriple,
-    TrustedHost, TrustedPublishing, Upgrade, VersionControlSystem,
+    AddBoundsKind, BuildOptions, Concurrency, ConfigSettings, DevGroupsSpecification, EditableMode,
+    ExportFormat, ExtrasSpecification, HashCheckingMode, IndexStrategy, InstallOptions,
+    KeyringProviderType, NoBinary, NoBuild, PreviewMode, ProjectBuildBackend, Reinstall,
+    SourceStrategy, TargetTriple, TrustedHost, TrustedPublishing, Upgrade, VersionControlSystem,
 };
 use uv_distribution_types::{DependencyMetadata, Index, IndexLocations, IndexUrl};
 use uv_install_wheel::linker::LinkMode;
@@ -58,13 +61,16 @@ pub(crate) struct GlobalSettings {
     pub(crate) color: ColorChoice,
     pub(crate) native_tls: bool,
     pub(crate) concurrency: Concurrency,
+    pub(crate) retry_backoff: RetryBackoff,
     pub(crate) connectivity: Connectivity,
     pub(crate) allow_insecure_host: Vec<TrustedHost>,
+    pub(crate) proxies: ProxySettings,
     pub(crate) show_settings: bool,
     pub(crate) preview: PreviewMode,
     pub(crate) python_preference: PythonPreference,
     pub(crate) python_downloads: PythonDownloads,
     pub(crate) no_progress: bool,
+    pub(crate) report_cache_stats: bool,
     pub(crate) installer_metadata: bool,
 }
 
@@ -111,6 +117,20 @@ impl GlobalSettings {
                     .combine(workspace.and_then(|workspace| workspace.globals.concurrent_installs))
                     .map(NonZeroUsize::get)
                     .unwrap_or_else(Concurrency::threads),
+                downloads_per_host: env(env::CONCURRENT_DOWNLOADS_PER_HOST)
+                    .combine(
+                        workspace
+                            .and_then(|workspace| workspace.globals.concurrent_downloads_per_host),
+                    )
+                    .map(NonZeroUsize::get),
+            },
+            retry_backoff: RetryBackoff {
+                base: env(env::RETRY_BACKOFF_BASE)
+                    .combine(workspace.and_then(|workspace| workspace.globals.retry_backoff_base))
+                    .map_or(RetryBackoff::DEFAULT_BASE, Duration::from_secs),
+                max: env(env::RETRY_BACKOFF_MAX)
+                    .combine(workspace.and_then(|workspace| workspace.globals.retry_backoff_max))
+                    .map_or(RetryBackoff::DEFAULT_MAX, Duration::from_secs),
             },
             connectivity: if flag(args.offline, args.no_offline)
                 .combine(workspace.and_then(|workspace| workspace.globals.offline))
@@ -137,6 +157,13 @@ impl GlobalSettings {
                         .flatten(),
                 )
                 .collect(),
+            proxies: ProxySettings {
+                http: workspace.and_then(|workspace| workspace.globals.http_proxy.clone()),
+                https: workspace.and_then(|workspace| workspace.globals.https_proxy.clone()),
+                no_proxy: workspace
+                    .and_then(|workspace| workspace.globals.no_proxy.clone())
+                    .unwrap_or_default(),
+            },
             show_settings: args.show_settings,
             preview: PreviewMode::from(
                 flag(args.preview, args.no_preview)
@@ -153,17 +180,44 @@ impl GlobalSettings {
                 .combine(workspace.and_then(|workspace| workspace.globals.python_downloads))
                 .unwrap_or_default(),
             no_progress: args.no_progress,
+            report_cache_stats: args.report_cache_stats,
             installer_metadata: !args.no_installer_metadata,
         }
     }
 }
 
+/// The options shared by the HTTP clients created during an invocation of the CLI.
+#[derive(Debug, Clone)]
+pub(crate) struct ClientOptions {
+    /// The recorder for the decisions made by the HTTP cache, across all clients.
+    pub(crate) cache_stats: CacheStatsRecorder,
+    /// The backoff between retries of a failed request.
+    pub(crate) retry_backoff: RetryBackoff,
+    /// The maximum number of concurrent requests to a single host, if any.
+    pub(crate) concurrency_per_host: Option<usize>,
+    /// The resolved proxies, if any are configured.
+    pub(crate) proxies: Option<ProxySettings>,
+}
+
+impl ClientOptions {
+    /// Create the [`ClientOptions`] from the resolved global settings.
+    pub(crate) fn new(globals: &GlobalSettings, cache_stats: CacheStatsRecorder) -> Self {
+        Self {
+            cache_stats,
+            retry_backoff: globals.retry_backoff,
+            concurrency_per_host: globals.concurrency.downloads_per_host,
+            proxies: (!globals.proxies.is_empty()).then(|| globals.proxies.clone().resolve()),
+        }
+    }
+}
+
 /// The resolved cache settings to use for any invocation of the CLI.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub(crate) struct CacheSettings {
     pub(crate) no_cache: bool,
     pub(crate) cache_dir: Option<PathBuf>,
+    pub(crate) environment_retention: EnvironmentRetention,
 }
 
 impl CacheSettings {
@@ -177,6 +231,23 @@ impl CacheSettings {
             cache_dir: args
                 .cache_dir
                 .or_else(|| workspace.and_then(|workspace| workspace.globals.cache_dir.clone())),
+            environment_retention: {
+                let default = EnvironmentRetention::default();
+                EnvironmentRetention {
+                    max_entries: env(env::CACHE_MAX_ENVIRONMENTS)
+                        .combine(
+                            workspace
+                                .and_then(|workspace| workspace.globals.cache_max_environments),
+                        )
+                        .unwrap_or(default.max_entries),
+                    max_age: env(env::CACHE_ENVIRONMENT_MAX_AGE)
+                        .combine(
+                            workspace
+                                .and_then(|workspace| workspace.globals.cache_environment_max_age),
+                        )
+                        .map_or(default.max_age, Duration::from_secs),
+                }
+            },
         }
     }
 }
@@ -192,6 +263,7 @@ pub(crate) struct InitSettings {
     pub(crate) description: Option<String>,
     pub(crate) vcs: Option<VersionControlSystem>,
     pub(crate) build_backend: Option<ProjectBuildBackend>,
+    pub(crate) template: Option<String>,
     pub(crate) no_readme: bool,
     pub(crate) author_from: Option<AuthorFrom>,
     pub(crate) no_pin_python: bool,
@@ -216,6 +288,7 @@ impl InitSettings {
             description,
             vcs,
             build_backend,
+            template,
             no_readme,
             author_from,
             no_pin_python,
@@ -246,6 +319,7 @@ impl InitSettings {
             description,
             vcs,
             build_backend,
+            template,
             no_readme,
             author_from,
             no_pin_python,
@@ -272,6 +346,8 @@ pub(crate) struct RunSettings {
     pub(crate) isolated: bool,
     pub(crate) show_resolution: bool,
     pub(crate) all_packages: bool,
+    pub(crate) jobs: usize,
+    pub(crate) keep_going: bool,
     pub(crate) package: Option<PackageName>,
     pub(crate) no_project: bool,
     pub(crate) no_sync: bool,
@@ -317,6 +393,8 @@ impl RunSettings {
             build,
             refresh,
             all_packages,
+            jobs,
+            keep_going,
             package,
             no_project,
             python,
@@ -362,6 +440,8 @@ impl RunSettings {
             isolated,
             show_resolution,
             all_packages,
+            jobs: jobs.map_or(1, NonZeroUsize::get),
+            keep_going,
             package,
             no_project,
             no_sync,
@@ -388,7 +468,9 @@ pub(crate) struct ToolRunSettings {
     pub(crate) with_editable: Vec<String>,
     pub(crate) with_requirements: Vec<PathBuf>,
     pub(crate) isolated: bool,
+    pub(crate) from_project: bool,
     pub(crate) show_resolution: bool,
+    pub(crate) show_env: bool,
     pub(crate) python: Option<String>,
     pub(crate) install_mirrors: PythonInstallMirrors,
     pub(crate) refresh: Refresh,
@@ -410,7 +492,9 @@ impl ToolRunSettings {
             with_editable,
             with_requirements,
             isolated,
+            from_project,
             show_resolution,
+            show_env,
             installer,
             build,
             refresh,
@@ -457,7 +541,9 @@ impl ToolRunSettings {
                 .filter_map(Maybe::into_option)
                 .collect(),
             isolated,
+            from_project,
             show_resolution,
+            show_env,
             python: python.and_then(Maybe::into_option),
             refresh: Refresh::from(refresh),
             settings: ResolverInstallerSettings::combine(
@@ -486,6 +572,7 @@ pub(crate) struct ToolInstallSettings {
     pub(crate) settings: ResolverInstallerSettings,
     pub(crate) force: bool,
     pub(crate) editable: bool,
+    pub(crate) bounds: Option<AddBoundsKind>,
     pub(crate) install_mirrors: PythonInstallMirrors,
 }
 
@@ -504,6 +591,7 @@ impl ToolInstallSettings {
             overrides,
             installer,
             force,
+            bounds,
             build,
             refresh,
             python,
@@ -517,6 +605,8 @@ impl ToolInstallSettings {
                 .unwrap_or_default(),
         );
 
+        let bounds = bounds.or(filesystem.as_ref().and_then(|fs| fs.bounds));
+
         let install_mirrors = filesystem
             .map(FilesystemOptions::into_options)
             .map(|options| options.install_mirrors)
@@ -550,6 +640,7 @@ impl ToolInstallSettings {
             python: python.and_then(Maybe::into_option),
             force,
             editable,
+            bounds,
             refresh: Refresh::from(refresh),
             options,
             settings,
@@ -653,6 +744,87 @@ impl ToolUpgradeSettings {
     }
 }
 
+/// The resolved settings to use for a `tool doctor` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct ToolDoctorSettings {
+    pub(crate) names: Vec<PackageName>,
+    pub(crate) check: bool,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) args: ResolverInstallerOptions,
+    pub(crate) filesystem: ResolverInstallerOptions,
+}
+
+impl ToolDoctorSettings {
+    /// Resolve the [`ToolDoctorSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolDoctorArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolDoctorArgs {
+            name,
+            check,
+            installer,
+            build,
+        } = args;
+
+        let args = resolver_installer_options(installer, build);
+        let filesystem = filesystem.map(FilesystemOptions::into_options);
+        let install_mirrors = filesystem
+            .clone()
+            .map(|options| options.install_mirrors)
+            .unwrap_or_default();
+        let top_level = filesystem
+            .map(|options| options.top_level)
+            .unwrap_or_default();
+
+        Self {
+            names: name,
+            check,
+            install_mirrors,
+            args,
+            filesystem: top_level,
+        }
+    }
+}
+
+/// The resolved settings to use for a `tool export` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct ToolExportSettings {
+    pub(crate) name: PackageName,
+    pub(crate) out_dir: PathBuf,
+    pub(crate) refresh: Refresh,
+    pub(crate) args: ResolverInstallerOptions,
+    pub(crate) filesystem: ResolverInstallerOptions,
+}
+
+impl ToolExportSettings {
+    /// Resolve the [`ToolExportSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: ToolExportArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let ToolExportArgs {
+            name,
+            out_dir,
+            installer,
+            build,
+            refresh,
+        } = args;
+
+        let args = resolver_installer_options(installer, build);
+        let filesystem = filesystem
+            .map(FilesystemOptions::into_options)
+            .map(|options| options.top_level)
+            .unwrap_or_default();
+
+        Self {
+            name,
+            out_dir,
+            refresh: Refresh::from(refresh),
+            args,
+            filesystem,
+        }
+    }
+}
+
 /// The resolved settings to use for a `tool list` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -926,6 +1098,7 @@ impl PythonPinSettings {
 pub(crate) struct SyncSettings {
     pub(crate) locked: bool,
     pub(crate) frozen: bool,
+    pub(crate) check: bool,
     pub(crate) extras: ExtrasSpecification,
     pub(crate) dev: DevGroupsSpecification,
     pub(crate) editable: EditableMode,
@@ -963,6 +1136,7 @@ impl SyncSettings {
             no_install_package,
             locked,
             frozen,
+            check,
             installer,
             build,
             refresh,
@@ -983,6 +1157,7 @@ impl SyncSettings {
         Self {
             locked,
             frozen,
+            check,
             extras: ExtrasSpecification::from_args(
                 flag(all_extras, no_all_extras).unwrap_or_default(),
                 no_extra,
@@ -1056,6 +1231,62 @@ impl LockSettings {
     }
 }
 
+/// The resolved settings to use for a `version` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct VersionSettings {
+    pub(crate) value: Option<String>,
+    pub(crate) bump: Vec<VersionBump>,
+    pub(crate) dry_run: bool,
+    pub(crate) short: bool,
+    pub(crate) output_format: VersionFormat,
+    pub(crate) frozen: bool,
+    pub(crate) package: Option<PackageName>,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverSettings,
+}
+
+impl VersionSettings {
+    /// Resolve the [`VersionSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: VersionArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let VersionArgs {
+            value,
+            bump,
+            dry_run,
+            short,
+            output_format,
+            frozen,
+            package,
+            resolver,
+            build,
+            refresh,
+            python,
+        } = args;
+
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            value,
+            bump,
+            dry_run,
+            short,
+            output_format,
+            frozen,
+            package,
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            settings: ResolverSettings::combine(resolver_options(resolver, build), filesystem),
+            install_mirrors,
+        }
+    }
+}
+
 /// The resolved settings to use for a `add` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
 #[derive(Debug, Clone)]
@@ -1069,6 +1300,7 @@ pub(crate) struct AddSettings {
     pub(crate) editable: Option<bool>,
     pub(crate) extras: Vec<ExtraName>,
     pub(crate) raw_sources: bool,
+    pub(crate) bounds: Option<AddBoundsKind>,
     pub(crate) rev: Option<String>,
     pub(crate) tag: Option<String>,
     pub(crate) branch: Option<String>,
@@ -1095,6 +1327,7 @@ impl AddSettings {
             no_editable,
             extra,
             raw_sources,
+            bounds,
             rev,
             tag,
             branch,
@@ -1164,6 +1397,8 @@ impl AddSettings {
             }
         }
 
+        let bounds = bounds.or(filesystem.as_ref().and_then(|fs| fs.bounds));
+
         let install_mirrors = filesystem
             .clone()
             .map(|fs| fs.install_mirrors.clone())
@@ -1177,6 +1412,7 @@ impl AddSettings {
             requirements,
             dependency_type,
             raw_sources,
+            bounds,
             rev,
             tag,
             branch,
@@ -1204,6 +1440,8 @@ pub(crate) struct RemoveSettings {
     pub(crate) frozen: bool,
     pub(crate) no_sync: bool,
     pub(crate) packages: Vec<PackageName>,
+    pub(crate) unused: bool,
+    pub(crate) dry_run: bool,
     pub(crate) dependency_type: DependencyType,
     pub(crate) package: Option<PackageName>,
     pub(crate) script: Option<PathBuf>,
@@ -1222,6 +1460,8 @@ impl RemoveSettings {
             optional,
             packages,
             group,
+            unused,
+            dry_run,
             no_sync,
             locked,
             frozen,
@@ -1253,6 +1493,8 @@ impl RemoveSettings {
             frozen,
             no_sync,
             packages,
+            unused,
+            dry_run,
             dependency_type,
             package,
             script,
@@ -1267,6 +1509,62 @@ impl RemoveSettings {
     }
 }
 
+/// The resolved settings to use for an `upgrade` invocation.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone)]
+pub(crate) struct UpgradeSettings {
+    pub(crate) packages: Vec<PackageName>,
+    pub(crate) bounds: Option<AddBoundsKind>,
+    pub(crate) dry_run: bool,
+    pub(crate) frozen: bool,
+    pub(crate) no_sync: bool,
+    pub(crate) package: Option<PackageName>,
+    pub(crate) python: Option<String>,
+    pub(crate) install_mirrors: PythonInstallMirrors,
+    pub(crate) refresh: Refresh,
+    pub(crate) settings: ResolverInstallerSettings,
+}
+
+impl UpgradeSettings {
+    /// Resolve the [`UpgradeSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: UpgradeArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let UpgradeArgs {
+            packages,
+            bounds,
+            dry_run,
+            no_sync,
+            frozen,
+            installer,
+            build,
+            refresh,
+            package,
+            python,
+        } = args;
+
+        let install_mirrors = filesystem
+            .clone()
+            .map(|fs| fs.install_mirrors.clone())
+            .unwrap_or_default();
+
+        Self {
+            packages,
+            bounds,
+            dry_run,
+            frozen,
+            no_sync,
+            package,
+            python: python.and_then(Maybe::into_option),
+            refresh: Refresh::from(refresh),
+            settings: ResolverInstallerSettings::combine(
+                resolver_installer_options(installer, build),
+                filesystem,
+            ),
+            install_mirrors,
+        }
+    }
+}
+
 /// The resolved settings to use for a `tree` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -1336,6 +1634,29 @@ impl TreeSettings {
     }
 }
 
+/// The resolved settings to use for a `workspace list` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct WorkspaceListSettings {
+    pub(crate) affected_by: Option<Vec<PathBuf>>,
+    pub(crate) format: WorkspaceListFormat,
+}
+
+impl WorkspaceListSettings {
+    /// Resolve the [`WorkspaceListSettings`] from the CLI and filesystem configuration.
+    #[allow(clippy::needless_pass_by_value)]
+    pub(crate) fn resolve(args: WorkspaceListArgs, _filesystem: Option<FilesystemOptions>) -> Self {
+        let WorkspaceListArgs {
+            affected_by,
+            format,
+        } = args;
+
+        Self {
+            affected_by,
+            format,
+        }
+    }
+}
+
 /// The resolved settings to use for an `export` invocation.
 #[allow(clippy::struct_excessive_bools, dead_code)]
 #[derive(Debug, Clone)]
@@ -1432,6 +1753,42 @@ impl ExportSettings {
     }
 }
 
+/// The resolved settings to use for a `mirror` invocation.
+#[derive(Debug, Clone)]
+pub(crate) struct MirrorSettings {
+    pub(crate) out_dir: PathBuf,
+    pub(crate) index_locations: IndexLocations,
+    pub(crate) keyring_provider: KeyringProviderType,
+}
+
+impl MirrorSettings {
+    /// Resolve the [`MirrorSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: MirrorArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let MirrorArgs {
+            out_dir,
+            keyring_provider,
+        } = args;
+
+        let ResolverSettings {
+            index_locations,
+            keyring_provider,
+            ..
+        } = ResolverSettings::combine(
+            ResolverOptions {
+                keyring_provider,
+                ..ResolverOptions::default()
+            },
+            filesystem,
+        );
+
+        Self {
+            out_dir,
+            index_locations,
+            keyring_provider,
+        }
+    }
+}
+
 /// The resolved settings to use for a `pip compile` invocation.
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
@@ -2959,6 +3316,134 @@ impl PublishSettings {
     }
 }
 
+/// The resolved settings to use for an invocation of the `uv auth login` CLI.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthLoginSettings {
+    pub(crate) service: String,
+    pub(crate) username: Option<String>,
+    pub(crate) password: Option<String>,
+    pub(crate) store: AuthStore,
+    pub(crate) indexes: Vec<Index>,
+}
+
+impl AuthLoginSettings {
+    /// Resolve the [`AuthLoginSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuthLoginArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let Options { top_level, .. } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default();
+
+        // Tokens are encoded in the same way as username/password
+        let (username, password) = if let Some(token) = args.token {
+            (Some("__token__".to_string()), Some(token))
+        } else {
+            (args.username, args.password)
+        };
+
+        Self {
+            service: args.service,
+            username,
+            password,
+            store: resolve_auth_store(
+                args.store,
+                args.keyring_provider.combine(top_level.keyring_provider),
+            ),
+            indexes: top_level.index.unwrap_or_default(),
+        }
+    }
+}
+
+/// The resolved settings to use for an invocation of the `uv auth logout` CLI.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthLogoutSettings {
+    pub(crate) service: String,
+    pub(crate) username: Option<String>,
+    pub(crate) store: AuthStore,
+    pub(crate) indexes: Vec<Index>,
+}
+
+impl AuthLogoutSettings {
+    /// Resolve the [`AuthLogoutSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuthLogoutArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let Options { top_level, .. } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default();
+
+        Self {
+            service: args.service,
+            username: args.username,
+            store: resolve_auth_store(
+                args.store,
+                args.keyring_provider.combine(top_level.keyring_provider),
+            ),
+            indexes: top_level.index.unwrap_or_default(),
+        }
+    }
+}
+
+/// The resolved settings to use for an invocation of the `uv auth token` CLI.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthTokenSettings {
+    pub(crate) service: String,
+    pub(crate) username: Option<String>,
+    pub(crate) store: AuthStore,
+    pub(crate) indexes: Vec<Index>,
+}
+
+impl AuthTokenSettings {
+    /// Resolve the [`AuthTokenSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuthTokenArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let Options { top_level, .. } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default();
+
+        Self {
+            service: args.service,
+            username: args.username,
+            store: resolve_auth_store(
+                args.store,
+                args.keyring_provider.combine(top_level.keyring_provider),
+            ),
+            indexes: top_level.index.unwrap_or_default(),
+        }
+    }
+}
+
+/// The resolved settings to use for an invocation of the `uv auth list` CLI.
+#[derive(Debug, Clone)]
+pub(crate) struct AuthListSettings {
+    pub(crate) store: AuthStore,
+}
+
+impl AuthListSettings {
+    /// Resolve the [`AuthListSettings`] from the CLI and filesystem configuration.
+    pub(crate) fn resolve(args: AuthListArgs, filesystem: Option<FilesystemOptions>) -> Self {
+        let Options { top_level, .. } = filesystem
+            .map(FilesystemOptions::into_options)
+            .unwrap_or_default();
+
+        Self {
+            store: resolve_auth_store(
+                args.store,
+                args.keyring_provider.combine(top_level.keyring_provider),
+            ),
+        }
+    }
+}
+
+/// Resolve the store for `uv auth`, falling back to the store that matches the keyring provider.
+fn resolve_auth_store(
+    store: Option<AuthStore>,
+    keyring_provider: Option<KeyringProviderType>,
+) -> AuthStore {
+    store.unwrap_or(match keyring_provider {
+        Some(KeyringProviderType::Native) => AuthStore::Native,
+        Some(KeyringProviderType::Disabled | KeyringProviderType::Subprocess) | None => {
+            AuthStore::File
+        }
+    })
+}
+
 // Environment variables that are not exposed as CLI arguments.
 mod env {
     use uv_static::EnvVars;
@@ -2972,6 +3457,29 @@ mod env {
     pub(super) const CONCURRENT_INSTALLS: (&str, &str) =
         (EnvVars::UV_CONCURRENT_INSTALLS, "a non-zero integer");
 
+    pub(super) const CONCURRENT_DOWNLOADS_PER_HOST: (&str, &str) = (
+        EnvVars::UV_CONCURRENT_DOWNLOADS_PER_HOST,
+        "a non-zero integer",
+    );
+
+    pub(super) const RETRY_BACKOFF_BASE: (&str, &str) = (
+        EnvVars::UV_RETRY_BACKOFF_BASE,
+        "an integer number of seconds",
+    );
+
+    pub(super) const RETRY_BACKOFF_MAX: (&str, &str) = (
+        EnvVars::UV_RETRY_BACKOFF_MAX,
+        "an integer number of seconds",
+    );
+
+    pub(super) const CACHE_MAX_ENVIRONMENTS: (&str, &str) =
+        (EnvVars::UV_CACHE_MAX_ENVIRONMENTS, "an integer");
+
+    pub(super) const CACHE_ENVIRONMENT_MAX_AGE: (&str, &str) = (
+        EnvVars::UV_CACHE_ENVIRONMENT_MAX_AGE,
+        "an integer number of seconds",
+    );
+
     pub(super) const UV_PYTHON_DOWNLOADS: (&str, &str) = (
         EnvVars::UV_PYTHON_DOWNLOADS,
         "one of 'auto', 'true', 'manual', 'never', or 'false'",
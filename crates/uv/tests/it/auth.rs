@@ -0,0 +1,317 @@
+use anyhow::Result;
+use assert_fs::prelude::*;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "The credentials file location can't be overridden on Windows"
+)]
+fn login_token_logout() -> Result<()> {
+    let context = TestContext::new_with_versions(&[]);
+
+    // Index names are resolved from the configuration.
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc::indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [[tool.uv.index]]
+        name = "internal"
+        url = "https://example.com/simple"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("login")
+        .arg("internal")
+        .arg("--username")
+        .arg("user")
+        .arg("--password")
+        .arg("secret"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for user@https://example.com
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("login")
+        .arg("https://example.org:8080/simple")
+        .arg("--token")
+        .arg("pypi-token"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for __token__@https://example.org:8080
+    "###
+    );
+
+    // The credentials are stored in the configuration directory, keyed by realm.
+    let credentials = context
+        .home_dir
+        .child("config")
+        .child("uv")
+        .child("credentials.toml");
+    assert_eq!(
+        fs_err::read_to_string(credentials.path())?,
+        indoc::indoc! {r#"
+            [[credential]]
+            realm = "https://example.com"
+            username = "user"
+            password = "secret"
+
+            [[credential]]
+            realm = "https://example.org:8080"
+            username = "__token__"
+            password = "pypi-token"
+        "#}
+    );
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs_err::metadata(credentials.path())?.permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
+    uv_snapshot!(context.filters(), context.auth().arg("list"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://example.com (user)
+    https://example.org:8080 (__token__)
+
+    ----- stderr -----
+    "###
+    );
+
+    // Credentials apply to any URL in the realm.
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("token")
+        .arg("https://example.org:8080/other/"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pypi-token
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("token")
+        .arg("internal")
+        .arg("--username")
+        .arg("other"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No credentials found for `other@https://example.com/simple`
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("logout")
+        .arg("example.com"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed credentials for user@https://example.com
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("token")
+        .arg("internal"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No credentials found for `https://example.com/simple`
+    "###
+    );
+
+    // With the `subprocess` keyring provider, the credentials file is used.
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("list")
+        .arg("--keyring-provider")
+        .arg("subprocess"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://example.org:8080 (__token__)
+
+    ----- stderr -----
+    "###
+    );
+
+    // The store can be selected regardless of the keyring provider.
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("list")
+        .arg("--store")
+        .arg("native")
+        .env(EnvVars::UV_KEYRING_PROVIDER, "disabled"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No credentials found
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("list")
+        .arg("--store")
+        .arg("file")
+        .env(EnvVars::UV_KEYRING_PROVIDER, "native"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    https://example.org:8080 (__token__)
+
+    ----- stderr -----
+    "###
+    );
+
+    Ok(())
+}
+
+#[test]
+#[cfg_attr(
+    windows,
+    ignore = "The credential store location can't be overridden on Windows"
+)]
+fn native_login_list_logout() {
+    let context = TestContext::new_with_versions(&[]);
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("login")
+        .arg("https://user@example.com/simple/")
+        .arg("--username")
+        .arg("user")
+        .arg("--password")
+        .arg("secret")
+        .arg("--keyring-provider")
+        .arg("native"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for user@https://example.com/simple
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("login")
+        .arg("example.com")
+        .arg("--token")
+        .arg("pypi-token")
+        .arg("--keyring-provider")
+        .arg("native"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Stored credentials for __token__@example.com
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("list")
+        .arg("--keyring-provider")
+        .arg("native"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    example.com (__token__)
+    https://example.com/simple (user)
+
+    ----- stderr -----
+    "###
+    );
+
+    // The credentials are not stored in plaintext.
+    let store = context
+        .home_dir
+        .child("data")
+        .child("uv")
+        .child("credentials")
+        .child("keyring.bin");
+    let contents = fs_err::read(store.path()).unwrap();
+    assert!(!contents.windows(6).any(|window| window == b"secret"));
+
+    // The most specific entry is used.
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("token")
+        .arg("https://example.com/simple/anyio/")
+        .arg("--keyring-provider")
+        .arg("native"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    secret
+
+    ----- stderr -----
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("logout")
+        .arg("https://example.com/simple")
+        .arg("--keyring-provider")
+        .arg("native"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Removed credentials for user@https://example.com/simple
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("logout")
+        .arg("https://example.com/simple")
+        .arg("--keyring-provider")
+        .arg("native"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: No credentials found for `https://example.com/simple`
+    "###
+    );
+
+    uv_snapshot!(context.filters(), context.auth()
+        .arg("list")
+        .arg("--keyring-provider")
+        .arg("native"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    example.com (__token__)
+
+    ----- stderr -----
+    "###
+    );
+}
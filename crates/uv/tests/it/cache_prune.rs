@@ -1,3 +1,5 @@
+use std::time::{Duration, SystemTime};
+
 use anyhow::Result;
 use assert_cmd::prelude::*;
 use assert_fs::prelude::*;
@@ -83,7 +85,7 @@ fn prune_stale_directory() -> Result<()> {
     Ok(())
 }
 
-/// `cache prune` should remove all cached environments from the cache.
+/// `cache prune` should remove any expired cached environments from the cache.
 #[test]
 fn prune_cached_env() {
     let context = TestContext::new("3.12").with_filtered_counts();
@@ -128,6 +130,16 @@ fn prune_cached_env() {
         ])
         .collect();
 
+    // Backdate the last use of the environment, such that it's no longer considered in use.
+    backdate_environments(&context, Duration::from_secs(2 * 60 * 60));
+
+    // Expire the environment via the `cache-environment-max-age` setting.
+    context
+        .temp_dir
+        .child("uv.toml")
+        .write_str("cache-environment-max-age = 3600")
+        .unwrap();
+
     uv_snapshot!(filters, context.prune().arg("--verbose"), @r###"
     success: true
     exit_code: 0
@@ -136,12 +148,71 @@ fn prune_cached_env() {
     ----- stderr -----
     DEBUG uv [VERSION] ([COMMIT] DATE)
     Pruning cache at: [CACHE_DIR]/
-    DEBUG Removing dangling cache environment: [CACHE_DIR]/environments-v1/[ENTRY]
-    DEBUG Removing dangling cache archive: [CACHE_DIR]/archive-v0/[ENTRY]
+    DEBUG Removing expired cache environment: [CACHE_DIR]/environments-v1/[ENTRY]
     Removed [N] files ([SIZE])
     "###);
 }
 
+/// `cache prune` should retain any recently used cached environments.
+#[test]
+fn prune_cached_env_retained() {
+    let context = TestContext::new("3.12").with_filtered_counts();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    context
+        .tool_run()
+        .arg("pytest@8.0.0")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([
+            // The cache entry does not have a stable key, so we filter it out
+            (
+                r"\[CACHE_DIR\](\\|\/)(.*?)(\\|\/).*",
+                "[CACHE_DIR]/$2/[ENTRY]",
+            ),
+        ])
+        .collect();
+
+    // Even if the environment has expired, it was used too recently to be removed.
+    uv_snapshot!(filters, context.prune()
+        .arg("--verbose")
+        .env(EnvVars::UV_CACHE_ENVIRONMENT_MAX_AGE, "0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    DEBUG uv [VERSION] ([COMMIT] DATE)
+    Pruning cache at: [CACHE_DIR]/
+    DEBUG Retaining recently used cache environment: [CACHE_DIR]/environments-v1/[ENTRY]
+    No unused entries found
+    "###);
+}
+
+/// Set the last use of every cached environment to `age` in the past.
+fn backdate_environments(context: &TestContext, age: Duration) {
+    let last_used = filetime::FileTime::from_system_time(SystemTime::now() - age);
+    for shard in fs_err::read_dir(context.cache_dir.child("environments-v1")).unwrap() {
+        for entry in fs_err::read_dir(shard.unwrap().path()).unwrap() {
+            let path = entry.unwrap().path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "last-used")
+            {
+                filetime::set_file_mtime(&path, last_used).unwrap();
+            }
+        }
+    }
+}
+
 /// `cache prune` should remove any stale symlink from the cache.
 #[test]
 fn prune_stale_symlink() -> Result<()> {
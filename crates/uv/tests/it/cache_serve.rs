@@ -0,0 +1,155 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::process::{Child, Stdio};
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use assert_fs::prelude::*;
+
+use crate::common::uv_snapshot;
+use crate::common::TestContext;
+
+/// Serve the wheels in one cache, and install from the server with another.
+#[test]
+fn serve() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let server = Server::start(&context)?;
+    assert!(server
+        .serving
+        .starts_with("Serving 1 wheel for 1 package from: "));
+
+    // Install from the server, using a separate cache.
+    let other = TestContext::new("3.12");
+    uv_snapshot!(other.filters(), other
+        .pip_install()
+        .arg("iniconfig")
+        .arg("--index-url")
+        .arg(&server.index), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###
+    );
+
+    Ok(())
+}
+
+/// Wheels are packed when the server starts, and listed with their hashes.
+#[test]
+fn serve_hash() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let requirements_txt = context.temp_dir.child("requirements.txt");
+    requirements_txt.write_str("iniconfig==2.0.0")?;
+
+    // Install a requirement, to populate the cache.
+    context
+        .pip_sync()
+        .arg("requirements.txt")
+        .assert()
+        .success();
+
+    let server = Server::start(&context)?;
+
+    // The wheel is listed with its hash before it's ever requested.
+    let page = get(&server.index, "iniconfig/")?;
+    assert!(page.contains(r#"<a href="../../files/iniconfig-2.0.0-py3-none-any.whl#sha256="#));
+
+    // The served wheel matches the listed hash.
+    let other = TestContext::new("3.12");
+    other
+        .pip_install()
+        .arg("iniconfig")
+        .arg("--index-url")
+        .arg(&server.index)
+        .assert()
+        .success();
+
+    // Packing is deterministic, so a later server lists the same hash.
+    let hash = |page: &str| {
+        page.split("#sha256=")
+            .nth(1)
+            .and_then(|rest| rest.split('"').next())
+            .map(ToString::to_string)
+    };
+    drop(server);
+    let later = Server::start(&context)?;
+    assert_eq!(hash(&page), hash(&get(&later.index, "iniconfig/")?));
+
+    Ok(())
+}
+
+/// Fetch a page from the server, relative to the index URL.
+fn get(index: &str, path: &str) -> Result<String> {
+    let url = url::Url::parse(index)?.join(path)?;
+    let mut stream = TcpStream::connect(format!(
+        "{}:{}",
+        url.host_str().unwrap(),
+        url.port().unwrap()
+    ))?;
+    write!(
+        stream,
+        "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n",
+        url.path()
+    )?;
+    let mut response = String::new();
+    stream.read_to_string(&mut response)?;
+    Ok(response)
+}
+
+/// A running `uv cache serve` process, which is killed on drop.
+struct Server {
+    child: Child,
+    /// The first line of output, summarizing the served wheels.
+    serving: String,
+    /// The index URL of the server.
+    index: String,
+}
+
+impl Server {
+    /// Start a server for the cache of the given context, on an arbitrary port.
+    fn start(context: &TestContext) -> Result<Self> {
+        let mut child = context
+            .serve()
+            .arg("--bind")
+            .arg("127.0.0.1:0")
+            .stderr(Stdio::piped())
+            .spawn()?;
+        let mut lines = BufReader::new(child.stderr.take().unwrap()).lines();
+        let serving = lines.next().unwrap()?;
+        let listening = lines.next().unwrap()?;
+        let index = listening
+            .strip_prefix("Listening at: ")
+            .unwrap()
+            .to_string();
+        Ok(Self {
+            child,
+            serving,
+            index,
+        })
+    }
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
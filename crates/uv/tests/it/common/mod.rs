@@ -616,6 +616,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv version` command with options shared across scenarios.
+    pub fn version(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("version");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv export` command with options shared across scenarios.
     pub fn export(&self) -> Command {
         let mut command = self.new_command();
@@ -624,6 +632,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv mirror` command with options shared across scenarios.
+    pub fn mirror(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("mirror");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv build` command with options shared across scenarios.
     pub fn build(&self) -> Command {
         let mut command = self.new_command();
@@ -646,6 +662,25 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv auth` command, with an isolated credentials file and credential store.
+    pub fn auth(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("auth");
+        self.add_shared_args(&mut command, false);
+        command
+            .env(
+                EnvVars::XDG_CONFIG_HOME,
+                self.home_dir.child("config").as_os_str(),
+            )
+            .env(
+                EnvVars::XDG_DATA_HOME,
+                self.home_dir.child("data").as_os_str(),
+            )
+            .env_remove(EnvVars::UV_KEYRING_PROVIDER)
+            .env_remove(EnvVars::UV_KEYRING_PASSWORD);
+        command
+    }
+
     /// Create a `uv python find` command with options shared across scenarios.
     pub fn python_find(&self) -> Command {
         let mut command = self.new_command();
@@ -772,6 +807,22 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv tool doctor` command with options shared across scenarios.
+    pub fn tool_doctor(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("tool").arg("doctor");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
+    /// Create a `uv tool export` command with options shared across scenarios.
+    pub fn tool_export(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("tool").arg("export");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv add` command for the given requirements.
     pub fn add(&self) -> Command {
         let mut command = self.new_command();
@@ -788,6 +839,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv upgrade` command with options shared across scenarios.
+    pub fn upgrade(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("upgrade");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv tree` command with options shared across scenarios.
     pub fn tree(&self) -> Command {
         let mut command = self.new_command();
@@ -796,6 +855,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv workspace list` command with options shared across scenarios.
+    pub fn workspace_list(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("workspace").arg("list");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv cache clean` command.
     pub fn clean(&self) -> Command {
         let mut command = self.new_command();
@@ -812,6 +879,14 @@ impl TestContext {
         command
     }
 
+    /// Create a `uv cache serve` command.
+    pub fn serve(&self) -> Command {
+        let mut command = self.new_command();
+        command.arg("cache").arg("serve");
+        self.add_shared_args(&mut command, false);
+        command
+    }
+
     /// Create a `uv build_backend` command.
     ///
     /// Note that this command is hidden and only invoking it through a build frontend is supported.
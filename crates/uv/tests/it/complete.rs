@@ -0,0 +1,115 @@
+use std::path::PathBuf;
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::indoc;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, venv_bin_path, TestContext};
+
+/// Request the completions for the given words from the given command, as `bash` would, where
+/// the last word is the one being completed.
+fn complete(mut command: Command, words: &[&str]) -> Command {
+    command
+        .env(EnvVars::COMPLETE, "bash")
+        .env("_CLAP_COMPLETE_INDEX", (words.len() - 1).to_string())
+        .arg("--")
+        .args(words);
+    command
+}
+
+/// Complete the tasks and environment executables for `uv run`.
+#[test]
+#[cfg(unix)]
+fn complete_run() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.scripts]
+        hello-task = { cmd = "echo hello", help = "Say hello" }
+        "#
+    })?;
+
+    // Add an executable to the project environment.
+    let executable = venv_bin_path(&context.venv).join("hello-bin");
+    fs_err::write(&executable, "#!/bin/sh\necho hello\n")?;
+    fs_err::set_permissions(&executable, std::fs::Permissions::from_mode(0o755))?;
+
+    uv_snapshot!(context.filters(), complete(context.command(), &["uv", "run", "hello"]), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello-bin
+    hello-task
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), complete(context.command(), &["uv", "run", "hello-t"]), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello-task
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// Complete the names of the installed tools for `uv tool uninstall` and `uvx`.
+#[test]
+fn complete_tools() {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), complete(context.command(), &["uv", "tool", "uninstall", "b"])
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), complete(context.command(), &["uv", "tool", "uninstall", "x"])
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    // `uvx` forwards the completion request to uv.
+    let mut uvx = Command::new(PathBuf::from(env!("CARGO_BIN_EXE_uvx")));
+    context.add_shared_args(&mut uvx, false);
+    uv_snapshot!(context.filters(), complete(uvx, &["uvx", "b"])
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    black
+    ----- stderr -----
+    "###);
+}
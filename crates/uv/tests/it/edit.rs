@@ -3548,6 +3548,106 @@ fn remove_registry() -> Result<()> {
     Ok(())
 }
 
+/// Remove dependencies that aren't imported by the project.
+#[test]
+fn remove_unused() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0", "iniconfig==2.0.0"]
+    "#})?;
+
+    let main = context.temp_dir.child("main.py");
+    main.write_str("import anyio")?;
+
+    uv_snapshot!(context.filters(), context.sync(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==3.7.0
+     + idna==3.6
+     + iniconfig==2.0.0
+     + sniffio==1.3.1
+    "###);
+
+    // `iniconfig` is never imported.
+    uv_snapshot!(context.filters(), context.remove().arg("--unused").arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig
+
+    ----- stderr -----
+    Would remove 1 unused dependency
+    "###);
+
+    uv_snapshot!(context.filters(), context.remove().arg("--unused"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig
+
+    ----- stderr -----
+    Removing 1 unused dependency
+    Resolved 4 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+     - iniconfig==2.0.0
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+        "###
+        );
+    });
+
+    // Dependencies in `tool.uv.allow-unused` are retained, even if they're never imported.
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio==3.7.0"]
+
+        [tool.uv]
+        allow-unused = ["anyio"]
+    "#})?;
+    main.write_str("print('Hello, world!')")?;
+
+    uv_snapshot!(context.filters(), context.remove().arg("--unused"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No unused dependencies found
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn add_preserves_indentation_in_pyproject_toml() -> Result<()> {
     let context = TestContext::new("3.12");
@@ -4353,6 +4453,276 @@ fn add_lower_bound_local() -> Result<()> {
     Ok(())
 }
 
+/// Add major-version bounds with `--bounds major`.
+#[test]
+fn add_bounds() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    // Adding `anyio` should include a lower and upper bound.
+    uv_snapshot!(context.filters(), context.add().arg("anyio").arg("--bounds").arg("major"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio>=4.3.0, <5.0.0",
+        ]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Read the bounds from the `tool.uv.bounds` setting.
+#[test]
+fn add_bounds_setting() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+
+        [tool.uv]
+        bounds = "exact"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.add().arg("anyio").arg("--dev"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+
+        [tool.uv]
+        bounds = "exact"
+
+        [dependency-groups]
+        dev = [
+            "anyio==4.3.0",
+        ]
+        "###
+        );
+    });
+
+    // The command-line flag takes precedence over the setting.
+    uv_snapshot!(context.filters(), context.add().arg("iniconfig").arg("--bounds").arg("minor"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 5 packages in [TIME]
+    Prepared 2 packages in [TIME]
+    Uninstalled 1 package in [TIME]
+    Installed 2 packages in [TIME]
+     + iniconfig==2.0.0
+     ~ project==0.1.0 (from file://[TEMP_DIR]/)
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "iniconfig>=2.0.0, <2.1.0",
+        ]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+
+        [tool.uv]
+        bounds = "exact"
+
+        [dependency-groups]
+        dev = [
+            "anyio==4.3.0",
+        ]
+        "###
+        );
+    });
+
+    Ok(())
+}
+
+/// Update the bounds of existing dependencies when upgrading.
+#[test]
+fn add_bounds_upgrade() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=3.0.0,<4.0.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.lock(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###);
+
+    // Re-adding `anyio` without `--upgrade-package` should retain the existing bounds.
+    uv_snapshot!(context.filters(), context.add().arg("anyio").arg("--bounds").arg("major").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio",
+        ]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "###
+        );
+    });
+
+    // With `--upgrade-package`, the bounds should reflect the upgraded version.
+    uv_snapshot!(context.filters(), context.add().arg("anyio").arg("--bounds").arg("major").arg("--upgrade-package").arg("anyio"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + sniffio==1.3.1
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = [
+            "anyio>=4.3.0, <5.0.0",
+        ]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "###
+        );
+    });
+
+    Ok(())
+}
+
 /// Add dependencies to a (legacy) non-project workspace root.
 #[test]
 fn add_non_project() -> Result<()> {
@@ -8299,3 +8669,211 @@ fn add_no_indent() -> Result<()> {
     });
     Ok(())
 }
+
+/// Upgrade the version specifiers of all dependencies, retaining their style.
+#[test]
+fn upgrade() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=3.0.0", "iniconfig>=1.0.0,<2", "requests @ https://files.pythonhosted.org/packages/70/8e/0e2d847013cb52cd35b38c009bb167a1a26b2ce6cd6965bf26b47bc0bf44/requests-2.31.0-py3-none-any.whl"]
+
+        [dependency-groups]
+        dev = ["typing-extensions==4.0.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+    "#})?;
+
+    // Preview the changes.
+    uv_snapshot!(context.filters(), context.upgrade().arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package           Table                 Current     Latest Upgraded
+    ----------------- --------------------- ----------- ------ ---------------
+    anyio             project.dependencies  >=3.0.0     4.3.0  >=4.3.0
+    iniconfig         project.dependencies  >=1.0.0, <2 2.0.0  >=2.0.0, <3.0.0
+    typing-extensions dependency-groups.dev ==4.0.0     4.10.0 ==4.10.0
+
+    ----- stderr -----
+    Would upgrade 3 dependencies
+    "###);
+
+    // The project should not be locked.
+    assert!(!context.temp_dir.child("uv.lock").exists());
+
+    uv_snapshot!(context.filters(), context.upgrade(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package           Table                 Current     Latest Upgraded
+    ----------------- --------------------- ----------- ------ ---------------
+    anyio             project.dependencies  >=3.0.0     4.3.0  >=4.3.0
+    iniconfig         project.dependencies  >=1.0.0, <2 2.0.0  >=2.0.0, <3.0.0
+    typing-extensions dependency-groups.dev ==4.0.0     4.10.0 ==4.10.0
+
+    ----- stderr -----
+    Resolved 10 packages in [TIME]
+    Prepared 10 packages in [TIME]
+    Installed 10 packages in [TIME]
+     + anyio==4.3.0
+     + certifi==2024.2.2
+     + charset-normalizer==3.3.2
+     + idna==3.6
+     + iniconfig==2.0.0
+     + project==0.1.0 (from file://[TEMP_DIR]/)
+     + requests==2.31.0 (from https://files.pythonhosted.org/packages/70/8e/0e2d847013cb52cd35b38c009bb167a1a26b2ce6cd6965bf26b47bc0bf44/requests-2.31.0-py3-none-any.whl)
+     + sniffio==1.3.1
+     + typing-extensions==4.10.0
+     + urllib3==2.2.1
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=4.3.0", "iniconfig>=2.0.0, <3.0.0", "requests @ https://files.pythonhosted.org/packages/70/8e/0e2d847013cb52cd35b38c009bb167a1a26b2ce6cd6965bf26b47bc0bf44/requests-2.31.0-py3-none-any.whl"]
+
+        [dependency-groups]
+        dev = ["typing-extensions==4.10.0"]
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "###
+        );
+    });
+
+    // Upgrading again should have no effect.
+    uv_snapshot!(context.filters(), context.upgrade(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    All dependencies are up-to-date
+    "###);
+
+    Ok(())
+}
+
+/// Upgrade a specific dependency with `--bounds`.
+#[test]
+fn upgrade_package_bounds() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=3.0.0,!=3.1.0", "iniconfig>=1.0.0"]
+    "#})?;
+
+    // The version specifier for `anyio` doesn't match a supported style.
+    uv_snapshot!(context.filters(), context.upgrade().arg("anyio").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    warning: Skipping `anyio`, since the version specifier `>=3.0.0, !=3.1.0` can't be upgraded automatically (use `--bounds` to replace it)
+    No dependencies to upgrade
+    "###);
+
+    // With `--bounds`, the version specifier should be replaced.
+    uv_snapshot!(context.filters(), context.upgrade().arg("anyio").arg("--bounds").arg("minor").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Package Table                Current          Latest Upgraded
+    ------- -------------------- ---------------- ------ ---------------
+    anyio   project.dependencies >=3.0.0, !=3.1.0 4.3.0  >=4.3.0, <4.4.0
+
+    ----- stderr -----
+    "###);
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["anyio>=4.3.0, <4.4.0", "iniconfig>=1.0.0"]
+        "###
+        );
+    });
+
+    // Upgrading a package that isn't a dependency should fail.
+    uv_snapshot!(context.filters(), context.upgrade().arg("flask"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The dependency `flask` could not be found in the project
+    "###);
+
+    Ok(())
+}
+
+/// If the project environment can't be created, the `pyproject.toml` should be left untouched.
+#[test]
+fn upgrade_environment_failure() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=1.0.0"]
+    "#})?;
+
+    // Request an interpreter that doesn't exist.
+    context
+        .upgrade()
+        .arg("--python")
+        .arg("3.99")
+        .assert()
+        .failure();
+
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig>=1.0.0"]
+        "###
+        );
+    });
+
+    Ok(())
+}
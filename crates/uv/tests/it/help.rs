@@ -18,19 +18,23 @@ fn help() {
       init                       Create a new project
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
+      upgrade                    Upgrade the project's dependencies to the latest versions
       sync                       Update the project's environment
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
+      mirror                     Materialize a local package index from the project's lockfile
       tree                       Display the project's dependency tree
+      workspace                  Manage the project's workspace
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage credentials for package indexes
       cache                      Manage uv's cache
       self                       Manage the uv executable
-      version                    Display uv's version
+      version                    Read or update the project's version
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
 
@@ -62,6 +66,8 @@ fn help() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --report-cache-stats
+              Print a JSON summary of the HTTP cache statistics after the command completes
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -95,24 +101,28 @@ fn help_flag() {
     Usage: uv [OPTIONS] <COMMAND>
 
     Commands:
-      run      Run a command or script
-      init     Create a new project
-      add      Add dependencies to the project
-      remove   Remove dependencies from the project
-      sync     Update the project's environment
-      lock     Update the project's lockfile
-      export   Export the project's lockfile to an alternate format
-      tree     Display the project's dependency tree
-      tool     Run and install commands provided by Python packages
-      python   Manage Python versions and installations
-      pip      Manage Python packages with a pip-compatible interface
-      venv     Create a virtual environment
-      build    Build Python packages into source distributions and wheels
-      publish  Upload distributions to an index
-      cache    Manage uv's cache
-      self     Manage the uv executable
-      version  Display uv's version
-      help     Display documentation for a command
+      run        Run a command or script
+      init       Create a new project
+      add        Add dependencies to the project
+      remove     Remove dependencies from the project
+      upgrade    Upgrade the project's dependencies to the latest versions
+      sync       Update the project's environment
+      lock       Update the project's lockfile
+      export     Export the project's lockfile to an alternate format
+      mirror     Materialize a local package index from the project's lockfile
+      tree       Display the project's dependency tree
+      workspace  Manage the project's workspace
+      tool       Run and install commands provided by Python packages
+      python     Manage Python versions and installations
+      pip        Manage Python packages with a pip-compatible interface
+      venv       Create a virtual environment
+      build      Build Python packages into source distributions and wheels
+      publish    Upload distributions to an index
+      auth       Manage credentials for package indexes
+      cache      Manage uv's cache
+      self       Manage the uv executable
+      version    Read or update the project's version
+      help       Display documentation for a command
 
     Cache options:
       -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
@@ -142,6 +152,8 @@ fn help_flag() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --report-cache-stats
+              Print a JSON summary of the HTTP cache statistics after the command completes
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -174,24 +186,28 @@ fn help_short_flag() {
     Usage: uv [OPTIONS] <COMMAND>
 
     Commands:
-      run      Run a command or script
-      init     Create a new project
-      add      Add dependencies to the project
-      remove   Remove dependencies from the project
-      sync     Update the project's environment
-      lock     Update the project's lockfile
-      export   Export the project's lockfile to an alternate format
-      tree     Display the project's dependency tree
-      tool     Run and install commands provided by Python packages
-      python   Manage Python versions and installations
-      pip      Manage Python packages with a pip-compatible interface
-      venv     Create a virtual environment
-      build    Build Python packages into source distributions and wheels
-      publish  Upload distributions to an index
-      cache    Manage uv's cache
-      self     Manage the uv executable
-      version  Display uv's version
-      help     Display documentation for a command
+      run        Run a command or script
+      init       Create a new project
+      add        Add dependencies to the project
+      remove     Remove dependencies from the project
+      upgrade    Upgrade the project's dependencies to the latest versions
+      sync       Update the project's environment
+      lock       Update the project's lockfile
+      export     Export the project's lockfile to an alternate format
+      mirror     Materialize a local package index from the project's lockfile
+      tree       Display the project's dependency tree
+      workspace  Manage the project's workspace
+      tool       Run and install commands provided by Python packages
+      python     Manage Python versions and installations
+      pip        Manage Python packages with a pip-compatible interface
+      venv       Create a virtual environment
+      build      Build Python packages into source distributions and wheels
+      publish    Upload distributions to an index
+      auth       Manage credentials for package indexes
+      cache      Manage uv's cache
+      self       Manage the uv executable
+      version    Read or update the project's version
+      help       Display documentation for a command
 
     Cache options:
       -n, --no-cache               Avoid reading from or writing to the cache, instead using a temporary
@@ -221,6 +237,8 @@ fn help_short_flag() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --report-cache-stats
+              Print a JSON summary of the HTTP cache statistics after the command completes
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -398,6 +416,11 @@ fn help_subcommand() {
               
               [env: UV_NO_PROGRESS=]
 
+          --report-cache-stats
+              Print a JSON summary of the HTTP cache statistics after the command completes.
+              
+              The summary is written to stderr, and includes counters for each index.
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
               
@@ -642,6 +665,11 @@ fn help_subsubcommand() {
               
               [env: UV_NO_PROGRESS=]
 
+          --report-cache-stats
+              Print a JSON summary of the HTTP cache statistics after the command completes.
+              
+              The summary is written to stderr, and includes counters for each index.
+
           --directory <DIRECTORY>
               Change to the given directory prior to running the command.
               
@@ -738,6 +766,8 @@ fn help_flag_subcommand() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --report-cache-stats
+              Print a JSON summary of the HTTP cache statistics after the command completes
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -811,6 +841,8 @@ fn help_flag_subsubcommand() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --report-cache-stats
+              Print a JSON summary of the HTTP cache statistics after the command completes
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -843,16 +875,20 @@ fn help_unknown_subcommand() {
         init
         add
         remove
+        upgrade
         sync
         lock
         export
+        mirror
         tree
+        workspace
         tool
         python
         pip
         venv
         build
         publish
+        auth
         cache
         self
         version
@@ -870,16 +906,20 @@ fn help_unknown_subcommand() {
         init
         add
         remove
+        upgrade
         sync
         lock
         export
+        mirror
         tree
+        workspace
         tool
         python
         pip
         venv
         build
         publish
+        auth
         cache
         self
         version
@@ -924,19 +964,23 @@ fn help_with_global_option() {
       init                       Create a new project
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
+      upgrade                    Upgrade the project's dependencies to the latest versions
       sync                       Update the project's environment
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
+      mirror                     Materialize a local package index from the project's lockfile
       tree                       Display the project's dependency tree
+      workspace                  Manage the project's workspace
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage credentials for package indexes
       cache                      Manage uv's cache
       self                       Manage the uv executable
-      version                    Display uv's version
+      version                    Read or update the project's version
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
 
@@ -968,6 +1012,8 @@ fn help_with_global_option() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --report-cache-stats
+              Print a JSON summary of the HTTP cache statistics after the command completes
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
@@ -1040,19 +1086,23 @@ fn help_with_no_pager() {
       init                       Create a new project
       add                        Add dependencies to the project
       remove                     Remove dependencies from the project
+      upgrade                    Upgrade the project's dependencies to the latest versions
       sync                       Update the project's environment
       lock                       Update the project's lockfile
       export                     Export the project's lockfile to an alternate format
+      mirror                     Materialize a local package index from the project's lockfile
       tree                       Display the project's dependency tree
+      workspace                  Manage the project's workspace
       tool                       Run and install commands provided by Python packages
       python                     Manage Python versions and installations
       pip                        Manage Python packages with a pip-compatible interface
       venv                       Create a virtual environment
       build                      Build Python packages into source distributions and wheels
       publish                    Upload distributions to an index
+      auth                       Manage credentials for package indexes
       cache                      Manage uv's cache
       self                       Manage the uv executable
-      version                    Display uv's version
+      version                    Read or update the project's version
       generate-shell-completion  Generate shell completion
       help                       Display documentation for a command
 
@@ -1084,6 +1134,8 @@ fn help_with_no_pager() {
               Allow insecure connections to a host [env: UV_INSECURE_HOST=]
           --no-progress
               Hide all progress outputs [env: UV_NO_PROGRESS=]
+          --report-cache-stats
+              Print a JSON summary of the HTTP cache statistics after the command completes
           --directory <DIRECTORY>
               Change to the given directory prior to running the command
           --project <PROJECT>
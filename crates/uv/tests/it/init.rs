@@ -3357,3 +3357,187 @@ fn init_without_description() -> Result<()> {
 
     Ok(())
 }
+
+/// Run `uv init --template` to create a project from a local template directory.
+#[test]
+fn init_template() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let template = context.temp_dir.child("templates").child("service");
+    template.child("pyproject.toml").write_str(indoc! {r#"
+        [project]
+        name = "{{ name }}"
+        version = "0.1.0"
+        dependencies = []
+
+        [dependency-groups]
+        dev = []
+    "#})?;
+    template
+        .child("src")
+        .child("{{ module_name }}")
+        .child("__init__.py")
+        .write_str(indoc! {r#"
+        def hello() -> str:
+            return "Hello from {{ name }}!"
+    "#})?;
+    template
+        .child(".github")
+        .child("workflows")
+        .child("ci.yml")
+        .write_str(indoc! {r#"
+        jobs:
+          test:
+            strategy:
+              matrix:
+                python-version: ["{{python_version}}"]
+            steps:
+              - run: uv run --python ${{ matrix.python-version }} pytest
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.init().arg("--template").arg("templates/service").arg("foo-bar"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `foo-bar` at `[TEMP_DIR]/foo-bar`
+    "###);
+
+    let pyproject = context.read("foo-bar/pyproject.toml");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject, @r###"
+        [project]
+        name = "foo-bar"
+        version = "0.1.0"
+        dependencies = []
+        requires-python = ">=3.12"
+
+        [dependency-groups]
+        dev = []
+        "###
+        );
+    });
+
+    let init_py = context.read("foo-bar/src/foo_bar/__init__.py");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            init_py, @r###"
+        def hello() -> str:
+            return "Hello from foo-bar!"
+        "###
+        );
+    });
+
+    // Unknown placeholders, like GitHub Actions expressions, are left as-is.
+    let ci = context.read("foo-bar/.github/workflows/ci.yml");
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            ci, @r###"
+        jobs:
+          test:
+            strategy:
+              matrix:
+                python-version: ["3.12"]
+            steps:
+              - run: uv run --python ${{ matrix.python-version }} pytest
+        "###
+        );
+    });
+
+    let python_version = context.read("foo-bar/.python-version");
+    assert_snapshot!(python_version, @"3.12");
+
+    // A missing template directory is an error.
+    uv_snapshot!(context.filters(), context.init().arg("--template").arg("templates/missing").arg("baz"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Template directory does not exist: `templates/missing`
+    "###);
+
+    Ok(())
+}
+
+/// Symlinks within a template are recreated, while symlinks that point outside of the template
+/// are rejected.
+#[test]
+#[cfg(unix)]
+fn init_template_symlinks() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let template = context.temp_dir.child("templates").child("service");
+    template
+        .child("src")
+        .child("{{ module_name }}")
+        .child("__init__.py")
+        .write_str("")?;
+    template.child("docs").child("index.md").write_str("# Docs")?;
+    template
+        .child("module")
+        .symlink_to_file("src/{{ module_name }}/__init__.py")?;
+    template.child("documentation").symlink_to_dir("docs")?;
+
+    uv_snapshot!(context.filters(), context.init().arg("--template").arg("templates/service").arg("foo-bar"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Initialized project `foo-bar` at `[TEMP_DIR]/foo-bar`
+    "###);
+
+    let project = context.temp_dir.child("foo-bar");
+    assert_eq!(
+        fs_err::read_link(project.child("module"))?,
+        std::path::PathBuf::from("src/foo_bar/__init__.py")
+    );
+    assert_eq!(
+        fs_err::read_link(project.child("documentation"))?,
+        std::path::PathBuf::from("docs")
+    );
+    assert_eq!(context.read("foo-bar/documentation/index.md"), "# Docs");
+
+    // A symlink to a file outside of the template is rejected, before creating the project.
+    context.temp_dir.child("secret.txt").write_str("secret")?;
+    let template = context.temp_dir.child("templates").child("leaky");
+    template.child("README.md").write_str("")?;
+    template.child("notes").symlink_to_file("../../secret.txt")?;
+
+    uv_snapshot!(context.filters(), context.init().arg("--template").arg("templates/leaky").arg("baz"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Template contains a symlink that points outside of the template: `notes`
+    "###);
+
+    context.temp_dir.child("baz").assert(predicate::path::missing());
+
+    // As are absolute symlinks.
+    fs_err::remove_file(template.child("notes"))?;
+    template
+        .child("notes")
+        .symlink_to_file(context.temp_dir.child("secret.txt").path())?;
+
+    uv_snapshot!(context.filters(), context.init().arg("--template").arg("templates/leaky").arg("baz"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Template contains a symlink that points outside of the template: `notes`
+    "###);
+
+    Ok(())
+}
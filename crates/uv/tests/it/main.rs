@@ -3,6 +3,8 @@
 
 pub(crate) mod common;
 
+mod auth;
+
 mod branching_urls;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
@@ -17,6 +19,12 @@ mod cache_clean;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod cache_prune;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod cache_serve;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod complete;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod ecosystem;
 
@@ -39,6 +47,9 @@ mod lock_conflict;
 
 mod lock_scenarios;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod mirror;
+
 mod pip_check;
 
 #[cfg(all(feature = "python", feature = "pypi"))]
@@ -93,6 +104,12 @@ mod sync;
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_dir;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod tool_doctor;
+
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod tool_export;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod tool_install;
 
@@ -114,6 +131,9 @@ mod tree;
 #[cfg(feature = "python")]
 mod venv;
 
+#[cfg(all(feature = "python", feature = "pypi"))]
+mod version;
+
 #[cfg(all(feature = "python", feature = "pypi"))]
 mod workflow;
 
@@ -0,0 +1,240 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use insta::assert_snapshot;
+use url::Url;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Mirror the distributions in the lockfile, then install from the mirror without network access.
+#[test]
+fn mirror() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.mirror().arg("mirror"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Mirrored 2 distributions (2 downloaded) in [TIME]
+    Wrote index for 1 package to: `mirror`
+    "###);
+
+    let mirror = context.temp_dir.child("mirror");
+    mirror
+        .child("files/iniconfig-2.0.0.tar.gz")
+        .assert(predicates::path::is_file());
+    mirror
+        .child("files/iniconfig-2.0.0-py3-none-any.whl")
+        .assert(predicates::path::is_file());
+
+    assert_snapshot!(context.read("mirror/iniconfig/index.html"), @r###"
+    <!DOCTYPE html>
+    <html>
+      <head>
+        <meta name="pypi:repository-version" content="1.0">
+        <title>Links for iniconfig</title>
+      </head>
+      <body>
+        <h1>Links for iniconfig</h1>
+        <a href="../files/iniconfig-2.0.0-py3-none-any.whl#sha256=b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374">iniconfig-2.0.0-py3-none-any.whl</a><br/>
+        <a href="../files/iniconfig-2.0.0.tar.gz#sha256=2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3">iniconfig-2.0.0.tar.gz</a><br/>
+      </body>
+    </html>
+    "###);
+
+    assert_snapshot!(context.read("mirror/index.json"), @r###"
+    {
+      "meta": {
+        "api-version": "1.0"
+      },
+      "projects": [
+        {
+          "name": "iniconfig"
+        }
+      ]
+    }
+    "###);
+
+    // Re-running the mirror should reuse the existing distributions.
+    uv_snapshot!(context.filters(), context.mirror().arg("mirror"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Mirrored 2 distributions (0 downloaded) in [TIME]
+    Wrote index for 1 package to: `mirror`
+    "###);
+
+    // Install from the mirror, without network access.
+    let index = Url::from_directory_path(mirror.path()).unwrap();
+    uv_snapshot!(context.filters(), context.pip_install()
+        .arg("iniconfig")
+        .arg("--index-url")
+        .arg(index.as_str())
+        .arg("--offline")
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
+/// Relock against the mirror, then sync from it without network access.
+#[test]
+fn mirror_sync() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    context.lock().assert().success();
+    context.mirror().arg("mirror").assert().success();
+
+    let mirror = context.temp_dir.child("mirror");
+    let index = Url::from_directory_path(mirror.path()).unwrap();
+
+    // The lockfile references PyPI, so it can't be used as-is.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--locked")
+        .arg("--offline")
+        .arg("--index-url")
+        .arg(index.as_str())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    "###);
+
+    // Relock against the mirror, which retains the locked versions.
+    uv_snapshot!(context.filters(), context.lock()
+        .arg("--offline")
+        .arg("--index-url")
+        .arg(index.as_str())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+
+        [[package]]
+        name = "iniconfig"
+        version = "2.0.0"
+        source = { registry = "mirror" }
+        sdist = { path = "files/iniconfig-2.0.0.tar.gz", hash = "sha256:2d91e135bf72d31a410b17c16da610a82cb55f6b0477d1a902134b24a455b8b3" }
+        wheels = [
+            { path = "files/iniconfig-2.0.0-py3-none-any.whl", hash = "sha256:b6a85871a79d2e3b22d2d1b94ac2824226a63c6b741c88f7ae975f18b6778374" },
+        ]
+
+        [[package]]
+        name = "project"
+        version = "0.1.0"
+        source = { virtual = "." }
+        dependencies = [
+            { name = "iniconfig" },
+        ]
+
+        [package.metadata]
+        requires-dist = [{ name = "iniconfig", specifier = "==2.0.0" }]
+        "###
+        );
+    });
+
+    // Sync from the mirror, without network access.
+    uv_snapshot!(context.filters(), context.sync()
+        .arg("--locked")
+        .arg("--offline")
+        .arg("--index-url")
+        .arg(index.as_str())
+        .env_remove(EnvVars::UV_EXCLUDE_NEWER), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    "###);
+
+    Ok(())
+}
+
+/// Mirroring requires a lockfile.
+#[test]
+fn mirror_missing_lockfile() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = ["iniconfig==2.0.0"]
+        "#,
+    )?;
+
+    uv_snapshot!(context.filters(), context.mirror().arg("mirror"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`.
+    "###);
+
+    Ok(())
+}
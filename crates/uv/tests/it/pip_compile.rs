@@ -6,6 +6,7 @@ use std::io::Cursor;
 use std::path::PathBuf;
 
 use anyhow::{bail, Context, Result};
+use assert_cmd::assert::OutputAssertExt;
 use assert_fs::prelude::*;
 use flate2::write::GzEncoder;
 use fs_err::File;
@@ -6205,6 +6206,74 @@ fn offline_registry() -> Result<()> {
     Ok(())
 }
 
+/// Report the HTTP cache statistics for a resolution served entirely from the cache.
+#[test]
+fn offline_registry_cache_stats() -> Result<()> {
+    let context = TestContext::new("3.12");
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("iniconfig==2.0.0")?;
+
+    // Populate the cache.
+    context
+        .pip_compile()
+        .arg("requirements.in")
+        .assert()
+        .success();
+
+    let filters: Vec<_> = context
+        .filters()
+        .into_iter()
+        .chain([(r#""cached_bytes": \d+"#, r#""cached_bytes": [BYTES]"#)])
+        .collect();
+
+    // Resolve with `--offline`, such that every response is served from the cache.
+    uv_snapshot!(filters, context.pip_compile()
+            .arg("requirements.in")
+            .arg("--offline")
+            .arg("--no-header")
+            .arg("--report-cache-stats"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    iniconfig==2.0.0
+        # via -r requirements.in
+    {
+      "total": {
+        "fresh": 2,
+        "revalidated": 0,
+        "not_modified": 0,
+        "misses": 0,
+        "cached_bytes": [BYTES],
+        "downloaded_bytes": 0
+      },
+      "indexes": {
+        "https://files.pythonhosted.org": {
+          "fresh": 1,
+          "revalidated": 0,
+          "not_modified": 0,
+          "misses": 0,
+          "cached_bytes": [BYTES],
+          "downloaded_bytes": 0
+        },
+        "https://pypi.org/simple": {
+          "fresh": 1,
+          "revalidated": 0,
+          "not_modified": 0,
+          "misses": 0,
+          "cached_bytes": [BYTES],
+          "downloaded_bytes": 0
+        }
+      }
+    }
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Resolve a registry package without network access via the `--offline` flag. We should backtrack
 /// to the latest version of the package that's available in the cache.
 #[test]
@@ -12064,6 +12133,52 @@ fn emit_index_annotation_hide_password() -> Result<()> {
     Ok(())
 }
 
+/// Pin packages to an index via its `packages` patterns.
+#[test]
+fn compile_index_packages() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let uv_toml = context.temp_dir.child("uv.toml");
+    uv_toml.write_str(indoc! {r#"
+        [[index]]
+        name = "test"
+        url = "https://test.pypi.org/simple"
+        packages = ["ini*"]
+        explicit = true
+    "#})?;
+
+    let requirements_in = context.temp_dir.child("requirements.in");
+    requirements_in.write_str("anyio==3.7.0\niniconfig==2.0.0")?;
+
+    uv_snapshot!(context.filters(), context.pip_compile()
+        .arg("requirements.in")
+        .arg("--emit-index-annotation"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    # This file was autogenerated by uv via the following command:
+    #    uv pip compile --cache-dir [CACHE_DIR] requirements.in --emit-index-annotation
+    anyio==3.7.0
+        # via -r requirements.in
+        # from https://pypi.org/simple
+    idna==3.6
+        # via anyio
+        # from https://pypi.org/simple
+    iniconfig==2.0.0
+        # via -r requirements.in
+        # from https://test.pypi.org/simple
+    sniffio==1.3.1
+        # via anyio
+        # from https://pypi.org/simple
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    "###
+    );
+
+    Ok(())
+}
+
 /// Ensure that `--emit-index-annotation` prints the index URL for each package.
 #[test]
 fn emit_index_annotation_pypi_org_simple() -> Result<()> {
@@ -3,7 +3,7 @@
 use anyhow::Result;
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::{fixture::ChildPath, prelude::*};
-use indoc::indoc;
+use indoc::{formatdoc, indoc};
 use insta::assert_snapshot;
 use predicates::str::contains;
 use std::path::Path;
@@ -1153,6 +1153,149 @@ fn run_in_workspace() -> Result<()> {
     Installed 2 packages in [TIME]
      + child2==0.1.0 (from file://[TEMP_DIR]/child2)
      + typing-extensions==4.10.0
+    Running `python` in child1
+    Running `python` in child2
+    Running `python` in project
+
+    child1: success
+    child2: success
+    project: success
+    "###);
+
+    Ok(())
+}
+
+/// Run a command in every member of a workspace.
+#[test]
+fn run_in_workspace_members() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(
+        r#"
+        [tool.uv.workspace]
+        members = ["child1", "child2", "child3"]
+
+        [tool.uv.scripts]
+        hello = "echo hello"
+        "#,
+    )?;
+
+    for name in ["child1", "child2", "child3"] {
+        let child = context.temp_dir.child(name);
+        child.child("pyproject.toml").write_str(&formatdoc! { r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = []
+
+            [build-system]
+            requires = ["setuptools>=42"]
+            build-backend = "setuptools.build_meta"
+            "#
+        })?;
+        child
+            .child("src")
+            .child(name)
+            .child("__init__.py")
+            .touch()?;
+    }
+
+    // Print the working directory, and fail in `child2`.
+    let script = indoc! { r#"
+        import os, sys
+        name = os.path.basename(os.getcwd())
+        print(name)
+        sys.exit(1 if name == "child2" else 0)
+       "#
+    };
+
+    // By default, stop after the first failure.
+    uv_snapshot!(context.filters(), context.run().arg("--all-packages").arg("python").arg("-c").arg(script), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    child1
+    child2
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 3 packages in [TIME]
+     + child1==0.1.0 (from file://[TEMP_DIR]/child1)
+     + child2==0.1.0 (from file://[TEMP_DIR]/child2)
+     + child3==0.1.0 (from file://[TEMP_DIR]/child3)
+    Running `python` in child1
+    Running `python` in child2
+
+    child1: success
+    child2: exit code 1
+    child3: skipped
+    "###);
+
+    // With `--keep-going`, run the command in the remaining members.
+    uv_snapshot!(context.filters(), context.run().arg("--all-packages").arg("--keep-going").arg("python").arg("-c").arg(script), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+    child1
+    child2
+    child3
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Audited 3 packages in [TIME]
+    Running `python` in child1
+    Running `python` in child2
+    Running `python` in child3
+
+    child1: success
+    child2: exit code 1
+    child3: success
+    "###);
+
+    // Run the members concurrently.
+    uv_snapshot!(context.filters(), context.run().arg("--all-packages").arg("--jobs").arg("2").arg("python").arg("-c").arg("pass"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Audited 3 packages in [TIME]
+    Running `python` in child1
+    Running `python` in child2
+    Running `python` in child3
+
+    child1: success
+    child2: success
+    child3: success
+    "###);
+
+    // Tasks can't be run in every member.
+    uv_snapshot!(context.filters(), context.run().arg("--all-packages").arg("hello"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--all-packages` is not supported for tasks, but `hello` is defined in `tool.uv.scripts`
+    "###);
+
+    // `--jobs` requires `--all-packages`.
+    uv_snapshot!(context.filters(), context.run().arg("--jobs").arg("2").arg("python").arg("-c").arg("pass"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: the following required arguments were not provided:
+      --all-packages
+
+    Usage: uv run --all-packages --jobs <JOBS> <COMMAND>
+
+    For more information, try '--help'.
     "###);
 
     Ok(())
@@ -3561,3 +3704,221 @@ fn run_with_group_conflict() -> Result<()> {
 
     Ok(())
 }
+
+/// Run tasks declared in `tool.uv.scripts`.
+#[test]
+#[cfg(unix)]
+fn run_task() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        test = ["iniconfig"]
+
+        [tool.uv.scripts]
+        hello = "echo hello"
+        greet = { cmd = ["python", "-c", "import sys; print('Hello,', *sys.argv[1:])"], help = "Greet someone" }
+        env = { cmd = "echo $GREETING from $(basename $(pwd))", env = { GREETING = "Howdy" }, cwd = "child" }
+        check = { cmd = "python -c 'import iniconfig; print(iniconfig.__name__)'", groups = ["test"], depends-on = ["hello"] }
+        "#
+    })?;
+    context.temp_dir.child("child").create_dir_all()?;
+
+    // A shell command.
+    uv_snapshot!(context.filters(), context.run().arg("hello"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited in [TIME]
+    Running task `hello`
+    "###);
+
+    // A list of arguments, with additional arguments appended.
+    uv_snapshot!(context.filters(), context.run().arg("greet").arg("World").arg("--loudly"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Hello, World --loudly
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited in [TIME]
+    Running task `greet`
+    "###);
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([(r"shadows the command at `.*`", "shadows the command at `[PATH]`")])
+        .collect::<Vec<_>>();
+
+    // Environment variables and the working directory. The task shadows the `env` command.
+    uv_snapshot!(filters, context.run().arg("env"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    Howdy from child
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Audited in [TIME]
+    warning: Task `env` in `tool.uv.scripts` shadows the command at `[PATH]`; the task will be run instead
+    Running task `env`
+    "###);
+
+    // The task's dependency groups should be synced, and its dependencies should run first.
+    uv_snapshot!(context.filters(), context.run().arg("check"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello
+    iniconfig
+
+    ----- stderr -----
+    Resolved 2 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + iniconfig==2.0.0
+    Running task `hello`
+    Running task `check`
+    "###);
+
+    Ok(())
+}
+
+/// Tasks declared in the workspace root require their dependency groups to be defined in the
+/// member from which they're run.
+#[test]
+#[cfg(unix)]
+fn run_task_workspace_groups() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        test = ["iniconfig"]
+
+        [tool.uv.workspace]
+        members = ["child"]
+
+        [tool.uv.scripts]
+        hello = "echo hello"
+        check = { cmd = "python -c 'import iniconfig'", groups = ["test"] }
+        "#
+    })?;
+
+    let child = context.temp_dir.child("child");
+    child.child("pyproject.toml").write_str(indoc! { r#"
+        [project]
+        name = "child"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [build-system]
+        requires = ["setuptools>=42"]
+        build-backend = "setuptools.build_meta"
+        "#
+    })?;
+    child.child("src").child("child").child("__init__.py").touch()?;
+
+    // Tasks from the workspace root can be run from a member.
+    uv_snapshot!(context.filters(), context.run().arg("hello").current_dir(&child), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    hello
+
+    ----- stderr -----
+    Resolved 3 packages in [TIME]
+    Prepared 1 package in [TIME]
+    Installed 1 package in [TIME]
+     + child==0.1.0 (from file://[TEMP_DIR]/child)
+    Running task `hello`
+    "###);
+
+    // But the member doesn't define the `test` group.
+    uv_snapshot!(context.filters(), context.run().arg("check").current_dir(&child), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Task `check` requires the dependency group `test`, which is not defined in: `pyproject.toml`
+    "###);
+
+    Ok(())
+}
+
+/// Report failures, cycles, and missing dependencies in `tool.uv.scripts`.
+#[test]
+#[cfg(unix)]
+fn run_task_failure() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [tool.uv.scripts]
+        fail = "exit 3"
+        after = { cmd = "echo unreachable", depends-on = ["fail"] }
+        cycle-a = { depends-on = ["cycle-b"] }
+        cycle-b = { depends-on = ["cycle-a"] }
+        missing = { depends-on = ["undefined"] }
+        "#
+    })?;
+
+    // If a dependency fails, the task should not run.
+    uv_snapshot!(context.filters(), context.run().arg("after"), @r###"
+    success: false
+    exit_code: 3
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    Audited in [TIME]
+    Running task `fail`
+    "###);
+
+    uv_snapshot!(context.filters(), context.run().arg("cycle-a"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Found a cycle in the dependencies of task `cycle-a`: `cycle-a` -> `cycle-b` -> `cycle-a`
+    "###);
+
+    uv_snapshot!(context.filters(), context.run().arg("missing"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Task `missing` depends on `undefined`, which is not defined in `tool.uv.scripts`
+    "###);
+
+    Ok(())
+}
@@ -71,14 +71,25 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -86,6 +97,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -138,6 +153,12 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -223,14 +244,25 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -238,6 +270,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -290,6 +326,12 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -376,14 +418,25 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -391,6 +444,10 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -443,6 +500,12 @@ fn resolve_uv_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -561,14 +624,25 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -576,6 +650,10 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -628,6 +706,12 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -715,14 +799,25 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -730,6 +825,10 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -848,14 +947,25 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -863,6 +973,10 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -915,6 +1029,12 @@ fn resolve_pyproject_toml() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -1025,14 +1145,25 @@ fn resolve_index_url() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -1040,6 +1171,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -1092,6 +1227,12 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                     Index {
                         name: None,
@@ -1121,6 +1262,12 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -1208,14 +1355,25 @@ fn resolve_index_url() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -1223,6 +1381,10 @@ fn resolve_index_url() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -1277,6 +1439,12 @@ fn resolve_index_url() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                     Index {
                         name: None,
@@ -1306,6 +1474,12 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                     Index {
                         name: None,
@@ -1335,6 +1509,12 @@ fn resolve_index_url() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -1445,14 +1625,25 @@ fn resolve_find_links() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -1460,6 +1651,10 @@ fn resolve_find_links() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -1513,6 +1708,12 @@ fn resolve_find_links() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 no_index: true,
@@ -1621,14 +1822,25 @@ fn resolve_top_level() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -1636,6 +1848,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -1760,14 +1976,25 @@ fn resolve_top_level() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -1775,6 +2002,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -1827,6 +2058,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                     Index {
                         name: None,
@@ -1856,6 +2093,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -1941,14 +2184,25 @@ fn resolve_top_level() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -1956,6 +2210,10 @@ fn resolve_top_level() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -2008,6 +2266,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                     Index {
                         name: None,
@@ -2037,6 +2301,12 @@ fn resolve_top_level() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -2146,14 +2416,25 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -2161,6 +2442,10 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -2275,14 +2560,25 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -2290,6 +2586,10 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -2404,14 +2704,25 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -2419,6 +2730,10 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -2535,14 +2850,25 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -2550,6 +2876,10 @@ fn resolve_user_configuration() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -2685,14 +3015,25 @@ fn resolve_tool() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -2700,6 +3041,10 @@ fn resolve_tool() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     ToolInstallSettings {
         package: "requirements.in",
@@ -2782,6 +3127,7 @@ fn resolve_tool() -> anyhow::Result<()> {
         },
         force: false,
         editable: false,
+        bounds: None,
         install_mirrors: PythonInstallMirrors {
             python_install_mirror: None,
             pypy_install_mirror: None,
@@ -2843,14 +3189,25 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -2858,6 +3215,10 @@ fn resolve_poetry_toml() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -3000,14 +3361,25 @@ fn resolve_both() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -3015,6 +3387,10 @@ fn resolve_both() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -3067,6 +3443,12 @@ fn resolve_both() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -3275,14 +3657,25 @@ fn resolve_config_file() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -3290,6 +3683,10 @@ fn resolve_config_file() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -3342,6 +3739,12 @@ fn resolve_config_file() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -3438,7 +3841,7 @@ fn resolve_config_file() -> anyhow::Result<()> {
       |
     1 | [project]
       |  ^^^^^^^
-    unknown field `project`, expected one of `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `publish-url`, `trusted-publishing`, `check-url`, `pip`, `cache-keys`, `override-dependencies`, `constraint-dependencies`, `environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `dev-dependencies`, `build-backend`
+    unknown field `project`, expected one of `native-tls`, `offline`, `no-cache`, `cache-dir`, `preview`, `python-preference`, `python-downloads`, `concurrent-downloads`, `concurrent-builds`, `concurrent-installs`, `concurrent-downloads-per-host`, `retry-backoff-base`, `retry-backoff-max`, `http-proxy`, `https-proxy`, `no-proxy`, `index`, `index-url`, `extra-index-url`, `no-index`, `find-links`, `index-strategy`, `keyring-provider`, `allow-insecure-host`, `resolution`, `prerelease`, `fork-strategy`, `dependency-metadata`, `config-settings`, `no-build-isolation`, `no-build-isolation-package`, `exclude-newer`, `link-mode`, `compile-bytecode`, `no-sources`, `upgrade`, `upgrade-package`, `reinstall`, `reinstall-package`, `no-build`, `no-build-package`, `no-binary`, `no-binary-package`, `python-install-mirror`, `pypy-install-mirror`, `publish-url`, `trusted-publishing`, `check-url`, `pip`, `cache-keys`, `bounds`, `override-dependencies`, `constraint-dependencies`, `environments`, `conflicts`, `workspace`, `sources`, `managed`, `package`, `default-groups`, `scripts`, `allow-unused`, `dev-dependencies`, `build-backend`
     "###
     );
 
@@ -3528,14 +3931,25 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -3543,6 +3957,10 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -3660,14 +4078,25 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -3675,6 +4104,10 @@ fn resolve_skip_empty() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -3800,6 +4233,11 @@ fn allow_insecure_host() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [
@@ -3814,11 +4252,17 @@ fn allow_insecure_host() -> anyhow::Result<()> {
                 port: None,
             },
         ],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -3826,6 +4270,10 @@ fn allow_insecure_host() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -3954,14 +4402,25 @@ fn index_priority() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -3969,6 +4428,10 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -4023,6 +4486,12 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                     Index {
                         name: None,
@@ -4052,6 +4521,12 @@ fn index_priority() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -4137,14 +4612,25 @@ fn index_priority() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -4152,6 +4638,10 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -4206,6 +4696,12 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                     Index {
                         name: None,
@@ -4235,6 +4731,12 @@ fn index_priority() -> anyhow::Result<()> {
                         default: false,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -4326,14 +4828,25 @@ fn index_priority() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -4341,6 +4854,10 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -4395,6 +4912,12 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                     Index {
                         name: None,
@@ -4424,6 +4947,12 @@ fn index_priority() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -4510,14 +5039,25 @@ fn index_priority() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -4525,6 +5065,10 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -4579,6 +5123,12 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                     Index {
                         name: None,
@@ -4608,6 +5158,12 @@ fn index_priority() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -4701,14 +5257,25 @@ fn index_priority() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -4716,6 +5283,10 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -4770,6 +5341,12 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                     Index {
                         name: None,
@@ -4799,6 +5376,12 @@ fn index_priority() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -4885,14 +5468,25 @@ fn index_priority() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -4900,6 +5494,10 @@ fn index_priority() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipCompileSettings {
         src_file: [
@@ -4954,6 +5552,12 @@ fn index_priority() -> anyhow::Result<()> {
                             Cli,
                         ),
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                     Index {
                         name: None,
@@ -4983,6 +5587,12 @@ fn index_priority() -> anyhow::Result<()> {
                         default: true,
                         origin: None,
                         publish_url: None,
+                        client_cert: None,
+                        client_key: None,
+                        ca_cert: None,
+                        credential_helper: None,
+                        packages: None,
+                        proxy: None,
                     },
                 ],
                 flat_index: [],
@@ -5082,14 +5692,25 @@ fn verify_hashes() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -5097,6 +5718,10 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipInstallSettings {
         package: [],
@@ -5205,14 +5830,25 @@ fn verify_hashes() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -5220,6 +5856,10 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipInstallSettings {
         package: [],
@@ -5326,14 +5966,25 @@ fn verify_hashes() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -5341,6 +5992,10 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipInstallSettings {
         package: [],
@@ -5449,14 +6104,25 @@ fn verify_hashes() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -5464,6 +6130,10 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipInstallSettings {
         package: [],
@@ -5570,14 +6240,25 @@ fn verify_hashes() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -5585,6 +6266,10 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipInstallSettings {
         package: [],
@@ -5692,14 +6377,25 @@ fn verify_hashes() -> anyhow::Result<()> {
             downloads: 50,
             builds: 16,
             installs: 8,
+            downloads_per_host: None,
+        },
+        retry_backoff: RetryBackoff {
+            base: 1s,
+            max: 1800s,
         },
         connectivity: Online,
         allow_insecure_host: [],
+        proxies: ProxySettings {
+            http: None,
+            https: None,
+            no_proxy: [],
+        },
         show_settings: true,
         preview: Disabled,
         python_preference: Managed,
         python_downloads: Automatic,
         no_progress: false,
+        report_cache_stats: false,
         installer_metadata: true,
     }
     CacheSettings {
@@ -5707,6 +6403,10 @@ fn verify_hashes() -> anyhow::Result<()> {
         cache_dir: Some(
             "[CACHE_DIR]/",
         ),
+        environment_retention: EnvironmentRetention {
+            max_entries: 64,
+            max_age: 2592000s,
+        },
     }
     PipInstallSettings {
         package: [],
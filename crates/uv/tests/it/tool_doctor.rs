@@ -0,0 +1,115 @@
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::PathChild;
+
+use uv_static::EnvVars;
+
+use crate::common::{site_packages_path, uv_snapshot, TestContext};
+
+#[test]
+#[cfg(unix)]
+fn tool_doctor() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    // Install `black`.
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    // A freshly installed tool should be healthy.
+    uv_snapshot!(context.filters(), context.tool_doctor()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 tool; no problems found
+    "###);
+
+    // Remove one of the executables, and a module from the environment.
+    fs_err::remove_file(bin_dir.child("black")).unwrap();
+    let site_packages = site_packages_path(tool_dir.child("black").path(), "python3.12");
+    fs_err::remove_file(site_packages.join("pathspec").join("util.py")).unwrap();
+
+    // With `--check`, the problems should be reported without repairing the tool.
+    uv_snapshot!(context.filters(), context.tool_doctor()
+        .arg("--check")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 1
+    ----- stdout -----
+
+    ----- stderr -----
+    `black` is broken:
+      - The executable `black` is missing: `bin/black`
+      - The package `pathspec` is missing a file: `tools/black/lib/python3.12/site-packages/pathspec/util.py`
+    Found 1 broken tool; run `uv tool doctor` to repair
+    "###);
+
+    // Otherwise, the tool should be rebuilt from its receipt.
+    uv_snapshot!(context.filters(), context.tool_doctor()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    `black` is broken:
+      - The executable `black` is missing: `bin/black`
+      - The package `pathspec` is missing a file: `tools/black/lib/python3.12/site-packages/pathspec/util.py`
+    Installed 6 packages in [TIME]
+     + black==24.2.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    Rebuilt `black` from its receipt
+    "###);
+
+    // After the rebuild, the tool should be healthy again.
+    uv_snapshot!(context.filters(), context.tool_doctor()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Checked 1 tool; no problems found
+    "###);
+}
+
+#[test]
+fn tool_doctor_not_installed() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    uv_snapshot!(context.filters(), context.tool_doctor()
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    No tools installed
+    "###);
+}
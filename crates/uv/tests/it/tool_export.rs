@@ -0,0 +1,109 @@
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::fixture::PathChild;
+use insta::assert_snapshot;
+
+use uv_static::EnvVars;
+
+use crate::common::{uv_snapshot, TestContext};
+
+#[test]
+fn tool_export() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+    let wheels = context.temp_dir.child("wheels");
+
+    // Install `black`.
+    context
+        .tool_install()
+        .arg("black==24.2.0")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_export()
+        .arg("black")
+        .arg("--out-dir")
+        .arg("wheels")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Exported 6 wheels for `black` to: wheels
+    "###);
+
+    // Every package in the tool environment should be exported as a wheel.
+    // (The `black` wheel is platform-specific, so it's omitted from the snapshot.)
+    let mut filenames = fs_err::read_dir(&wheels)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+        .filter(|filename| !filename.starts_with("black-"))
+        .collect::<Vec<_>>();
+    filenames.sort();
+    assert_snapshot!(filenames.join("\n"), @r###"
+    click-8.1.7-py3-none-any.whl
+    mypy_extensions-1.0.0-py3-none-any.whl
+    packaging-24.0-py3-none-any.whl
+    pathspec-0.12.1-py3-none-any.whl
+    platformdirs-4.2.0-py3-none-any.whl
+    "###);
+
+    // Uninstall `black`, then reinstall it from the exported wheels without network access.
+    context
+        .tool_uninstall()
+        .arg("black")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .assert()
+        .success();
+
+    uv_snapshot!(context.filters(), context.tool_install()
+        .arg("black==24.2.0")
+        .arg("--offline")
+        .arg("--no-index")
+        .arg("--find-links")
+        .arg(wheels.as_os_str())
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::PATH, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 6 packages in [TIME]
+    Installed 6 packages in [TIME]
+     + black==24.2.0
+     + click==8.1.7
+     + mypy-extensions==1.0.0
+     + packaging==24.0
+     + pathspec==0.12.1
+     + platformdirs==4.2.0
+    Installed 2 executables: black, blackd
+    "###);
+}
+
+#[test]
+fn tool_export_not_installed() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    uv_snapshot!(context.filters(), context.tool_export()
+        .arg("black")
+        .arg("--out-dir")
+        .arg("wheels")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `black` is not installed; run `uv tool install black` to install
+    "###);
+}
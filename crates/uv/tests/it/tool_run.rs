@@ -185,6 +185,143 @@ fn tool_run_from_version() {
     "###);
 }
 
+#[test]
+fn tool_run_from_project() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        dev = ["pytest==8.0.0"]
+        "#
+    })?;
+
+    // Without a lockfile, `--from-project` should fail.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--from-project")
+        .arg("pytest")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`.
+    "###);
+
+    context.lock().assert().success();
+
+    // The tool should run at the locked version.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--from-project")
+        .arg("pytest")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.0
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.0.0
+    "###);
+
+    // Tools that aren't in the lockfile should be rejected.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--from-project")
+        .arg("ruff")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `ruff` is not included in the lockfile at: uv.lock
+    "###);
+
+    Ok(())
+}
+
+/// `--from-project` should pin the tool's dependencies to their locked versions, too.
+#[test]
+fn tool_run_from_project_transitive() -> anyhow::Result<()> {
+    let context = TestContext::new("3.12");
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! { r#"
+        [project]
+        name = "foo"
+        version = "1.0.0"
+        requires-python = ">=3.12"
+        dependencies = []
+
+        [dependency-groups]
+        dev = ["pytest==8.0.0", "pluggy==1.3.0"]
+        "#
+    })?;
+
+    context.lock().assert().success();
+
+    // `pluggy` should be installed at the locked version, rather than the latest.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--from-project")
+        .arg("pytest")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.0
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.3.0
+     + pytest==8.0.0
+    "###);
+
+    // Packages that aren't locked to a registry can't be pinned, and should be rejected.
+    uv_snapshot!(context.filters(), context.tool_run()
+        .arg("--from-project")
+        .arg("foo")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--from-project` requires `foo` and its dependencies to be locked to a registry, but `foo` is locked to a non-registry source
+    "###);
+
+    Ok(())
+}
+
 #[test]
 fn tool_run_suggest_valid_commands() {
     let context = TestContext::new("3.12").with_filtered_exe_suffix();
@@ -1453,3 +1590,108 @@ fn tool_run_latest() {
     ----- stderr -----
     "###);
 }
+
+/// `--show-env` should report whether the cached environment was reused.
+#[test]
+fn tool_run_show_env() {
+    let context = TestContext::new("3.12").with_filtered_exe_suffix();
+    let tool_dir = context.temp_dir.child("tools");
+    let bin_dir = context.temp_dir.child("bin");
+
+    let filters = context
+        .filters()
+        .into_iter()
+        .chain([
+            // The archive entry does not have a stable key, so we filter it out
+            (
+                r"\[CACHE_DIR\](\\|\/)([^\\\/\s]+)(\\|\/)\S+",
+                "[CACHE_DIR]/$2/[ENTRY]",
+            ),
+        ])
+        .collect::<Vec<_>>();
+
+    // The first invocation creates the environment.
+    uv_snapshot!(filters, context.tool_run()
+        .arg("--show-env")
+        .arg("pytest@8.0.0")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.0
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Prepared 4 packages in [TIME]
+    Installed 4 packages in [TIME]
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.0.0
+    Using cached environment at: [CACHE_DIR]/archive-v0/[ENTRY] (created, as no environment matched the requirements hash)
+    "###);
+
+    // The second invocation reuses it.
+    uv_snapshot!(filters, context.tool_run()
+        .arg("--show-env")
+        .arg("pytest@8.0.0")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.0
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Using cached environment at: [CACHE_DIR]/archive-v0/[ENTRY] (reused, as the requirements hash matched)
+    "###);
+
+    // A different set of requirements misses, but retains the environment that was just used.
+    uv_snapshot!(filters, context.tool_run()
+        .arg("--show-env")
+        .arg("--with")
+        .arg("anyio")
+        .arg("pytest@8.0.0")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str())
+        .env(EnvVars::UV_CACHE_MAX_ENVIRONMENTS, "0"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.0
+
+    ----- stderr -----
+    Resolved 7 packages in [TIME]
+    Prepared 3 packages in [TIME]
+    Installed 7 packages in [TIME]
+     + anyio==4.3.0
+     + idna==3.6
+     + iniconfig==2.0.0
+     + packaging==24.0
+     + pluggy==1.4.0
+     + pytest==8.0.0
+     + sniffio==1.3.1
+    Using cached environment at: [CACHE_DIR]/archive-v0/[ENTRY] (created, as no environment matched the requirements hash)
+    "###);
+
+    uv_snapshot!(filters, context.tool_run()
+        .arg("--show-env")
+        .arg("pytest@8.0.0")
+        .arg("--version")
+        .env(EnvVars::UV_TOOL_DIR, tool_dir.as_os_str())
+        .env(EnvVars::XDG_BIN_HOME, bin_dir.as_os_str()), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    pytest 8.0.0
+
+    ----- stderr -----
+    Resolved 4 packages in [TIME]
+    Using cached environment at: [CACHE_DIR]/archive-v0/[ENTRY] (reused, as the requirements hash matched)
+    "###);
+}
@@ -0,0 +1,418 @@
+use anyhow::Result;
+use assert_cmd::assert::OutputAssertExt;
+use assert_fs::prelude::*;
+use indoc::indoc;
+use insta::assert_snapshot;
+
+use crate::common::{uv_snapshot, TestContext};
+
+/// Read and update the version of a project.
+#[test]
+fn version_update() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "1.2.3" # The current version.
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.version(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.2.3
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--short"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    1.2.3
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("1.2.4"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.2.3 => 1.2.4
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("minor"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.2.4 => 1.3.0
+
+    ----- stderr -----
+    "###);
+
+    // The surrounding formatting should be preserved.
+    let pyproject_toml = context.read("pyproject.toml");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            pyproject_toml, @r###"
+        [project]
+        name = "project"
+        version = "1.3.0" # The current version.
+        requires-python = ">=3.12"
+        dependencies = []
+        "###
+        );
+    });
+
+    uv_snapshot!(context.filters(), context.version().arg("--output-format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    {
+      "package_name": "project",
+      "version": "1.3.0"
+    }
+
+    ----- stderr -----
+    "###);
+
+    // With `--dry-run`, the `pyproject.toml` should be left unchanged.
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("major").arg("--dry-run"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.3.0 => 2.0.0
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--short"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    1.3.0
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// Bump the pre-release, post-release, and development segments of a project version.
+#[test]
+fn version_bump_segments() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "1.2.3"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    // Release segments are bumped before pre-release segments, regardless of order.
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("beta").arg("--bump").arg("major"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.2.3 => 2.0.0b1
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("pre"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 2.0.0b1 => 2.0.0b2
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("rc").arg("--bump").arg("dev"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 2.0.0b2 => 2.0.0rc1.dev1
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("dev"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 2.0.0rc1.dev1 => 2.0.0rc1.dev2
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("stable"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 2.0.0rc1.dev2 => 2.0.0
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("post"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 2.0.0 => 2.0.0.post1
+
+    ----- stderr -----
+    "###);
+
+    // Starting a pre-release of the current version would decrease the version.
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("alpha"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The new version `2.0.0a1` is not greater than the current version `2.0.0.post1`; to set the version anyway, provide it explicitly, e.g., `uv version 2.0.0a1`
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("pre"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: `--bump pre` requires a pre-release version, but the current version is `2.0.0.post1`; use `--bump alpha`, `--bump beta`, or `--bump rc` to start a pre-release
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("minor").arg("--bump").arg("patch"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Only one of `--bump major`, `--bump minor`, or `--bump patch` can be provided
+    "###);
+
+    Ok(())
+}
+
+/// Bumping the development segment of a release starts the development of the next version,
+/// since a development release sorts before the corresponding release.
+#[test]
+fn version_bump_dev() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "1.2.3"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("dev"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.2.3 => 1.2.4.dev1
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("dev"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.2.4.dev1 => 1.2.4.dev2
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("stable"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.2.4.dev2 => 1.2.4
+
+    ----- stderr -----
+    "###);
+
+    // An explicit bump of another segment takes precedence.
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("minor").arg("--bump").arg("dev"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.2.4 => 1.3.0.dev1
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("alpha"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.3.0.dev1 => 1.3.0a1
+
+    ----- stderr -----
+    "###);
+
+    // The pre-release number is bumped for a pre-release.
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("dev"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 1.3.0a1 => 1.3.0a2.dev1
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}
+
+/// Updating the version should update the project's entry in the lockfile.
+#[test]
+fn version_relock() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        version = "0.1.0"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("patch"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 0.1.0 => 0.1.1
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###);
+
+    let lock = context.read("uv.lock");
+
+    insta::with_settings!({
+        filters => context.filters(),
+    }, {
+        assert_snapshot!(
+            lock, @r###"
+        version = 1
+        requires-python = ">=3.12"
+
+        [options]
+        exclude-newer = "2024-03-25T00:00:00Z"
+
+        [[package]]
+        name = "project"
+        version = "0.1.1"
+        source = { virtual = "." }
+        "###
+        );
+    });
+
+    // The lockfile should now be up-to-date.
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    "###);
+
+    // With `--frozen`, the lockfile should be left unchanged.
+    uv_snapshot!(context.filters(), context.version().arg("0.2.0").arg("--frozen"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    project 0.1.1 => 0.2.0
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.lock().arg("--locked"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    Resolved 1 package in [TIME]
+    error: The lockfile at `uv.lock` needs to be updated, but `--locked` was provided. To update the lockfile, run `uv lock`.
+    "###);
+
+    Ok(())
+}
+
+/// A dynamic version can't be read or updated.
+#[test]
+fn version_dynamic() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        dynamic = ["version"]
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("minor"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: The version of `project` is declared as dynamic in `project.dynamic`, so it can't be read or updated by uv
+    "###);
+
+    Ok(())
+}
+
+/// A missing version is reported as missing, rather than dynamic.
+#[test]
+fn version_missing() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    let pyproject_toml = context.temp_dir.child("pyproject.toml");
+    pyproject_toml.write_str(indoc! {r#"
+        [project]
+        name = "project"
+        requires-python = ">=3.12"
+        dependencies = []
+    "#})?;
+
+    uv_snapshot!(context.filters(), context.version().arg("--bump").arg("minor"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Failed to parse: `pyproject.toml`
+      Caused by: TOML parse error at line 1, column 1
+      |
+    1 | [project]
+      | ^^^^^^^^^
+    `pyproject.toml` is using the `[project]` table, but the required `project.version` field is neither set nor present in the `project.dynamic` list
+    "###);
+
+    Ok(())
+}
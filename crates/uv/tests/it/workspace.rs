@@ -7,7 +7,7 @@ use anyhow::Result;
 use assert_cmd::assert::OutputAssertExt;
 use assert_fs::fixture::{FileWriteStr, PathChild};
 use assert_fs::prelude::FileTouch;
-use indoc::indoc;
+use indoc::{formatdoc, indoc};
 use insta::{assert_json_snapshot, assert_snapshot};
 use serde::{Deserialize, Serialize};
 
@@ -1902,3 +1902,177 @@ fn transitive_dep_in_git_workspace_with_root() -> Result<()> {
 
     Ok(())
 }
+
+/// List the members of a workspace, and those affected by changes to a set of paths.
+#[test]
+fn workspace_list_affected_by() -> Result<()> {
+    let context = TestContext::new("3.12");
+
+    context
+        .temp_dir
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+        [tool.uv.workspace]
+        members = ["packages/*"]
+
+        [tool.uv.sources]
+        a = { workspace = true }
+        b = { workspace = true }
+    "#})?;
+
+    // `b` depends on `a`, and `c` depends on `b`; `d` is independent.
+    for (name, dependencies) in [
+        ("a", "[]"),
+        ("b", r#"["a"]"#),
+        ("c", r#"["b"]"#),
+        ("d", "[]"),
+    ] {
+        let member = context.temp_dir.child("packages").child(name);
+        member.child("pyproject.toml").write_str(&formatdoc! {r#"
+            [project]
+            name = "{name}"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = {dependencies}
+
+            [build-system]
+            requires = ["setuptools>=42"]
+            build-backend = "setuptools.build_meta"
+        "#})?;
+        member
+            .child("src")
+            .child(name)
+            .child("__init__.py")
+            .touch()?;
+    }
+
+    uv_snapshot!(context.filters(), context.workspace_list(), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    a
+    b
+    c
+    d
+
+    ----- stderr -----
+    "###);
+
+    // Determining the affected members requires a lockfile.
+    uv_snapshot!(context.filters(), context.workspace_list().arg("--affected-by").arg("packages/a/src/a/__init__.py"), @r###"
+    success: false
+    exit_code: 2
+    ----- stdout -----
+
+    ----- stderr -----
+    error: Unable to find lockfile at `uv.lock`. To create a lockfile, run `uv lock` or `uv sync`.
+    "###);
+
+    context.lock().assert().success();
+
+    // A change to `a` affects `a`, along with its (transitive) dependents.
+    uv_snapshot!(context.filters(), context.workspace_list().arg("--affected-by").arg("packages/a/src/a/__init__.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    a
+    b
+    c
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.workspace_list().arg("--affected-by").arg("packages/c/pyproject.toml").arg("packages/d"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    c
+    d
+
+    ----- stderr -----
+    "###);
+
+    // Paths outside of the workspace members are ignored.
+    uv_snapshot!(context.filters(), context.workspace_list().arg("--affected-by").arg("README.md"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+
+    ----- stderr -----
+    "###);
+
+    uv_snapshot!(context.filters(), context.workspace_list().arg("--affected-by").arg("packages/b/README.md").arg("--format").arg("json"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    [
+      {
+        "name": "b",
+        "path": "packages/b"
+      },
+      {
+        "name": "c",
+        "path": "packages/c"
+      }
+    ]
+
+    ----- stderr -----
+    "###);
+
+    // Relative paths are resolved against the project directory.
+    uv_snapshot!(context.filters(), context.workspace_list().arg("--project").arg("packages/b").arg("--affected-by").arg("src/b/__init__.py"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    b
+    c
+
+    ----- stderr -----
+    "###);
+
+    // If `d` starts depending on `a` without updating the lockfile, the lockfile is outdated.
+    context
+        .temp_dir
+        .child("packages")
+        .child("d")
+        .child("pyproject.toml")
+        .write_str(indoc! {r#"
+            [project]
+            name = "d"
+            version = "0.1.0"
+            requires-python = ">=3.12"
+            dependencies = ["a"]
+
+            [build-system]
+            requires = ["setuptools>=42"]
+            build-backend = "setuptools.build_meta"
+        "#})?;
+
+    uv_snapshot!(context.filters(), context.workspace_list().arg("--affected-by").arg("packages/a"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    a
+    b
+    c
+
+    ----- stderr -----
+    warning: The lockfile at `uv.lock` is outdated, so the affected members may be incomplete (run `uv lock` to update it)
+    "###);
+
+    context.lock().assert().success();
+
+    uv_snapshot!(context.filters(), context.workspace_list().arg("--affected-by").arg("packages/a"), @r###"
+    success: true
+    exit_code: 0
+    ----- stdout -----
+    a
+    b
+    c
+    d
+
+    ----- stderr -----
+    "###);
+
+    Ok(())
+}